@@ -0,0 +1,33 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs a simulated two-phase commit cluster with arbitrary votes and faults, and checks the
+//! atomic commitment properties of the run, including termination once the faults are repaired.
+
+#![no_main]
+
+use arbitrary::Unstructured;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut u = Unstructured::new(data);
+    let Ok(mut simulation) = augrim_fuzz::cluster(&mut u) else {
+        return;
+    };
+    simulation.run_for(augrim_fuzz::CLUSTER_RUN_TIME);
+    augrim_fuzz::check_atomic_commitment(&simulation);
+
+    simulation.run_for(augrim_fuzz::QUIESCENCE_TIME);
+    augrim_fuzz::check_termination(&simulation);
+});
@@ -0,0 +1,32 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs sequences of arbitrary events through the coordinator of `TwoPhaseCommitAlgorithm`,
+//! starting from an arbitrary valid coordinator context.
+
+#![no_main]
+
+use arbitrary::Unstructured;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut u = Unstructured::new(data);
+    let Ok(context) = augrim_fuzz::coordinator_context(&mut u) else {
+        return;
+    };
+    let Ok(steps) = augrim_fuzz::steps(&mut u) else {
+        return;
+    };
+    augrim_fuzz::run(context, steps);
+});
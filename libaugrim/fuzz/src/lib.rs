@@ -0,0 +1,317 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decodes fuzzer input into the events, contexts and clusters exercised by the fuzz targets.
+//!
+//! Events and contexts are decoded from the same small cluster and ranges as the proptest
+//! strategies of [`augrim::testing::two_phase_commit`], so that decoded events frequently match
+//! the decoded contexts.
+
+use std::time::{Duration, SystemTime};
+
+use arbitrary::{Result, Unstructured};
+use augrim::check::atomic_commitment::{self, CommitmentRun, Failures};
+use augrim::error::AlgorithmError;
+use augrim::sim::two_phase_commit::{add_cluster, application, TwoPhaseCommitSimulation};
+use augrim::sim::{Fault, FaultSchedule, SimRng, Simulation};
+use augrim::testing::two_phase_commit::{
+    coordinator_states, participant_states, COORDINATOR, EPOCHS, OUTSIDER, PARTICIPANTS,
+    TIME_SECS, VALUES,
+};
+use augrim::two_phase_commit::{
+    Epoch, Participant, TwoPhaseCommitAction, TwoPhaseCommitAlgorithm,
+    TwoPhaseCommitContextBuilder, TwoPhaseCommitEvent, TwoPhaseCommitInvariants,
+    TwoPhaseCommitMessage, TwoPhaseCommitState,
+};
+use augrim::{Algorithm, Invariant, ManualTimeSource};
+
+pub use augrim::testing::two_phase_commit::{Context, Event};
+
+pub type Action = TwoPhaseCommitAction<String, String, SystemTime>;
+
+fn process(u: &mut Unstructured) -> Result<String> {
+    Ok(
+        u.choose(&[COORDINATOR, PARTICIPANTS[0], PARTICIPANTS[1], OUTSIDER])?
+            .to_string(),
+    )
+}
+
+fn epoch(u: &mut Unstructured) -> Result<Epoch> {
+    u.int_in_range(EPOCHS)
+}
+
+fn value(u: &mut Unstructured) -> Result<String> {
+    Ok(u.choose(&VALUES)?.to_string())
+}
+
+fn time(u: &mut Unstructured) -> Result<SystemTime> {
+    Ok(SystemTime::UNIX_EPOCH + Duration::from_secs(u.int_in_range(TIME_SECS)?))
+}
+
+fn message(u: &mut Unstructured) -> Result<TwoPhaseCommitMessage<String>> {
+    Ok(match u.int_in_range(0..=5)? {
+        0 => TwoPhaseCommitMessage::VoteRequest(epoch(u)?, value(u)?),
+        1 => TwoPhaseCommitMessage::VoteResponse(epoch(u)?, u.arbitrary()?),
+        2 => TwoPhaseCommitMessage::Commit(epoch(u)?),
+        3 => TwoPhaseCommitMessage::Abort(epoch(u)?),
+        4 => TwoPhaseCommitMessage::DecisionRequest(epoch(u)?),
+        _ => TwoPhaseCommitMessage::DecisionAck(epoch(u)?),
+    })
+}
+
+/// Decode any event.
+pub fn event(u: &mut Unstructured) -> Result<Event> {
+    Ok(match u.int_in_range(0..=3)? {
+        0 => TwoPhaseCommitEvent::Alarm(),
+        1 => TwoPhaseCommitEvent::Deliver(process(u)?, message(u)?),
+        2 => TwoPhaseCommitEvent::Start(value(u)?),
+        _ => TwoPhaseCommitEvent::Vote(u.arbitrary()?),
+    })
+}
+
+/// Decode a sequence of events, each paired with the time elapsed before it.
+pub fn steps(u: &mut Unstructured) -> Result<Vec<(Duration, Event)>> {
+    let mut steps = Vec::new();
+    while !u.is_empty() && steps.len() < 64 {
+        steps.push((Duration::from_secs(u.int_in_range(0..=40)?), event(u)?));
+    }
+    Ok(steps)
+}
+
+fn coordinator_state(u: &mut Unstructured) -> Result<TwoPhaseCommitState<SystemTime>> {
+    let states = coordinator_states(time(u)?);
+    Ok(u.choose(&states)?.clone())
+}
+
+fn participant_state(u: &mut Unstructured) -> Result<TwoPhaseCommitState<SystemTime>> {
+    let states = participant_states(u.arbitrary()?, time(u)?);
+    Ok(u.choose(&states)?.clone())
+}
+
+/// Decode the epoch and last commit epoch of a context; the last commit epoch is never greater
+/// than the epoch, as the invariants require.
+fn epochs(u: &mut Unstructured) -> Result<(Epoch, Option<Epoch>)> {
+    let epoch = epoch(u)?;
+    let last_commit_epoch = if u.arbitrary()? {
+        Some(u.int_in_range(0..=epoch)?)
+    } else {
+        None
+    };
+    Ok((epoch, last_commit_epoch))
+}
+
+/// Decode a valid coordinator context.
+pub fn coordinator_context(u: &mut Unstructured) -> Result<Context> {
+    let (epoch, last_commit_epoch) = epochs(u)?;
+    let count = u.int_in_range(1..=PARTICIPANTS.len())?;
+    let participants = PARTICIPANTS[..count]
+        .iter()
+        .map(|process| {
+            Ok(Participant {
+                process: process.to_string(),
+                vote: u.arbitrary()?,
+                decision_ack: u.arbitrary()?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut builder = TwoPhaseCommitContextBuilder::new()
+        .with_coordinator(COORDINATOR.to_string())
+        .with_epoch(epoch)
+        .with_state(coordinator_state(u)?)
+        .with_this_process(COORDINATOR.to_string())
+        .with_participants(participants);
+    if let Some(last_commit_epoch) = last_commit_epoch {
+        builder = builder.with_last_commit_epoch(last_commit_epoch);
+    }
+    if u.arbitrary()? {
+        builder = builder.with_value(value(u)?);
+    }
+    Ok(builder
+        .build()
+        .expect("decoded coordinator context is valid"))
+}
+
+/// Decode a valid participant context.
+pub fn participant_context(u: &mut Unstructured) -> Result<Context> {
+    let (epoch, last_commit_epoch) = epochs(u)?;
+    let this_process = u.choose(&PARTICIPANTS)?.to_string();
+
+    let mut builder = TwoPhaseCommitContextBuilder::new()
+        .with_coordinator(COORDINATOR.to_string())
+        .with_epoch(epoch)
+        .with_state(participant_state(u)?)
+        .with_this_process(this_process)
+        .with_participant_processes(PARTICIPANTS.iter().map(|p| p.to_string()).collect());
+    if let Some(last_commit_epoch) = last_commit_epoch {
+        builder = builder.with_last_commit_epoch(last_commit_epoch);
+    }
+    if u.arbitrary()? {
+        builder = builder.with_value(value(u)?);
+    }
+    Ok(builder
+        .build()
+        .expect("decoded participant context is valid"))
+}
+
+/// Run the events through the algorithm, each with the context produced by the previous ones.
+///
+/// Panics if the algorithm fails with anything but an invalid state error, or if its actions
+/// violate [`TwoPhaseCommitInvariants`].
+pub fn run(context: Context, steps: Vec<(Duration, Event)>) {
+    let clock = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+    let algorithm = TwoPhaseCommitAlgorithm::new(clock.clone());
+
+    let mut context = context;
+    for (elapsed, event) in steps {
+        clock.advance(elapsed);
+        let actions = match algorithm.event(event.clone(), context.clone()) {
+            Ok(actions) => actions,
+            Err(AlgorithmError::InvalidState(_)) => continue,
+            Err(err) => panic!("{event:?} failed with {context:?}: {err}"),
+        };
+
+        if let Err(err) = TwoPhaseCommitInvariants.check_actions(&context, &actions) {
+            panic!("{event:?} with {context:?} returned {actions:?}: {err}");
+        }
+
+        if let Some(next) = actions.into_iter().rev().find_map(|action| match action {
+            TwoPhaseCommitAction::Update { context, .. } => Some(context),
+            _ => None,
+        }) {
+            context = next;
+        }
+    }
+}
+
+pub type ClusterSimulation = TwoPhaseCommitSimulation<String, String, SystemTime>;
+
+/// The time for which a decoded cluster is run with its decoded faults.
+pub const CLUSTER_RUN_TIME: Duration = Duration::from_secs(300);
+
+/// The time for which a decoded cluster is run once its faults are repaired: long enough for
+/// every epoch to time out, and for every process to learn the decision.
+pub const QUIESCENCE_TIME: Duration = Duration::from_secs(300);
+
+/// Decode a simulated two-phase commit cluster, with its seed, votes and fault schedule.
+///
+/// Process `p0` coordinates the remaining processes; the coordinator proposes `value-1`,
+/// `value-2` and so on, for a decoded number of rounds. At [`CLUSTER_RUN_TIME`], every crashed
+/// process is restarted and any partition is healed.
+pub fn cluster(u: &mut Unstructured) -> Result<ClusterSimulation> {
+    let count: usize = u.int_in_range(2..=5)?;
+    let processes: Vec<String> = (0..count).map(|i| format!("p{i}")).collect();
+
+    let seed = u.arbitrary()?;
+    let rounds = u.int_in_range(1..=3)?;
+    let vote_no_probability = f64::from(u.int_in_range(0..=4u8)?) / 4.0;
+    let mut votes = SimRng::new(u.arbitrary()?);
+
+    let mut simulation = Simulation::new(SystemTime::UNIX_EPOCH, seed).with_application(
+        application((1..=rounds).map(|round| format!("value-{round}")), move || {
+            !votes.chance(vote_no_probability)
+        }),
+    );
+    add_cluster(
+        &mut simulation,
+        processes[0].clone(),
+        processes[1..].to_vec(),
+    )
+    .expect("cluster contexts are valid");
+
+    let mut schedule = faults(u, &processes)?;
+    for process in &processes {
+        schedule = schedule.with_fault(
+            CLUSTER_RUN_TIME,
+            Fault::Restart {
+                process: process.clone(),
+            },
+        );
+    }
+    simulation
+        .schedule_faults(schedule.with_fault(CLUSTER_RUN_TIME, Fault::Heal))
+        .expect("faults name cluster processes");
+
+    Ok(simulation)
+}
+
+fn faults(u: &mut Unstructured, processes: &[String]) -> Result<FaultSchedule<String>> {
+    let max_ms = CLUSTER_RUN_TIME.as_millis() as u64;
+    let mut schedule = FaultSchedule::new();
+    while !u.is_empty() && schedule.faults.len() < 16 {
+        let at = Duration::from_millis(u.int_in_range(0..=max_ms)?);
+        let fault = match u.int_in_range(0..=6)? {
+            0 => Fault::Crash {
+                process: u.choose(processes)?.clone(),
+            },
+            1 => Fault::Restart {
+                process: u.choose(processes)?.clone(),
+            },
+            2 => {
+                let mut groups = vec![Vec::new(), Vec::new()];
+                for process in processes {
+                    groups[usize::from(u.arbitrary::<bool>()?)].push(process.clone());
+                }
+                Fault::Partition { groups }
+            }
+            3 => Fault::Heal,
+            4 => Fault::ClockSkew {
+                process: u.choose(processes)?.clone(),
+                ahead_ms: u.int_in_range(0..=60_000)?,
+            },
+            5 => {
+                let min_ms = u.int_in_range(0..=100)?;
+                Fault::Delay {
+                    min_ms,
+                    max_ms: min_ms + u.int_in_range(0..=1_000)?,
+                }
+            }
+            _ => Fault::Duplicate {
+                probability: f64::from(u.int_in_range(0..=4u8)?) / 4.0,
+            },
+        };
+        schedule = schedule.with_fault(at, fault);
+    }
+    Ok(schedule)
+}
+
+/// Check that the cluster's run satisfies the atomic commitment properties which hold despite
+/// outstanding failures: agreement (AC1), irreversibility (AC2) and validity (AC3).
+///
+/// Panics, describing the violations, if it does not.
+pub fn check_atomic_commitment(simulation: &ClusterSimulation) {
+    check(CommitmentRun::from(simulation.trace()));
+}
+
+/// Check that the cluster's run, once its faults have been repaired for
+/// [`QUIESCENCE_TIME`], also satisfies termination (AC5): every process which voted has
+/// decided.
+///
+/// Panics, describing the violations, if it does not.
+pub fn check_termination(simulation: &ClusterSimulation) {
+    check(CommitmentRun::from(simulation.trace()).with_failures(Failures::Repaired));
+}
+
+fn check(run: CommitmentRun<String>) {
+    let violations = atomic_commitment::check(&run);
+    assert!(
+        violations.is_empty(),
+        "{}",
+        violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
@@ -0,0 +1,392 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Closure-based algorithm adapters, returned by the corresponding [`Algorithm`] methods.
+
+use std::marker::PhantomData;
+
+use crate::error::{AlgorithmError, InternalError};
+
+use super::Algorithm;
+
+/// An algorithm which converts its events using a closure.
+///
+/// This `struct` is returned by the [`Algorithm::map_event`] method.
+pub struct MapEvent<T, F, E> {
+    inner: T,
+    f: F,
+    _event: PhantomData<fn(E)>,
+}
+
+impl<T, F, E> MapEvent<T, F, E> {
+    pub(super) fn new(inner: T, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _event: PhantomData,
+        }
+    }
+}
+
+impl<T, F, E> Algorithm for MapEvent<T, F, E>
+where
+    T: Algorithm,
+    F: Fn(E) -> T::Event,
+{
+    type Event = E;
+    type Action = T::Action;
+    type Context = T::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        self.inner.event((self.f)(event), context)
+    }
+}
+
+/// An algorithm which converts its events using a fallible closure.
+///
+/// This `struct` is returned by the [`Algorithm::try_map_event`] method.
+pub struct TryMapEvent<T, F, E> {
+    inner: T,
+    f: F,
+    _event: PhantomData<fn(E)>,
+}
+
+impl<T, F, E> TryMapEvent<T, F, E> {
+    pub(super) fn new(inner: T, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _event: PhantomData,
+        }
+    }
+}
+
+impl<T, F, E> Algorithm for TryMapEvent<T, F, E>
+where
+    T: Algorithm,
+    F: Fn(E) -> Result<T::Event, InternalError>,
+{
+    type Event = E;
+    type Action = T::Action;
+    type Context = T::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        self.inner.event((self.f)(event)?, context)
+    }
+}
+
+/// An algorithm which converts its actions using a closure.
+///
+/// This `struct` is returned by the [`Algorithm::map_action`] method.
+pub struct MapAction<T, F> {
+    inner: T,
+    f: F,
+}
+
+impl<T, F> MapAction<T, F> {
+    pub(super) fn new(inner: T, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<T, F, A> Algorithm for MapAction<T, F>
+where
+    T: Algorithm,
+    F: Fn(T::Action) -> A,
+{
+    type Event = T::Event;
+    type Action = A;
+    type Context = T::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        Ok(self
+            .inner
+            .event(event, context)?
+            .into_iter()
+            .map(&self.f)
+            .collect())
+    }
+}
+
+/// An algorithm which converts its actions using a fallible closure.
+///
+/// This `struct` is returned by the [`Algorithm::try_map_action`] method.
+pub struct TryMapAction<T, F> {
+    inner: T,
+    f: F,
+}
+
+impl<T, F> TryMapAction<T, F> {
+    pub(super) fn new(inner: T, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<T, F, A> Algorithm for TryMapAction<T, F>
+where
+    T: Algorithm,
+    F: Fn(T::Action) -> Result<A, InternalError>,
+{
+    type Event = T::Event;
+    type Action = A;
+    type Context = T::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        self.inner
+            .event(event, context)?
+            .into_iter()
+            .map(&self.f)
+            .collect::<Result<Vec<A>, InternalError>>()
+            .map_err(AlgorithmError::from)
+    }
+}
+
+/// An algorithm which converts its context using a closure.
+///
+/// Only the context passed in with an event is converted; contexts within actions are unchanged
+/// and may be converted with [`Algorithm::map_action`].
+///
+/// This `struct` is returned by the [`Algorithm::map_context`] method.
+pub struct MapContext<T, F, C> {
+    inner: T,
+    f: F,
+    _context: PhantomData<fn(C)>,
+}
+
+impl<T, F, C> MapContext<T, F, C> {
+    pub(super) fn new(inner: T, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _context: PhantomData,
+        }
+    }
+}
+
+impl<T, F, C> Algorithm for MapContext<T, F, C>
+where
+    T: Algorithm,
+    F: Fn(C) -> T::Context,
+{
+    type Event = T::Event;
+    type Action = T::Action;
+    type Context = C;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        self.inner.event(event, (self.f)(context))
+    }
+}
+
+/// An algorithm which converts its context using a fallible closure.
+///
+/// This `struct` is returned by the [`Algorithm::try_map_context`] method.
+pub struct TryMapContext<T, F, C> {
+    inner: T,
+    f: F,
+    _context: PhantomData<fn(C)>,
+}
+
+impl<T, F, C> TryMapContext<T, F, C> {
+    pub(super) fn new(inner: T, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _context: PhantomData,
+        }
+    }
+}
+
+impl<T, F, C> Algorithm for TryMapContext<T, F, C>
+where
+    T: Algorithm,
+    F: Fn(C) -> Result<T::Context, InternalError>,
+{
+    type Event = T::Event;
+    type Action = T::Action;
+    type Context = C;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        self.inner.event(event, (self.f)(context)?)
+    }
+}
+
+/// An algorithm which only returns the actions matching a predicate.
+///
+/// This `struct` is returned by the [`Algorithm::filter_actions`] method.
+pub struct FilterActions<T, F> {
+    inner: T,
+    f: F,
+}
+
+impl<T, F> FilterActions<T, F> {
+    pub(super) fn new(inner: T, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<T, F> Algorithm for FilterActions<T, F>
+where
+    T: Algorithm,
+    F: Fn(&T::Action) -> bool,
+{
+    type Event = T::Event;
+    type Action = T::Action;
+    type Context = T::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let mut actions = self.inner.event(event, context)?;
+        actions.retain(|action| (self.f)(action));
+        Ok(actions)
+    }
+}
+
+/// An algorithm which calls a closure with a reference to each action before returning it.
+///
+/// This `struct` is returned by the [`Algorithm::inspect`] method.
+pub struct Inspect<T, F> {
+    inner: T,
+    f: F,
+}
+
+impl<T, F> Inspect<T, F> {
+    pub(super) fn new(inner: T, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<T, F> Algorithm for Inspect<T, F>
+where
+    T: Algorithm,
+    F: Fn(&T::Action),
+{
+    type Event = T::Event;
+    type Action = T::Action;
+    type Context = T::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let actions = self.inner.event(event, context)?;
+        actions.iter().for_each(&self.f);
+        Ok(actions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    /// Test that the infallible adapters convert events, contexts and actions, and that
+    /// filter_actions and inspect see every action.
+    #[test]
+    fn test_adapters() -> Result<(), Box<dyn std::error::Error>> {
+        let seen = RefCell::new(Vec::new());
+
+        let algorithm = Range
+            .map_event(|event: &str| event.len() as u32)
+            .map_context(|context: i64| context as u32)
+            .inspect(|action| seen.borrow_mut().push(*action))
+            .filter_actions(|action| action % 2 == 0)
+            .map_action(|action| action.to_string());
+
+        assert_eq!(algorithm.event("abc", 10)?, vec!["10", "12"]);
+        assert_eq!(*seen.borrow(), vec![10, 11, 12]);
+
+        Ok(())
+    }
+
+    /// Test that errors returned by the fallible adapters are returned as internal errors.
+    #[test]
+    fn test_try_adapters() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = Range
+            .try_map_event(|event: &str| {
+                event
+                    .parse::<u32>()
+                    .map_err(|e| InternalError::from_source(Box::new(e)))
+            })
+            .try_map_context(|context: &str| {
+                context
+                    .parse::<u32>()
+                    .map_err(|e| InternalError::from_source(Box::new(e)))
+            })
+            .try_map_action(|action| {
+                u8::try_from(action).map_err(|e| InternalError::from_source(Box::new(e)))
+            });
+
+        assert_eq!(algorithm.event("2", "1")?, vec![1, 2]);
+        assert!(matches!(
+            algorithm.event("x", "1"),
+            Err(AlgorithmError::Internal(_))
+        ));
+        assert!(matches!(
+            algorithm.event("1", "x"),
+            Err(AlgorithmError::Internal(_))
+        ));
+        assert!(matches!(
+            algorithm.event("1", "256"),
+            Err(AlgorithmError::Internal(_))
+        ));
+
+        Ok(())
+    }
+
+    // Produces `event` actions, counting up from the context.
+    struct Range;
+
+    impl Algorithm for Range {
+        type Event = u32;
+        type Action = u32;
+        type Context = u32;
+
+        fn event(
+            &self,
+            event: Self::Event,
+            context: Self::Context,
+        ) -> Result<Vec<Self::Action>, AlgorithmError> {
+            Ok((context..context + event).collect())
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains AsyncAlgorithm, the asynchronous counterpart of `Algorithm`.
+
+use std::future::Future;
+
+use crate::error::AlgorithmError;
+
+use super::Algorithm;
+
+/// A consensus algorithm which processes events asynchronously.
+///
+/// This is the asynchronous counterpart of [`Algorithm`], for algorithms which must await
+/// other work (for example, an application-provided vote) while processing an event. Any
+/// [`Algorithm`] may be used as an `AsyncAlgorithm` via [`Algorithm::into_async`].
+pub trait AsyncAlgorithm {
+    /// The event type representing all valid events for the algorithm.
+    type Event;
+
+    /// The action type representing all valid actions for the algorithm.
+    type Action;
+
+    /// The type of context used by the algorithm.
+    type Context;
+
+    /// Process an event, with the provided context, resolving to a list of actions.
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> impl Future<Output = Result<Vec<Self::Action>, AlgorithmError>> + Send;
+}
+
+/// An asynchronous algorithm which runs a synchronous [`Algorithm`] to completion when polled.
+///
+/// This `struct` is returned by the [`Algorithm::into_async`] method.
+pub struct AsyncAdapter<A> {
+    inner: A,
+}
+
+impl<A> AsyncAdapter<A>
+where
+    A: Algorithm,
+{
+    pub fn new(inner: A) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the wrapped algorithm.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+impl<A> AsyncAlgorithm for AsyncAdapter<A>
+where
+    A: Algorithm + Sync,
+    A::Event: Send,
+    A::Context: Send,
+{
+    type Event = A::Event;
+    type Action = A::Action;
+    type Context = A::Context;
+
+    async fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        self.inner.event(event, context)
+    }
+}
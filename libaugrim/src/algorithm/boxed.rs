@@ -0,0 +1,211 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains BoxedAlgorithm and AlgorithmRegistry, for selecting algorithms at run-time.
+
+use std::collections::BTreeMap;
+
+use crate::error::{AlgorithmError, InvalidStateError};
+
+use super::Algorithm;
+
+/// A type-erased algorithm with the given event, action and context types.
+pub type DynAlgorithm<E, A, C> = dyn Algorithm<Event = E, Action = A, Context = C> + Send + Sync;
+
+/// An algorithm whose concrete type has been erased.
+///
+/// Any algorithms which share event, action and context types may be stored as the same
+/// `BoxedAlgorithm` type; [`Algorithm::into_algorithm`] may be used first to convert an
+/// algorithm into those shared types.
+///
+/// This `struct` is returned by the [`Algorithm::boxed`] method.
+pub struct BoxedAlgorithm<E, A, C> {
+    inner: Box<DynAlgorithm<E, A, C>>,
+}
+
+impl<E, A, C> BoxedAlgorithm<E, A, C> {
+    pub fn new<T>(algorithm: T) -> Self
+    where
+        T: Algorithm<Event = E, Action = A, Context = C> + Send + Sync + 'static,
+    {
+        Self {
+            inner: Box::new(algorithm),
+        }
+    }
+}
+
+impl<E, A, C> Algorithm for BoxedAlgorithm<E, A, C> {
+    type Event = E;
+    type Action = A;
+    type Context = C;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        self.inner.event(event, context)
+    }
+}
+
+type Factory<E, A, C> = Box<dyn Fn() -> BoxedAlgorithm<E, A, C> + Send + Sync>;
+
+/// A set of algorithm constructors, selected by name.
+///
+/// This allows an application to choose an algorithm from its configuration at run-time.
+///
+/// # Example
+///
+/// ```
+/// use augrim::{Algorithm, AlgorithmRegistry};
+/// use augrim::error::AlgorithmError;
+///
+/// struct Add(u32);
+///
+/// impl Algorithm for Add {
+///     type Event = u32;
+///     type Action = u32;
+///     type Context = ();
+///
+///     fn event(&self, event: u32, _: ()) -> Result<Vec<u32>, AlgorithmError> {
+///         Ok(vec![event + self.0])
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut registry = AlgorithmRegistry::new();
+/// registry.register("add-one", || Add(1).boxed())?;
+/// registry.register("add-two", || Add(2).boxed())?;
+///
+/// let algorithm = registry.create("add-two")?;
+/// assert_eq!(algorithm.event(1, ())?, vec![3]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct AlgorithmRegistry<E, A, C> {
+    factories: BTreeMap<String, Factory<E, A, C>>,
+}
+
+impl<E, A, C> Default for AlgorithmRegistry<E, A, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, A, C> AlgorithmRegistry<E, A, C> {
+    pub fn new() -> Self {
+        Self {
+            factories: BTreeMap::new(),
+        }
+    }
+
+    /// Register a function which constructs the algorithm with the given name.
+    ///
+    /// Returns an error if an algorithm is already registered with that name.
+    pub fn register<F>(
+        &mut self,
+        name: impl Into<String>,
+        factory: F,
+    ) -> Result<(), InvalidStateError>
+    where
+        F: Fn() -> BoxedAlgorithm<E, A, C> + Send + Sync + 'static,
+    {
+        let name = name.into();
+        if self.factories.contains_key(&name) {
+            return Err(InvalidStateError::with_message(format!(
+                "an algorithm is already registered with the name {name}"
+            )));
+        }
+        self.factories.insert(name, Box::new(factory));
+        Ok(())
+    }
+
+    /// Construct the algorithm registered with the given name.
+    pub fn create(&self, name: &str) -> Result<BoxedAlgorithm<E, A, C>, InvalidStateError> {
+        self.factories
+            .get(name)
+            .map(|factory| factory())
+            .ok_or_else(|| {
+                InvalidStateError::with_message(format!(
+                    "no algorithm is registered with the name {name}"
+                ))
+            })
+    }
+
+    /// Returns the names of all registered algorithms, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that algorithms of different types can be selected by name, and that duplicate and
+    /// unknown names are errors.
+    #[test]
+    fn test_registry() -> Result<(), Box<dyn std::error::Error>> {
+        let mut registry = AlgorithmRegistry::new();
+        registry.register("add", || Add(1).boxed())?;
+        registry.register("double", || Double.boxed())?;
+        assert!(registry.register("add", || Double.boxed()).is_err());
+
+        assert_eq!(registry.names().collect::<Vec<_>>(), vec!["add", "double"]);
+        assert_eq!(registry.create("add")?.event(2, ())?, vec![3]);
+        assert_eq!(registry.create("double")?.event(2, ())?, vec![4]);
+        assert!(registry.create("unknown").is_err());
+
+        Ok(())
+    }
+
+    /// Test that a boxed trait object may be used as an algorithm.
+    #[test]
+    fn test_box_dyn() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithms: Vec<Box<DynAlgorithm<u32, u32, ()>>> =
+            vec![Box::new(Add(1)), Box::new(Double)];
+
+        let results = algorithms
+            .iter()
+            .map(|algorithm| algorithm.event(3, ()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(results, vec![vec![4], vec![6]]);
+
+        Ok(())
+    }
+
+    struct Add(u32);
+
+    impl Algorithm for Add {
+        type Event = u32;
+        type Action = u32;
+        type Context = ();
+
+        fn event(&self, event: u32, _: ()) -> Result<Vec<u32>, AlgorithmError> {
+            Ok(vec![event + self.0])
+        }
+    }
+
+    struct Double;
+
+    impl Algorithm for Double {
+        type Event = u32;
+        type Action = u32;
+        type Context = ();
+
+        fn event(&self, event: u32, _: ()) -> Result<Vec<u32>, AlgorithmError> {
+            Ok(vec![event * 2])
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains `Checked`, an algorithm which validates invariants on its inputs and outputs.
+
+use crate::error::{AlgorithmError, InvalidStateError};
+
+use super::Algorithm;
+
+/// A set of invariants over an algorithm's contexts and actions.
+///
+/// `C` is the context type and `A` is the action type of the algorithm being checked.
+pub trait Invariant<C, A> {
+    /// Validate the context passed in with an event, before the event is processed.
+    ///
+    /// The default implementation accepts every context.
+    fn check_context(&self, context: &C) -> Result<(), InvalidStateError> {
+        let _ = context;
+        Ok(())
+    }
+
+    /// Validate the actions produced by processing an event with the given context.
+    fn check_actions(&self, context: &C, actions: &[A]) -> Result<(), InvalidStateError>;
+}
+
+/// An algorithm that validates an [`Invariant`] on every event it processes.
+///
+/// If the incoming context or the resulting actions violate the invariant,
+/// [`AlgorithmError::InvalidState`] is returned in place of the actions. This is intended as
+/// a debugging aid: it catches bugs both in the algorithm and in callers which corrupt the
+/// contexts they store.
+///
+/// This `struct` is returned by the [`Algorithm::checked`] method.
+pub struct Checked<A, I> {
+    inner: A,
+    invariant: I,
+}
+
+impl<A, I> Checked<A, I>
+where
+    A: Algorithm,
+    I: Invariant<A::Context, A::Action>,
+{
+    /// Wrap the given algorithm, checking the given invariant.
+    pub fn new(inner: A, invariant: I) -> Self {
+        Self { inner, invariant }
+    }
+
+    /// Returns the wrapped algorithm.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+impl<A, I> Algorithm for Checked<A, I>
+where
+    A: Algorithm,
+    A::Context: Clone,
+    I: Invariant<A::Context, A::Action>,
+{
+    type Event = A::Event;
+    type Action = A::Action;
+    type Context = A::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        self.invariant.check_context(&context)?;
+
+        let actions = self.inner.event(event, context.clone())?;
+
+        self.invariant.check_actions(&context, &actions)?;
+
+        Ok(actions)
+    }
+}
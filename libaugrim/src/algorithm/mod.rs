@@ -14,6 +14,18 @@
 
 //! Consensus algorithm trait and implementations.
 
+#[cfg(feature = "algorithm-adapters")]
+mod adapters;
+#[cfg(feature = "algorithm-async")]
+mod async_algorithm;
+#[cfg(feature = "algorithm-boxed")]
+mod boxed;
+#[cfg(feature = "algorithm-checked")]
+mod checked;
+#[cfg(feature = "algorithm-multiplexed")]
+mod multiplexed;
+#[cfg(feature = "algorithm-observed")]
+mod observed;
 mod value_impls;
 
 use std::convert::TryFrom;
@@ -21,6 +33,24 @@ use std::marker::PhantomData;
 
 use crate::error::{AlgorithmError, InternalError};
 
+#[cfg(feature = "algorithm-adapters")]
+pub use adapters::{
+    FilterActions, Inspect, MapAction, MapContext, MapEvent, TryMapAction, TryMapContext,
+    TryMapEvent,
+};
+#[cfg(feature = "algorithm-async")]
+pub use async_algorithm::{AsyncAdapter, AsyncAlgorithm};
+#[cfg(feature = "algorithm-boxed")]
+pub use boxed::{AlgorithmRegistry, BoxedAlgorithm, DynAlgorithm};
+#[cfg(feature = "algorithm-checked")]
+pub use checked::{Checked, Invariant};
+#[cfg(feature = "algorithm-multiplexed")]
+pub use multiplexed::{Multiplexed, MultiplexedContext};
+#[cfg(feature = "tracing")]
+pub use observed::TracingObserver;
+#[cfg(feature = "algorithm-observed")]
+pub use observed::{Observed, Observer};
+
 /// A value to be agreed upon between processes.
 pub trait Value: Clone {}
 
@@ -137,6 +167,149 @@ pub trait Algorithm {
             _context: PhantomData,
         }
     }
+
+    /// Converts the events accepted by the algorithm using the given closure.
+    ///
+    /// Unlike [`Algorithm::into_algorithm`], which relies on `TryFrom` implementations, the
+    /// conversion may be defined at the point of use.
+    #[cfg(feature = "algorithm-adapters")]
+    fn map_event<E, F>(self, f: F) -> MapEvent<Self, F, E>
+    where
+        Self: Sized,
+        F: Fn(E) -> Self::Event,
+    {
+        MapEvent::new(self, f)
+    }
+
+    /// Converts the events accepted by the algorithm using the given fallible closure. An error
+    /// returned by the closure is returned as [`AlgorithmError::Internal`].
+    #[cfg(feature = "algorithm-adapters")]
+    fn try_map_event<E, F>(self, f: F) -> TryMapEvent<Self, F, E>
+    where
+        Self: Sized,
+        F: Fn(E) -> Result<Self::Event, InternalError>,
+    {
+        TryMapEvent::new(self, f)
+    }
+
+    /// Converts the actions returned by the algorithm using the given closure.
+    #[cfg(feature = "algorithm-adapters")]
+    fn map_action<A, F>(self, f: F) -> MapAction<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Action) -> A,
+    {
+        MapAction::new(self, f)
+    }
+
+    /// Converts the actions returned by the algorithm using the given fallible closure. An error
+    /// returned by the closure is returned as [`AlgorithmError::Internal`].
+    #[cfg(feature = "algorithm-adapters")]
+    fn try_map_action<A, F>(self, f: F) -> TryMapAction<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Action) -> Result<A, InternalError>,
+    {
+        TryMapAction::new(self, f)
+    }
+
+    /// Converts the context passed in with each event using the given closure.
+    #[cfg(feature = "algorithm-adapters")]
+    fn map_context<C, F>(self, f: F) -> MapContext<Self, F, C>
+    where
+        Self: Sized,
+        F: Fn(C) -> Self::Context,
+    {
+        MapContext::new(self, f)
+    }
+
+    /// Converts the context passed in with each event using the given fallible closure. An error
+    /// returned by the closure is returned as [`AlgorithmError::Internal`].
+    #[cfg(feature = "algorithm-adapters")]
+    fn try_map_context<C, F>(self, f: F) -> TryMapContext<Self, F, C>
+    where
+        Self: Sized,
+        F: Fn(C) -> Result<Self::Context, InternalError>,
+    {
+        TryMapContext::new(self, f)
+    }
+
+    /// Only returns the actions for which the given predicate returns `true`.
+    #[cfg(feature = "algorithm-adapters")]
+    fn filter_actions<F>(self, f: F) -> FilterActions<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Action) -> bool,
+    {
+        FilterActions::new(self, f)
+    }
+
+    /// Calls the given closure with a reference to each action returned by the algorithm.
+    #[cfg(feature = "algorithm-adapters")]
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Action),
+    {
+        Inspect::new(self, f)
+    }
+
+    /// Converts the algorithm into an [`AsyncAlgorithm`], for use where an asynchronous
+    /// algorithm is expected (for example, by an async driver).
+    #[cfg(feature = "algorithm-async")]
+    fn into_async(self) -> AsyncAdapter<Self>
+    where
+        Self: Sized,
+    {
+        AsyncAdapter::new(self)
+    }
+
+    /// Erases the concrete type of the algorithm, so that it may be stored alongside other
+    /// algorithms with the same event, action and context types.
+    ///
+    /// See also [`AlgorithmRegistry`], for selecting an algorithm by name at run-time.
+    #[cfg(feature = "algorithm-boxed")]
+    fn boxed(self) -> BoxedAlgorithm<Self::Event, Self::Action, Self::Context>
+    where
+        Self: Sized + Send + Sync + 'static,
+    {
+        BoxedAlgorithm::new(self)
+    }
+
+    /// Wraps the algorithm such that every event, context, resulting action and elapsed time is
+    /// reported to the given [`Observer`].
+    ///
+    /// With the `tracing` feature enabled, [`TracingObserver`] may be used to emit these records
+    /// via the `tracing` crate.
+    #[cfg(feature = "algorithm-observed")]
+    fn observe<O>(self, observer: O) -> Observed<Self, O>
+    where
+        Self: Sized,
+        O: Observer<Self>,
+    {
+        Observed::new(self, observer)
+    }
+
+    /// Wraps the algorithm such that the given [`Invariant`] is validated against the incoming
+    /// context and the resulting actions of every event.
+    #[cfg(feature = "algorithm-checked")]
+    fn checked<I>(self, invariant: I) -> Checked<Self, I>
+    where
+        Self: Sized,
+        I: Invariant<Self::Context, Self::Action>,
+    {
+        Checked::new(self, invariant)
+    }
+
+    /// Wraps the algorithm such that it runs an independent instance for each key `K`, with
+    /// events, actions and contexts tagged by the instance key.
+    #[cfg(feature = "algorithm-multiplexed")]
+    fn multiplexed<K>(self) -> Multiplexed<K, Self>
+    where
+        Self: Sized,
+    {
+        Multiplexed::new(self)
+    }
 }
 
 /// An algorithm that wraps an algorithm of another type.
@@ -181,6 +354,23 @@ where
     }
 }
 
+impl<T> Algorithm for Box<T>
+where
+    T: Algorithm + ?Sized,
+{
+    type Event = T::Event;
+    type Action = T::Action;
+    type Context = T::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        (**self).event(event, context)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -217,6 +407,7 @@ mod tests {
     struct TestEvent(Option<u32>);
     struct TestAction(Option<u32>);
     struct TestContext(u32);
+    #[allow(dead_code)]
     #[derive(Debug, Eq, PartialEq, Clone)]
     struct TestProcess;
 
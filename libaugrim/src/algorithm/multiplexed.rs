@@ -0,0 +1,276 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains Multiplexed and MultiplexedContext, for running many instances of an algorithm.
+
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::error::{AlgorithmError, InvalidStateError};
+
+use super::Algorithm;
+
+/// The contexts of each instance of a [`Multiplexed`] algorithm, keyed by instance.
+///
+/// Clones share the underlying map until one of them is modified, so passing a clone to
+/// [`Algorithm::event`] is cheap; once the algorithm has returned, the original may be updated
+/// in place again.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiplexedContext<K, C> {
+    contexts: Arc<BTreeMap<K, C>>,
+}
+
+impl<K, C> Default for MultiplexedContext<K, C> {
+    fn default() -> Self {
+        Self {
+            contexts: Arc::new(BTreeMap::new()),
+        }
+    }
+}
+
+impl<K, C> MultiplexedContext<K, C>
+where
+    K: Ord,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the context of the given instance, if any.
+    pub fn get(&self, key: &K) -> Option<&C> {
+        self.contexts.get(key)
+    }
+
+    /// Returns true if there is a context for the given instance.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.contexts.contains_key(key)
+    }
+
+    /// Returns the keys of all instances, in sorted order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.contexts.keys()
+    }
+
+    /// Returns the key and context of all instances, in sorted order by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &C)> {
+        self.contexts.iter()
+    }
+
+    /// Returns the number of instances.
+    pub fn len(&self) -> usize {
+        self.contexts.len()
+    }
+
+    /// Returns true if there are no instances.
+    pub fn is_empty(&self) -> bool {
+        self.contexts.is_empty()
+    }
+}
+
+impl<K, C> MultiplexedContext<K, C>
+where
+    K: Ord + Clone,
+    C: Clone,
+{
+    /// Set the context of the given instance, returning its previous context, if any.
+    ///
+    /// This is used both to add a new instance and to apply a context update returned by the
+    /// instance.
+    pub fn insert(&mut self, key: K, context: C) -> Option<C> {
+        Arc::make_mut(&mut self.contexts).insert(key, context)
+    }
+
+    /// Remove the given instance, returning its context, if any.
+    pub fn remove(&mut self, key: &K) -> Option<C> {
+        Arc::make_mut(&mut self.contexts).remove(key)
+    }
+}
+
+impl<K, C> FromIterator<(K, C)> for MultiplexedContext<K, C>
+where
+    K: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = (K, C)>>(iter: I) -> Self {
+        Self {
+            contexts: Arc::new(iter.into_iter().collect()),
+        }
+    }
+}
+
+/// An algorithm which runs an independent instance of the inner algorithm for each key `K`.
+///
+/// Events are tagged with the key of the instance they are for, and are passed to the inner
+/// algorithm along with that instance's context from the [`MultiplexedContext`]. The resulting
+/// actions are tagged with the same key. An event for an instance which has no context returns
+/// [`AlgorithmError::InvalidState`].
+///
+/// Actions which update an instance's context should be applied with
+/// [`MultiplexedContext::insert`] under the action's key.
+///
+/// This `struct` is returned by the [`Algorithm::multiplexed`] method.
+///
+/// # Example
+///
+/// ```
+/// use augrim::{Algorithm, MultiplexedContext};
+/// use augrim::error::AlgorithmError;
+///
+/// struct Add;
+///
+/// impl Algorithm for Add {
+///     type Event = u32;
+///     type Action = u32;
+///     type Context = u32;
+///
+///     fn event(&self, event: u32, context: u32) -> Result<Vec<u32>, AlgorithmError> {
+///         Ok(vec![event + context])
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let algorithm = Add.multiplexed();
+/// let contexts: MultiplexedContext<&str, u32> = [("a", 10), ("b", 20)].into_iter().collect();
+///
+/// assert_eq!(algorithm.event(("b", 1), contexts.clone())?, vec![("b", 21)]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Multiplexed<K, A> {
+    inner: A,
+    _key: PhantomData<fn(K)>,
+}
+
+impl<K, A> Multiplexed<K, A>
+where
+    A: Algorithm,
+{
+    pub fn new(inner: A) -> Self {
+        Self {
+            inner,
+            _key: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped algorithm.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+impl<K, A> Algorithm for Multiplexed<K, A>
+where
+    K: Ord + Clone + std::fmt::Debug,
+    A: Algorithm,
+    A::Context: Clone,
+{
+    type Event = (K, A::Event);
+    type Action = (K, A::Action);
+    type Context = MultiplexedContext<K, A::Context>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let (key, event) = event;
+
+        let mut contexts = context.contexts;
+        // Take the context without copying if this is the only reference to the map.
+        let instance_context = match Arc::get_mut(&mut contexts) {
+            Some(contexts) => contexts.remove(&key),
+            None => contexts.get(&key).cloned(),
+        }
+        .ok_or_else(|| {
+            InvalidStateError::with_message(format!("no context for instance {key:?}"))
+        })?;
+
+        Ok(self
+            .inner
+            .event(event, instance_context)?
+            .into_iter()
+            .map(|action| (key.clone(), action))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that events are routed to the context of their instance and actions are tagged with
+    /// the instance key.
+    #[test]
+    fn test_routing() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = Counter.multiplexed();
+
+        let mut contexts = MultiplexedContext::new();
+        contexts.insert("a", 0);
+        contexts.insert("b", 100);
+
+        for (key, event) in [("a", 1), ("b", 2), ("a", 3)] {
+            for (key, action) in algorithm.event((key, event), contexts.clone())? {
+                contexts.insert(key, action);
+            }
+        }
+
+        assert_eq!(contexts.get(&"a"), Some(&4));
+        assert_eq!(contexts.get(&"b"), Some(&102));
+
+        Ok(())
+    }
+
+    /// Test that an event for an unknown instance is an invalid state error.
+    #[test]
+    fn test_unknown_instance() {
+        let algorithm = Counter.multiplexed();
+
+        let contexts: MultiplexedContext<_, _> = [("a", 0)].into_iter().collect();
+        assert!(matches!(
+            algorithm.event(("b", 1), contexts),
+            Err(AlgorithmError::InvalidState(_))
+        ));
+    }
+
+    /// Test that the caller's copy of the contexts is unaffected by the algorithm.
+    #[test]
+    fn test_shared_contexts() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = Counter.multiplexed();
+
+        let contexts: MultiplexedContext<_, _> = [("a", 0)].into_iter().collect();
+        algorithm.event(("a", 1), contexts.clone())?;
+
+        assert_eq!(contexts.get(&"a"), Some(&0));
+        assert_eq!(contexts.len(), 1);
+
+        Ok(())
+    }
+
+    // Adds the event to the context, returning the new context as the only action.
+    struct Counter;
+
+    impl Algorithm for Counter {
+        type Event = u32;
+        type Action = u32;
+        type Context = u32;
+
+        fn event(
+            &self,
+            event: Self::Event,
+            context: Self::Context,
+        ) -> Result<Vec<Self::Action>, AlgorithmError> {
+            Ok(vec![context + event])
+        }
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains `Observed`, an algorithm which reports its activity to an `Observer`.
+
+use std::time::{Duration, Instant};
+
+use crate::error::AlgorithmError;
+
+use super::Algorithm;
+
+/// Receives a record of the events processed by an [`Observed`] algorithm.
+///
+/// For each event, [`Observer::on_event`] is called before the event is processed, followed by
+/// either [`Observer::on_actions`] or [`Observer::on_error`] once the inner algorithm returns.
+pub trait Observer<A>
+where
+    A: Algorithm,
+{
+    /// Called with the event and context before they are passed to the algorithm.
+    fn on_event(&self, event: &A::Event, context: &A::Context);
+
+    /// Called with the actions produced by the algorithm and the time taken to produce them.
+    fn on_actions(&self, actions: &[A::Action], elapsed: Duration);
+
+    /// Called with the error returned by the algorithm and the time taken to produce it.
+    fn on_error(&self, error: &AlgorithmError, elapsed: Duration);
+}
+
+/// An algorithm that reports every event, context, resulting action and elapsed time to an
+/// [`Observer`].
+///
+/// This `struct` is returned by the [`Algorithm::observe`] method.
+pub struct Observed<A, O> {
+    inner: A,
+    observer: O,
+}
+
+impl<A, O> Observed<A, O>
+where
+    A: Algorithm,
+    O: Observer<A>,
+{
+    /// Wrap the given algorithm, reporting to the given observer.
+    pub fn new(inner: A, observer: O) -> Self {
+        Self { inner, observer }
+    }
+
+    /// Returns a reference to the observer.
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    /// Returns the wrapped algorithm, discarding the observer.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+impl<A, O> Algorithm for Observed<A, O>
+where
+    A: Algorithm,
+    O: Observer<A>,
+{
+    type Event = A::Event;
+    type Action = A::Action;
+    type Context = A::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        self.observer.on_event(&event, &context);
+
+        let start = Instant::now();
+        let result = self.inner.event(event, context);
+        let elapsed = start.elapsed();
+
+        match &result {
+            Ok(actions) => self.observer.on_actions(actions, elapsed),
+            Err(err) => self.observer.on_error(err, elapsed),
+        }
+
+        result
+    }
+}
+
+/// An [`Observer`] which emits records using the [`tracing`](https://docs.rs/tracing) crate.
+///
+/// Events and actions are emitted at the `DEBUG` level and errors at the `WARN` level, all with
+/// the target `augrim::observed`.
+#[cfg(feature = "tracing")]
+#[derive(Clone, Debug, Default)]
+pub struct TracingObserver {
+    name: Option<String>,
+}
+
+#[cfg(feature = "tracing")]
+impl TracingObserver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include the given name in every record, to distinguish between multiple algorithms (or
+    /// processes) reporting to the same subscriber.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<A> Observer<A> for TracingObserver
+where
+    A: Algorithm,
+    A::Event: std::fmt::Debug,
+    A::Action: std::fmt::Debug,
+    A::Context: std::fmt::Debug,
+{
+    fn on_event(&self, event: &A::Event, context: &A::Context) {
+        tracing::debug!(
+            target: "augrim::observed",
+            name = self.name.as_deref(),
+            ?event,
+            ?context,
+            "processing event"
+        );
+    }
+
+    fn on_actions(&self, actions: &[A::Action], elapsed: Duration) {
+        tracing::debug!(
+            target: "augrim::observed",
+            name = self.name.as_deref(),
+            ?actions,
+            ?elapsed,
+            "event produced {} actions",
+            actions.len()
+        );
+    }
+
+    fn on_error(&self, error: &AlgorithmError, elapsed: Duration) {
+        tracing::warn!(
+            target: "augrim::observed",
+            name = self.name.as_deref(),
+            %error,
+            ?elapsed,
+            "event failed"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use crate::error::InvalidStateError;
+
+    use super::*;
+
+    /// Test that the observer sees the event and context before processing, and then either the
+    /// resulting actions or the resulting error.
+    #[test]
+    fn test_observed() {
+        let algorithm = TestAlgorithm.observe(RecordingObserver::default());
+
+        let actions = algorithm.event(1, 2).unwrap();
+        assert_eq!(actions, vec![3]);
+        assert!(algorithm.event(0, 2).is_err());
+
+        assert_eq!(
+            *algorithm.observer().records.borrow(),
+            vec![
+                "event 1 context 2".to_string(),
+                "actions [3]".to_string(),
+                "event 0 context 2".to_string(),
+                "error zero".to_string(),
+            ]
+        );
+    }
+
+    struct TestAlgorithm;
+
+    impl Algorithm for TestAlgorithm {
+        type Event = u32;
+        type Action = u32;
+        type Context = u32;
+
+        fn event(
+            &self,
+            event: Self::Event,
+            context: Self::Context,
+        ) -> Result<Vec<Self::Action>, AlgorithmError> {
+            if event == 0 {
+                return Err(InvalidStateError::with_message("zero".into()).into());
+            }
+            Ok(vec![event + context])
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        records: RefCell<Vec<String>>,
+    }
+
+    impl Observer<TestAlgorithm> for RecordingObserver {
+        fn on_event(&self, event: &u32, context: &u32) {
+            self.records
+                .borrow_mut()
+                .push(format!("event {event} context {context}"));
+        }
+
+        fn on_actions(&self, actions: &[u32], _elapsed: Duration) {
+            self.records
+                .borrow_mut()
+                .push(format!("actions {actions:?}"));
+        }
+
+        fn on_error(&self, error: &AlgorithmError, _elapsed: Duration) {
+            self.records.borrow_mut().push(format!("error {error}"));
+        }
+    }
+}
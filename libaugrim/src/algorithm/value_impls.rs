@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Implements the Value trait on various primitive values.
+//! Implements the Value trait on various primitive values, and on vectors of values.
 
 macro_rules! impl_value {
     ($($target:path),* $(,)?) => {
@@ -22,4 +22,6 @@ macro_rules! impl_value {
 
 impl super::Value for () {}
 
+impl<V: super::Value> super::Value for Vec<V> {}
+
 impl_value!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, String);
@@ -0,0 +1,169 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains BestEffortBroadcast.
+
+use std::marker::PhantomData;
+
+use crate::algorithm::{Algorithm, Value};
+use crate::error::AlgorithmError;
+use crate::process::Process;
+
+use super::{
+    message_dropped, send_to_others, BroadcastAction, BroadcastEvent, BroadcastMessage,
+    BroadcastNotification, MessageId,
+};
+
+/// The context of [`BestEffortBroadcast`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BestEffortContext<P> {
+    this_process: P,
+    processes: Vec<P>,
+    sequence: u64,
+}
+
+impl<P: Process> BestEffortContext<P> {
+    /// Create the context of `this_process`, which broadcasts to `processes`; `this_process` is
+    /// added to the processes if it is not among them.
+    pub fn new(this_process: P, mut processes: Vec<P>) -> Self {
+        if !processes.contains(&this_process) {
+            processes.push(this_process.clone());
+        }
+        Self {
+            this_process,
+            processes,
+            sequence: 0,
+        }
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    pub fn processes(&self) -> &[P] {
+        &self.processes
+    }
+
+    /// Returns the number of values this process has broadcast.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+}
+
+/// Best-effort broadcast: the value is sent once to every process, and delivered by each process
+/// which receives it.
+///
+/// Crashes are ignored; if the sender crashes, some processes may not deliver the value.
+pub struct BestEffortBroadcast<P, V> {
+    _process: PhantomData<P>,
+    _value: PhantomData<V>,
+}
+
+impl<P, V> Default for BestEffortBroadcast<P, V> {
+    fn default() -> Self {
+        Self {
+            _process: PhantomData,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<P, V> BestEffortBroadcast<P, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<P, V> Algorithm for BestEffortBroadcast<P, V>
+where
+    P: Process,
+    V: Value,
+{
+    type Event = BroadcastEvent<P, V>;
+    type Action = BroadcastAction<P, V, BestEffortContext<P>>;
+    type Context = BestEffortContext<P>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        match event {
+            BroadcastEvent::Broadcast(value) => {
+                let message = BroadcastMessage {
+                    id: MessageId {
+                        origin: context.this_process.clone(),
+                        sequence: context.sequence,
+                    },
+                    value,
+                };
+                context.sequence += 1;
+
+                let mut actions = vec![BroadcastAction::Notify(BroadcastNotification::Deliver(
+                    context.this_process.clone(),
+                    message.value.clone(),
+                ))];
+                send_to_others(
+                    &context.processes,
+                    &context.this_process,
+                    &message,
+                    &mut actions,
+                );
+                actions.insert(0, BroadcastAction::Update(context));
+                Ok(actions)
+            }
+            BroadcastEvent::Deliver(process, message) => {
+                if !context.processes.contains(&process) {
+                    return Ok(message_dropped("sender is not a broadcast process"));
+                }
+                Ok(vec![BroadcastAction::Notify(
+                    BroadcastNotification::Deliver(process, message.value),
+                )])
+            }
+            BroadcastEvent::Crash(_) => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::Network;
+    use super::*;
+
+    fn network() -> Network<BestEffortBroadcast<u32, String>> {
+        Network::new(
+            BestEffortBroadcast::new(),
+            (0..3)
+                .map(|p| (p, BestEffortContext::new(p, vec![0, 1, 2])))
+                .collect(),
+        )
+    }
+
+    /// Test that every process delivers a value broadcast by a correct process, and that values
+    /// of a sender which crashes mid-broadcast may be delivered by only some processes.
+    #[test]
+    fn test_best_effort_broadcast() {
+        let mut network = network();
+        network.event(0, BroadcastEvent::Broadcast("a".into()));
+        network.run();
+        assert_eq!(network.delivered_by("a"), vec![0, 1, 2]);
+
+        network.event(1, BroadcastEvent::Broadcast("b".into()));
+        network.crash(1, 1);
+        network.detect_crashes();
+        network.run();
+        assert_eq!(network.delivered_by("b"), vec![0, 1]);
+    }
+}
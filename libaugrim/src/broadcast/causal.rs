@@ -0,0 +1,513 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the causal order broadcast algorithms, layered over a reliable broadcast.
+
+use std::iter;
+
+use crate::algorithm::{Algorithm, Value};
+use crate::error::AlgorithmError;
+use crate::message::Stamped;
+use crate::process::Process;
+use crate::time::VectorTimestamp;
+
+use super::{
+    message_dropped, BroadcastAction, BroadcastEvent, BroadcastMessage, BroadcastNotification,
+    MessageId,
+};
+
+/// The value broadcast by [`NoWaitingCausalBroadcast`] over its inner broadcast: a message,
+/// and every message the sender delivered or broadcast before it, in causal order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CausalMessage<P, V> {
+    pub past: Vec<BroadcastMessage<P, V>>,
+    pub message: BroadcastMessage<P, V>,
+}
+
+impl<P: Process, V: Value> Value for CausalMessage<P, V> {}
+
+/// The context of [`NoWaitingCausalBroadcast`], with the context `C` of its inner broadcast.
+///
+/// The causal past, of every message broadcast or delivered, grows with the number of values
+/// broadcast.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NoWaitingCausalContext<P, V, C> {
+    inner: C,
+    this_process: P,
+    sequence: u64,
+    past: Vec<BroadcastMessage<P, V>>,
+    delivered: Vec<MessageId<P>>,
+}
+
+impl<P: Process, V: Value, C> NoWaitingCausalContext<P, V, C> {
+    /// Create the context of `this_process`, with the context of the inner broadcast.
+    pub fn new(this_process: P, inner: C) -> Self {
+        Self {
+            inner,
+            this_process,
+            sequence: 0,
+            past: Vec::new(),
+            delivered: Vec::new(),
+        }
+    }
+
+    /// Returns the context of the inner broadcast.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    /// Returns the messages broadcast or delivered by this process, in causal order.
+    pub fn past(&self) -> &[BroadcastMessage<P, V>] {
+        &self.past
+    }
+
+    /// Returns the identifiers of the messages delivered, in the order they were delivered.
+    pub fn delivered(&self) -> &[MessageId<P>] {
+        &self.delivered
+    }
+
+    // Deliver the message delivered by the inner broadcast, first delivering any message in its
+    // past which has not been delivered.
+    fn deliver<M>(
+        &mut self,
+        causal: CausalMessage<P, V>,
+        actions: &mut Vec<BroadcastAction<P, V, Self, M>>,
+    ) {
+        for message in causal.past.into_iter().chain(iter::once(causal.message)) {
+            if self.delivered.contains(&message.id) {
+                continue;
+            }
+            actions.push(BroadcastAction::Notify(BroadcastNotification::Deliver(
+                message.id.origin.clone(),
+                message.value.clone(),
+            )));
+            self.delivered.push(message.id.clone());
+            if !self.past.iter().any(|past| past.id == message.id) {
+                self.past.push(message);
+            }
+        }
+    }
+}
+
+/// No-waiting causal order broadcast (3.9.1): each message carries the sender's causal past,
+/// every message it broadcast or delivered before, so that a process delivers any of those it
+/// has not delivered immediately before the message itself.
+///
+/// Values are delivered as soon as they are received, at the cost of messages, and contexts,
+/// which grow with the number of values broadcast. The reliability of delivery is that of the
+/// inner broadcast `A`, such as [`EagerReliableBroadcast`](super::EagerReliableBroadcast),
+/// which broadcasts [`CausalMessage`]s.
+pub struct NoWaitingCausalBroadcast<A> {
+    inner: A,
+}
+
+impl<A> NoWaitingCausalBroadcast<A> {
+    pub fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A, P, V, C> Algorithm for NoWaitingCausalBroadcast<A>
+where
+    A: Algorithm<
+        Event = BroadcastEvent<P, CausalMessage<P, V>>,
+        Action = BroadcastAction<P, CausalMessage<P, V>, C>,
+        Context = C,
+    >,
+    P: Process,
+    V: Value,
+    C: Clone,
+{
+    type Event = BroadcastEvent<P, V, BroadcastMessage<P, CausalMessage<P, V>>>;
+    type Action = BroadcastAction<
+        P,
+        V,
+        NoWaitingCausalContext<P, V, C>,
+        BroadcastMessage<P, CausalMessage<P, V>>,
+    >;
+    type Context = NoWaitingCausalContext<P, V, C>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let (inner_event, mut updated) = match event {
+            BroadcastEvent::Broadcast(value) => {
+                let message = BroadcastMessage {
+                    id: MessageId {
+                        origin: context.this_process.clone(),
+                        sequence: context.sequence,
+                    },
+                    value,
+                };
+                context.sequence += 1;
+                let causal = CausalMessage {
+                    past: context.past.clone(),
+                    message: message.clone(),
+                };
+                context.past.push(message);
+                (BroadcastEvent::Broadcast(causal), true)
+            }
+            BroadcastEvent::Deliver(process, message) => {
+                (BroadcastEvent::Deliver(process, message), false)
+            }
+            BroadcastEvent::Crash(process) => (BroadcastEvent::Crash(process), false),
+        };
+
+        let mut actions = Vec::new();
+        let inner = run_inner(&self.inner, inner_event, &mut context.inner, &mut actions)?;
+        // An inner broadcast may deliver without updating its own context, as best-effort
+        // broadcast does, while the delivered messages are recorded here.
+        updated |= inner.updated || !inner.delivered.is_empty();
+        for (origin, causal) in inner.delivered {
+            if causal.message.id.origin != origin {
+                actions.extend(message_dropped("origin does not match the causal message"));
+                continue;
+            }
+            context.deliver(causal, &mut actions);
+        }
+
+        if updated {
+            actions.insert(0, BroadcastAction::Update(context));
+        }
+        Ok(actions)
+    }
+}
+
+/// The context of [`WaitingCausalBroadcast`], with the context `C` of its inner broadcast.
+///
+/// The clock counts the values delivered from each process; values received before their
+/// causal predecessors are kept until those are delivered.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WaitingCausalContext<P: Ord, V, C> {
+    inner: C,
+    this_process: P,
+    sequence: u64,
+    clock: VectorTimestamp<P>,
+    pending: Vec<(P, Stamped<V, VectorTimestamp<P>>)>,
+}
+
+impl<P: Process + Ord, V: Value, C> WaitingCausalContext<P, V, C> {
+    /// Create the context of `this_process`, with the context of the inner broadcast.
+    pub fn new(this_process: P, inner: C) -> Self {
+        Self {
+            inner,
+            this_process,
+            sequence: 0,
+            clock: VectorTimestamp::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Returns the context of the inner broadcast.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    /// Returns the number of values delivered from each process.
+    pub fn clock(&self) -> &VectorTimestamp<P> {
+        &self.clock
+    }
+
+    /// Returns the values received but not yet delivered, with their senders, because a value
+    /// which causally precedes them has not been delivered.
+    pub fn pending(&self) -> &[(P, Stamped<V, VectorTimestamp<P>>)] {
+        &self.pending
+    }
+
+    // Stamp a value broadcast by this process: the values delivered from each other process,
+    // and the number broadcast by this process, before it.
+    fn stamp(&mut self, value: V) -> Stamped<V, VectorTimestamp<P>> {
+        let stamp = self
+            .clock
+            .iter()
+            .filter(|(process, _)| **process != self.this_process)
+            .map(|(process, count)| (process.clone(), count))
+            .chain(iter::once((self.this_process.clone(), self.sequence)))
+            .collect();
+        self.sequence += 1;
+        Stamped::new(stamp, value)
+    }
+
+    // Deliver every pending value whose causal predecessors have all been delivered.
+    fn deliver_pending<M>(&mut self, actions: &mut Vec<BroadcastAction<P, V, Self, M>>) {
+        while let Some(index) = self
+            .pending
+            .iter()
+            .position(|(_, stamped)| stamped.stamp <= self.clock)
+        {
+            let (origin, stamped) = self.pending.remove(index);
+            self.clock.increment(&origin);
+            actions.push(BroadcastAction::Notify(BroadcastNotification::Deliver(
+                origin,
+                stamped.message,
+            )));
+        }
+    }
+}
+
+/// Waiting causal order broadcast (3.9.2): each message is stamped with a vector timestamp of
+/// the values the sender delivered and broadcast before it, and a process holds a received
+/// value back until it has delivered every value counted by the stamp.
+///
+/// Messages only grow with the number of processes, at the cost of waiting for values which
+/// are delivered late. The reliability of delivery is that of the inner broadcast `A`, such
+/// as [`EagerReliableBroadcast`](super::EagerReliableBroadcast), which broadcasts
+/// [`Stamped`] values; it must deliver each value exactly once.
+pub struct WaitingCausalBroadcast<A> {
+    inner: A,
+}
+
+impl<A> WaitingCausalBroadcast<A> {
+    pub fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A, P, V, C> Algorithm for WaitingCausalBroadcast<A>
+where
+    A: Algorithm<
+        Event = BroadcastEvent<P, Stamped<V, VectorTimestamp<P>>>,
+        Action = BroadcastAction<P, Stamped<V, VectorTimestamp<P>>, C>,
+        Context = C,
+    >,
+    P: Process + Ord,
+    V: Value,
+    C: Clone,
+{
+    type Event = BroadcastEvent<P, V, BroadcastMessage<P, Stamped<V, VectorTimestamp<P>>>>;
+    type Action = BroadcastAction<
+        P,
+        V,
+        WaitingCausalContext<P, V, C>,
+        BroadcastMessage<P, Stamped<V, VectorTimestamp<P>>>,
+    >;
+    type Context = WaitingCausalContext<P, V, C>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let (inner_event, mut updated) = match event {
+            BroadcastEvent::Broadcast(value) => {
+                (BroadcastEvent::Broadcast(context.stamp(value)), true)
+            }
+            BroadcastEvent::Deliver(process, message) => {
+                (BroadcastEvent::Deliver(process, message), false)
+            }
+            BroadcastEvent::Crash(process) => (BroadcastEvent::Crash(process), false),
+        };
+
+        let mut actions = Vec::new();
+        let inner = run_inner(&self.inner, inner_event, &mut context.inner, &mut actions)?;
+        updated |= inner.updated;
+        if !inner.delivered.is_empty() {
+            updated = true;
+            context.pending.extend(inner.delivered);
+            context.deliver_pending(&mut actions);
+        }
+
+        if updated {
+            actions.insert(0, BroadcastAction::Update(context));
+        }
+        Ok(actions)
+    }
+}
+
+struct InnerOutcome<P, D> {
+    updated: bool,
+    delivered: Vec<(P, D)>,
+}
+
+// Input the event to the inner broadcast, keeping its updated context. Its messages and
+// notifications of dropped messages are passed on to `actions`; the values it delivers are
+// returned.
+fn run_inner<A, P, D, V, I, C>(
+    inner: &A,
+    event: BroadcastEvent<P, D>,
+    context: &mut I,
+    actions: &mut Vec<BroadcastAction<P, V, C, BroadcastMessage<P, D>>>,
+) -> Result<InnerOutcome<P, D>, AlgorithmError>
+where
+    A: Algorithm<Event = BroadcastEvent<P, D>, Action = BroadcastAction<P, D, I>, Context = I>,
+    I: Clone,
+{
+    let mut outcome = InnerOutcome {
+        updated: false,
+        delivered: Vec::new(),
+    };
+    for action in inner.event(event, context.clone())? {
+        match action {
+            BroadcastAction::Update(updated) => {
+                *context = updated;
+                outcome.updated = true;
+            }
+            BroadcastAction::SendMessage(process, message) => {
+                actions.push(BroadcastAction::SendMessage(process, message))
+            }
+            BroadcastAction::Notify(BroadcastNotification::Deliver(origin, value)) => {
+                outcome.delivered.push((origin, value))
+            }
+            BroadcastAction::Notify(BroadcastNotification::MessageDropped(reason)) => actions.push(
+                BroadcastAction::Notify(BroadcastNotification::MessageDropped(reason)),
+            ),
+        }
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::Network;
+    use super::super::{
+        BestEffortBroadcast, BestEffortContext, EagerReliableBroadcast, ReliableBroadcastContext,
+    };
+    use super::*;
+
+    const PROCESSES: [u32; 3] = [0, 1, 2];
+
+    /// Test that a reply received before the value it replies to is delivered after it, with the
+    /// value delivered from the reply's causal past.
+    #[test]
+    fn test_no_waiting_causal_broadcast() {
+        let mut network = Network::new(
+            NoWaitingCausalBroadcast::new(EagerReliableBroadcast::new()),
+            PROCESSES
+                .iter()
+                .map(|p| {
+                    let inner = ReliableBroadcastContext::new(*p, PROCESSES.to_vec());
+                    (*p, NoWaitingCausalContext::new(*p, inner))
+                })
+                .collect(),
+        );
+        network.event(0, BroadcastEvent::Broadcast("a".into()));
+        network.deliver_first(|from, to, _| from == 0 && to == 1);
+        network.event(1, BroadcastEvent::Broadcast("b".into()));
+
+        network.deliver_first(
+            |from, to, message: &BroadcastMessage<_, CausalMessage<_, _>>| {
+                from == 1 && to == 2 && message.value.message.value == "b"
+            },
+        );
+        assert_eq!(network.delivered_at(2), vec!["a", "b"]);
+
+        network.run();
+        for process in PROCESSES {
+            assert_eq!(network.delivered_at(process), vec!["a", "b"]);
+        }
+    }
+
+    /// Test that a reply received before the value it replies to, and a value received before
+    /// one broadcast earlier by the same process, are held back until their predecessors are
+    /// delivered.
+    #[test]
+    fn test_waiting_causal_broadcast() {
+        let mut network = Network::new(
+            WaitingCausalBroadcast::new(EagerReliableBroadcast::new()),
+            PROCESSES
+                .iter()
+                .map(|p| {
+                    let inner = ReliableBroadcastContext::new(*p, PROCESSES.to_vec());
+                    (*p, WaitingCausalContext::new(*p, inner))
+                })
+                .collect(),
+        );
+        let is_value = |value: &'static str| {
+            move |from, to, message: &BroadcastMessage<u32, Stamped<String, _>>| {
+                from == message.id.origin && to == 2 && message.value.message == value
+            }
+        };
+
+        network.event(0, BroadcastEvent::Broadcast("a".into()));
+        network.deliver_first(|from, to, _| from == 0 && to == 1);
+        network.event(1, BroadcastEvent::Broadcast("b".into()));
+
+        network.deliver_first(is_value("b"));
+        assert!(network.delivered_at(2).is_empty());
+        network.deliver_first(is_value("a"));
+        assert_eq!(network.delivered_at(2), vec!["a", "b"]);
+
+        network.run();
+        for process in PROCESSES {
+            assert_eq!(network.delivered_at(process), vec!["a", "b"]);
+        }
+
+        network.event(0, BroadcastEvent::Broadcast("c".into()));
+        network.event(0, BroadcastEvent::Broadcast("d".into()));
+        network.deliver_first(is_value("d"));
+        assert_eq!(network.delivered_at(2), vec!["a", "b"]);
+        network.deliver_first(is_value("c"));
+        assert_eq!(network.delivered_at(2), vec!["a", "b", "c", "d"]);
+
+        network.run();
+        assert_eq!(network.delivered.len(), 12);
+    }
+
+    /// Test that values delivered by an inner broadcast which does not update its own context
+    /// are still recorded in the stored context.
+    #[test]
+    fn test_update_on_inner_delivery() -> Result<(), AlgorithmError> {
+        let no_waiting = NoWaitingCausalBroadcast::new(BestEffortBroadcast::new());
+        let context = NoWaitingCausalContext::new(1, BestEffortContext::new(1, vec![0, 1]));
+        let causal = CausalMessage {
+            past: vec![],
+            message: message_of("a".to_string()),
+        };
+        let context =
+            update(no_waiting.event(BroadcastEvent::Deliver(0, message_of(causal)), context)?)
+                .expect("no update action");
+        assert_eq!(context.delivered(), [message_of(()).id]);
+
+        let waiting = WaitingCausalBroadcast::new(BestEffortBroadcast::new());
+        let context = WaitingCausalContext::new(1, BestEffortContext::new(1, vec![0, 1]));
+        let stamped = Stamped::new(VectorTimestamp::new(), "a".to_string());
+        let context =
+            update(waiting.event(BroadcastEvent::Deliver(0, message_of(stamped)), context)?)
+                .expect("no update action");
+        assert_eq!(context.clock().get(&0), 1);
+        Ok(())
+    }
+
+    // Returns the context of the update action, if any.
+    fn update<C, M>(actions: Vec<BroadcastAction<u32, String, C, M>>) -> Option<C> {
+        actions.into_iter().find_map(|action| match action {
+            BroadcastAction::Update(context) => Some(context),
+            _ => None,
+        })
+    }
+
+    // Wrap a value as the first message broadcast by process 0.
+    fn message_of<V>(value: V) -> BroadcastMessage<u32, V> {
+        BroadcastMessage {
+            id: MessageId {
+                origin: 0,
+                sequence: 0,
+            },
+            value,
+        }
+    }
+}
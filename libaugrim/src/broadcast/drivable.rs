@@ -0,0 +1,186 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementations of the driver traits, allowing the broadcasts to be run by any driver.
+//!
+//! The broadcasts set no alarms, so their actions are driven with [`SystemTime`], for example
+//! with a [`SystemTimeFactory`](crate::time::SystemTimeFactory) time source.
+
+use std::time::SystemTime;
+
+use crate::algorithm::Value;
+use crate::driver::{DrivableAction, DrivableEvent, DriverAction};
+use crate::process::Process;
+
+use super::{BroadcastAction, BroadcastEvent, BroadcastNotification};
+#[cfg(feature = "algorithm-consensus")]
+use super::{TotalOrderAction, TotalOrderNotification};
+
+impl<P, V, C, M> DrivableAction for BroadcastAction<P, V, C, M>
+where
+    P: Process,
+    V: Value,
+{
+    type Context = C;
+    type Time = SystemTime;
+    type Process = P;
+    type Message = M;
+    type Notification = BroadcastNotification<P, V>;
+
+    fn into_driver_action(
+        self,
+    ) -> DriverAction<Self::Context, Self::Time, Self::Process, Self::Message, Self::Notification>
+    {
+        match self {
+            BroadcastAction::Update(context) => DriverAction::Update {
+                context,
+                alarm: None,
+            },
+            BroadcastAction::SendMessage(to, message) => DriverAction::SendMessage(to, message),
+            BroadcastAction::Notify(notification) => DriverAction::Notify(notification),
+        }
+    }
+}
+
+#[cfg(feature = "algorithm-consensus")]
+impl<P, V, C, M> DrivableAction for TotalOrderAction<P, V, C, M>
+where
+    P: Process,
+    V: Value,
+{
+    type Context = C;
+    type Time = SystemTime;
+    type Process = P;
+    type Message = M;
+    type Notification = TotalOrderNotification<V>;
+
+    fn into_driver_action(
+        self,
+    ) -> DriverAction<Self::Context, Self::Time, Self::Process, Self::Message, Self::Notification>
+    {
+        match self {
+            TotalOrderAction::Update(context) => DriverAction::Update {
+                context,
+                alarm: None,
+            },
+            TotalOrderAction::SendMessage(to, message) => DriverAction::SendMessage(to, message),
+            TotalOrderAction::Notify(notification) => DriverAction::Notify(notification),
+        }
+    }
+}
+
+impl<P, V, M> DrivableEvent for BroadcastEvent<P, V, M>
+where
+    P: Process,
+    V: Value,
+{
+    type Process = P;
+    type Message = M;
+
+    fn alarm() -> Option<Self> {
+        None
+    }
+
+    fn deliver(from: Self::Process, message: Self::Message) -> Self {
+        BroadcastEvent::Deliver(from, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use crate::driver::ThreadDriver;
+    use crate::time::SystemTimeFactory;
+
+    use super::super::{
+        BestEffortBroadcast, BestEffortContext, BroadcastMessage, EagerReliableBroadcast,
+        MessageId, ReliableBroadcastContext,
+    };
+    use super::*;
+
+    /// Test that a broadcast run by a driver sends its messages, delivers its values and stores
+    /// its context.
+    #[test]
+    fn test_thread_driver() -> Result<(), Box<dyn std::error::Error>> {
+        let (messages, outbound) = mpsc::channel();
+        let (notifications, notified) = mpsc::channel();
+
+        let handle = ThreadDriver::new(
+            BestEffortBroadcast::<u32, String>::new(),
+            SystemTimeFactory::new(),
+            messages,
+            notifications,
+        )
+        .spawn(BestEffortContext::new(0, vec![0, 1]), None);
+
+        handle.send(BroadcastEvent::Broadcast("a".into()))?;
+        assert_eq!(
+            notified.recv_timeout(Duration::from_secs(5))?,
+            BroadcastNotification::Deliver(0, "a".into())
+        );
+        let message = BroadcastMessage {
+            id: MessageId {
+                origin: 0,
+                sequence: 0,
+            },
+            value: "a".to_string(),
+        };
+        assert_eq!(outbound.recv_timeout(Duration::from_secs(5))?, (1, message));
+
+        let context = handle.shutdown()?;
+        assert_eq!(context.sequence(), 1);
+        Ok(())
+    }
+
+    /// Test that a value delivered to a driver is delivered once, and relayed.
+    #[test]
+    fn test_thread_driver_deliver() -> Result<(), Box<dyn std::error::Error>> {
+        let (messages, outbound) = mpsc::channel();
+        let (notifications, notified) = mpsc::channel();
+
+        let handle = ThreadDriver::new(
+            EagerReliableBroadcast::<u32, String>::new(),
+            SystemTimeFactory::new(),
+            messages,
+            notifications,
+        )
+        .spawn(ReliableBroadcastContext::new(0, vec![0, 1, 2]), None);
+
+        let message = BroadcastMessage {
+            id: MessageId {
+                origin: 1,
+                sequence: 0,
+            },
+            value: "b".to_string(),
+        };
+        let sender = handle.sender();
+        sender.deliver(1, message.clone())?;
+        sender.deliver(2, message.clone())?;
+        assert_eq!(
+            notified.recv_timeout(Duration::from_secs(5))?,
+            BroadcastNotification::Deliver(1, "b".into())
+        );
+
+        let context = handle.shutdown()?;
+        assert_eq!(
+            outbound.try_iter().collect::<Vec<_>>(),
+            vec![(1, message.clone()), (2, message.clone())]
+        );
+        assert_eq!(context.delivered(), [message]);
+        assert!(notified.try_recv().is_err());
+        Ok(())
+    }
+}
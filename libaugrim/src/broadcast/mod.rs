@@ -0,0 +1,350 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Broadcast algorithms, as described in the following source:
+//!
+//! - Cachin, Guerraoui, and Rodrigues, Reliable and Secure Distributed Programming, 2nd ed., 3.
+//!
+//! Each algorithm broadcasts values to a fixed set of processes, which includes the broadcasting
+//! process:
+//!
+//! - [`BestEffortBroadcast`] (3.2.1): if the sender is correct, every correct process delivers
+//!   the value.
+//! - [`LazyReliableBroadcast`] (3.3.2) and [`EagerReliableBroadcast`] (3.3.3): if any correct
+//!   process delivers a value, every correct process delivers it, even if the sender crashes.
+//!   The lazy algorithm only relays values on learning of a crash, from a perfect failure
+//!   detector; the eager algorithm always relays.
+//! - [`UniformReliableBroadcast`] (3.4.2): if any process delivers a value, even one which then
+//!   crashes, every correct process delivers it. Values are only delivered once every correct
+//!   process has acknowledged them, and crashes are learned from a perfect failure detector.
+//! - [`NoWaitingCausalBroadcast`] (3.9.1) and [`WaitingCausalBroadcast`] (3.9.2): values are
+//!   delivered in causal order, after every value delivered or broadcast by the sender before
+//!   broadcasting them. They are layered over one of the reliable broadcasts, which determines
+//!   their reliability; the no-waiting algorithm sends the causal past with each value, and the
+//!   waiting algorithm a vector timestamp.
+//! - [`TotalOrderBroadcast`] (6.1.3), with the `algorithm-consensus` feature: every process
+//!   delivers the same values in the same order, agreed by a sequence of consensus instances
+//!   over the values received from one of the reliable broadcasts. Its deliveries are reported
+//!   with their position in the order, by [`TotalOrderNotification::Deliver`].
+//!
+//! The algorithms share their events, actions and messages. A value is broadcast with
+//! [`BroadcastEvent::Broadcast`], and messages received from other processes are input with
+//! [`BroadcastEvent::Deliver`]. A perfect failure detector reports crashed processes with
+//! [`BroadcastEvent::Crash`]. Each value delivered is reported with
+//! [`BroadcastNotification::Deliver`], exactly once per process; the broadcasting process
+//! delivers its own values without sending messages to itself.
+//!
+//! An algorithm's `Update` action, if any, comes before its other actions: the context is stored
+//! before any message is sent or value delivered, so that a process which restarts from its
+//! stored context never reuses the identifier of a value it has broadcast, nor delivers a value
+//! twice. With the `driver` feature, the algorithms may be run by any driver.
+//!
+//! The contexts record every value delivered, so that duplicates are recognised; they grow
+//! with the number of values broadcast.
+
+mod best_effort;
+mod causal;
+#[cfg(feature = "driver")]
+mod drivable;
+mod reliable;
+#[cfg(feature = "algorithm-consensus")]
+mod total_order;
+mod uniform;
+
+use crate::algorithm::Value;
+use crate::message::Message;
+use crate::process::Process;
+
+pub use best_effort::{BestEffortBroadcast, BestEffortContext};
+pub use causal::{
+    CausalMessage, NoWaitingCausalBroadcast, NoWaitingCausalContext, WaitingCausalBroadcast,
+    WaitingCausalContext,
+};
+pub use reliable::{EagerReliableBroadcast, LazyReliableBroadcast, ReliableBroadcastContext};
+#[cfg(feature = "algorithm-consensus")]
+pub use total_order::{
+    TotalOrderAction, TotalOrderBroadcast, TotalOrderContext, TotalOrderMessage,
+    TotalOrderNotification,
+};
+pub use uniform::{PendingMessage, UniformReliableBroadcast, UniformReliableBroadcastContext};
+
+/// Identifies a broadcast value: the process which broadcast it, and the number of values that
+/// process had broadcast before it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MessageId<P> {
+    pub origin: P,
+    pub sequence: u64,
+}
+
+/// A broadcast value, as sent between processes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BroadcastMessage<P, V> {
+    pub id: MessageId<P>,
+    pub value: V,
+}
+
+impl<P, V> Message for BroadcastMessage<P, V>
+where
+    P: Process,
+    V: Value,
+{
+}
+
+impl<P, V> Value for BroadcastMessage<P, V>
+where
+    P: Process,
+    V: Value,
+{
+}
+
+/// An event of a broadcast algorithm.
+///
+/// `M` is the message sent between processes: a [`BroadcastMessage`] of the value, or for
+/// algorithms layered over another broadcast, such as the causal broadcasts, a message of the
+/// inner broadcast.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum BroadcastEvent<P, V, M = BroadcastMessage<P, V>> {
+    /// Broadcast the value to every process.
+    Broadcast(V),
+    /// A message was received from the process.
+    Deliver(P, M),
+    /// The perfect failure detector detected that the process crashed.
+    Crash(P),
+}
+
+/// An action of a broadcast algorithm with context `C`; `M` is the message sent between
+/// processes, as for [`BroadcastEvent`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum BroadcastAction<P, V, C, M = BroadcastMessage<P, V>> {
+    Update(C),
+    SendMessage(P, M),
+    Notify(BroadcastNotification<P, V>),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum BroadcastNotification<P, V> {
+    /// Deliver the value, broadcast by the given process.
+    Deliver(P, V),
+    MessageDropped(String),
+}
+
+// Send the message to every process but this one.
+fn send_to_others<P, V, C>(
+    processes: &[P],
+    this_process: &P,
+    message: &BroadcastMessage<P, V>,
+    actions: &mut Vec<BroadcastAction<P, V, C>>,
+) where
+    P: Process,
+    V: Value,
+{
+    for process in processes.iter().filter(|p| *p != this_process) {
+        actions.push(BroadcastAction::SendMessage(
+            process.clone(),
+            message.clone(),
+        ));
+    }
+}
+
+fn message_dropped<P, V, C, M>(reason: &str) -> Vec<BroadcastAction<P, V, C, M>> {
+    vec![BroadcastAction::Notify(
+        BroadcastNotification::MessageDropped(reason.into()),
+    )]
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::VecDeque;
+
+    use crate::algorithm::Algorithm;
+
+    use super::*;
+
+    /// An action in the form handled by a [`Network`], which delivers values tagged with `D`:
+    /// their origin, for a broadcast.
+    pub(crate) enum NetworkStep<C, M, D> {
+        Update(C),
+        SendMessage(u32, M),
+        Deliver(D, String),
+        MessageDropped(String),
+    }
+
+    /// An action of an algorithm which may be run by a [`Network`].
+    pub(crate) trait NetworkAction<C, M, D> {
+        fn into_step(self) -> NetworkStep<C, M, D>;
+    }
+
+    impl<C, M> NetworkAction<C, M, u32> for BroadcastAction<u32, String, C, M> {
+        fn into_step(self) -> NetworkStep<C, M, u32> {
+            match self {
+                BroadcastAction::Update(context) => NetworkStep::Update(context),
+                BroadcastAction::SendMessage(to, message) => NetworkStep::SendMessage(to, message),
+                BroadcastAction::Notify(BroadcastNotification::Deliver(origin, value)) => {
+                    NetworkStep::Deliver(origin, value)
+                }
+                BroadcastAction::Notify(BroadcastNotification::MessageDropped(reason)) => {
+                    NetworkStep::MessageDropped(reason)
+                }
+            }
+        }
+    }
+
+    /// A network of processes running the same broadcast algorithm, delivering messages in the
+    /// order they were sent, unless the sender or the receiver has crashed.
+    pub(crate) struct Network<A: Algorithm, M = BroadcastMessage<u32, String>, D = u32> {
+        algorithm: A,
+        contexts: Vec<(u32, A::Context)>,
+        in_flight: VecDeque<(u32, u32, M)>,
+        crashed: Vec<u32>,
+        pub(crate) delivered: Vec<(u32, D, String)>,
+    }
+
+    impl<A, C, M, D> Network<A, M, D>
+    where
+        A: Algorithm<Event = BroadcastEvent<u32, String, M>, Context = C>,
+        A::Action: NetworkAction<C, M, D>,
+        C: Clone,
+    {
+        pub(crate) fn new(algorithm: A, contexts: Vec<(u32, C)>) -> Self {
+            Self {
+                algorithm,
+                contexts,
+                in_flight: VecDeque::new(),
+                crashed: Vec::new(),
+                delivered: Vec::new(),
+            }
+        }
+
+        pub(crate) fn event(&mut self, process: u32, event: BroadcastEvent<u32, String, M>) {
+            let (_, context) = self
+                .contexts
+                .iter_mut()
+                .find(|(p, _)| *p == process)
+                .expect("unknown process");
+            let steps: Vec<_> = self
+                .algorithm
+                .event(event, context.clone())
+                .expect("event failed")
+                .into_iter()
+                .map(NetworkAction::into_step)
+                .collect();
+            assert!(
+                steps
+                    .iter()
+                    .skip(1)
+                    .all(|step| !matches!(step, NetworkStep::Update(_))),
+                "update is not the first action"
+            );
+            for step in steps {
+                match step {
+                    NetworkStep::Update(next) => *context = next,
+                    NetworkStep::SendMessage(to, message) => {
+                        self.in_flight.push_back((process, to, message))
+                    }
+                    NetworkStep::Deliver(tag, value) => self.delivered.push((process, tag, value)),
+                    NetworkStep::MessageDropped(reason) => panic!("message dropped: {reason}"),
+                }
+            }
+        }
+
+        /// Crash the process, dropping the messages it has sent which are still in flight,
+        /// except for the first `keep`.
+        pub(crate) fn crash(&mut self, process: u32, keep: usize) {
+            self.crashed.push(process);
+            let mut kept = 0;
+            self.in_flight.retain(|(from, _, _)| {
+                if *from != process {
+                    return true;
+                }
+                kept += 1;
+                kept <= keep
+            });
+        }
+
+        /// Report the crashed processes to every correct process.
+        pub(crate) fn detect_crashes(&mut self) {
+            let correct: Vec<u32> = self
+                .contexts
+                .iter()
+                .map(|(p, _)| *p)
+                .filter(|p| !self.crashed.contains(p))
+                .collect();
+            for process in correct {
+                for crashed in self.crashed.clone() {
+                    self.event(process, BroadcastEvent::Crash(crashed));
+                }
+            }
+        }
+
+        /// Deliver messages until none are in flight.
+        pub(crate) fn run(&mut self) {
+            while let Some((from, to, message)) = self.in_flight.pop_front() {
+                if !self.crashed.contains(&to) {
+                    self.event(to, BroadcastEvent::Deliver(from, message));
+                }
+            }
+        }
+
+        /// Deliver messages until none are in flight, the most recently sent first.
+        #[cfg(feature = "algorithm-consensus")]
+        pub(crate) fn run_newest_first(&mut self) {
+            while let Some((from, to, message)) = self.in_flight.pop_back() {
+                if !self.crashed.contains(&to) {
+                    self.event(to, BroadcastEvent::Deliver(from, message));
+                }
+            }
+        }
+
+        /// Deliver the first message in flight which matches the predicate, out of order.
+        pub(crate) fn deliver_first<F>(&mut self, predicate: F)
+        where
+            F: Fn(u32, u32, &M) -> bool,
+        {
+            let index = self
+                .in_flight
+                .iter()
+                .position(|(from, to, message)| predicate(*from, *to, message))
+                .expect("no matching message in flight");
+            if let Some((from, to, message)) = self.in_flight.remove(index) {
+                self.event(to, BroadcastEvent::Deliver(from, message));
+            }
+        }
+
+        /// Returns the values delivered by the process, in the order they were delivered.
+        pub(crate) fn delivered_at(&self, process: u32) -> Vec<&str> {
+            self.delivered
+                .iter()
+                .filter(|(p, _, _)| *p == process)
+                .map(|(_, _, value)| value.as_str())
+                .collect()
+        }
+
+        /// Returns the processes which delivered the value.
+        pub(crate) fn delivered_by(&self, value: &str) -> Vec<u32> {
+            let mut processes: Vec<u32> = self
+                .delivered
+                .iter()
+                .filter(|(_, _, v)| v == value)
+                .map(|(p, _, _)| *p)
+                .collect();
+            processes.sort_unstable();
+            processes
+        }
+    }
+}
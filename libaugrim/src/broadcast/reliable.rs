@@ -0,0 +1,368 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains LazyReliableBroadcast and EagerReliableBroadcast.
+
+use std::marker::PhantomData;
+
+use crate::algorithm::{Algorithm, Value};
+use crate::error::AlgorithmError;
+use crate::process::Process;
+
+use super::{
+    message_dropped, send_to_others, BroadcastAction, BroadcastEvent, BroadcastMessage,
+    BroadcastNotification, MessageId,
+};
+
+/// The context of [`LazyReliableBroadcast`] and [`EagerReliableBroadcast`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ReliableBroadcastContext<P, V> {
+    this_process: P,
+    processes: Vec<P>,
+    correct: Vec<P>,
+    sequence: u64,
+    delivered: Vec<BroadcastMessage<P, V>>,
+}
+
+impl<P: Process, V: Value> ReliableBroadcastContext<P, V> {
+    /// Create the context of `this_process`, which broadcasts to `processes`; `this_process` is
+    /// added to the processes if it is not among them.
+    pub fn new(this_process: P, mut processes: Vec<P>) -> Self {
+        if !processes.contains(&this_process) {
+            processes.push(this_process.clone());
+        }
+        Self {
+            this_process,
+            correct: processes.clone(),
+            processes,
+            sequence: 0,
+            delivered: Vec::new(),
+        }
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    pub fn processes(&self) -> &[P] {
+        &self.processes
+    }
+
+    /// Returns the processes which have not been detected to have crashed.
+    pub fn correct(&self) -> &[P] {
+        &self.correct
+    }
+
+    /// Returns the number of values this process has broadcast.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Returns the messages delivered, in the order they were delivered.
+    pub fn delivered(&self) -> &[BroadcastMessage<P, V>] {
+        &self.delivered
+    }
+
+    fn is_delivered(&self, id: &MessageId<P>) -> bool {
+        self.delivered.iter().any(|message| message.id == *id)
+    }
+
+    // Deliver the message, returning the actions to notify the application.
+    fn deliver<C>(&mut self, message: BroadcastMessage<P, V>) -> Vec<BroadcastAction<P, V, C>> {
+        let notify = BroadcastAction::Notify(BroadcastNotification::Deliver(
+            message.id.origin.clone(),
+            message.value.clone(),
+        ));
+        self.delivered.push(message);
+        vec![notify]
+    }
+
+    // Broadcast a value of this process, delivering it locally.
+    fn broadcast<C>(&mut self, value: V) -> Vec<BroadcastAction<P, V, C>> {
+        let message = BroadcastMessage {
+            id: MessageId {
+                origin: self.this_process.clone(),
+                sequence: self.sequence,
+            },
+            value,
+        };
+        self.sequence += 1;
+
+        let mut actions = self.deliver(message.clone());
+        send_to_others(&self.processes, &self.this_process, &message, &mut actions);
+        actions
+    }
+
+    // Check that a message received from the process is acceptable.
+    fn check_sender(&self, process: &P, message: &BroadcastMessage<P, V>) -> Result<(), &str> {
+        if !self.processes.contains(process) {
+            return Err("sender is not a broadcast process");
+        }
+        if !self.processes.contains(&message.id.origin) {
+            return Err("origin is not a broadcast process");
+        }
+        Ok(())
+    }
+}
+
+/// Lazy reliable broadcast: each process delivers and remembers the values it receives, and
+/// relays the values broadcast by a process once it has been detected to have crashed.
+///
+/// The crashes must be reported by a perfect failure detector, with
+/// [`BroadcastEvent::Crash`]; if a correct process is reported to have crashed, values may be
+/// relayed needlessly, but are still delivered only once.
+pub struct LazyReliableBroadcast<P, V> {
+    _process: PhantomData<P>,
+    _value: PhantomData<V>,
+}
+
+impl<P, V> Default for LazyReliableBroadcast<P, V> {
+    fn default() -> Self {
+        Self {
+            _process: PhantomData,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<P, V> LazyReliableBroadcast<P, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<P, V> Algorithm for LazyReliableBroadcast<P, V>
+where
+    P: Process,
+    V: Value,
+{
+    type Event = BroadcastEvent<P, V>;
+    type Action = BroadcastAction<P, V, ReliableBroadcastContext<P, V>>;
+    type Context = ReliableBroadcastContext<P, V>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        match event {
+            BroadcastEvent::Broadcast(value) => {
+                let mut actions = context.broadcast(value);
+                actions.insert(0, BroadcastAction::Update(context));
+                Ok(actions)
+            }
+            BroadcastEvent::Deliver(process, message) => {
+                if let Err(reason) = context.check_sender(&process, &message) {
+                    return Ok(message_dropped(reason));
+                }
+                if context.is_delivered(&message.id) {
+                    return Ok(vec![]);
+                }
+
+                // The origin may have crashed before sending the value to every process, so it
+                // is relayed.
+                let relay = !context.correct.contains(&message.id.origin);
+                let mut actions = context.deliver(message.clone());
+                if relay {
+                    send_to_others(
+                        &context.processes,
+                        &context.this_process,
+                        &message,
+                        &mut actions,
+                    );
+                }
+                actions.insert(0, BroadcastAction::Update(context));
+                Ok(actions)
+            }
+            BroadcastEvent::Crash(process) => {
+                if !context.correct.contains(&process) {
+                    return Ok(vec![]);
+                }
+                context.correct.retain(|p| *p != process);
+
+                // Relay every value delivered from the crashed process, which may not have sent
+                // it to every process.
+                let mut actions = Vec::new();
+                for message in context
+                    .delivered
+                    .iter()
+                    .filter(|message| message.id.origin == process)
+                {
+                    send_to_others(
+                        &context.processes,
+                        &context.this_process,
+                        message,
+                        &mut actions,
+                    );
+                }
+                actions.insert(0, BroadcastAction::Update(context));
+                Ok(actions)
+            }
+        }
+    }
+}
+
+/// Eager reliable broadcast: each process relays every value the first time it receives it.
+///
+/// No failure detector is needed, at the cost of sending every value from every process; crash
+/// events are ignored.
+pub struct EagerReliableBroadcast<P, V> {
+    _process: PhantomData<P>,
+    _value: PhantomData<V>,
+}
+
+impl<P, V> Default for EagerReliableBroadcast<P, V> {
+    fn default() -> Self {
+        Self {
+            _process: PhantomData,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<P, V> EagerReliableBroadcast<P, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<P, V> Algorithm for EagerReliableBroadcast<P, V>
+where
+    P: Process,
+    V: Value,
+{
+    type Event = BroadcastEvent<P, V>;
+    type Action = BroadcastAction<P, V, ReliableBroadcastContext<P, V>>;
+    type Context = ReliableBroadcastContext<P, V>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        match event {
+            BroadcastEvent::Broadcast(value) => {
+                let mut actions = context.broadcast(value);
+                actions.insert(0, BroadcastAction::Update(context));
+                Ok(actions)
+            }
+            BroadcastEvent::Deliver(process, message) => {
+                if let Err(reason) = context.check_sender(&process, &message) {
+                    return Ok(message_dropped(reason));
+                }
+                if context.is_delivered(&message.id) {
+                    return Ok(vec![]);
+                }
+
+                let mut actions = context.deliver(message.clone());
+                send_to_others(
+                    &context.processes,
+                    &context.this_process,
+                    &message,
+                    &mut actions,
+                );
+                actions.insert(0, BroadcastAction::Update(context));
+                Ok(actions)
+            }
+            BroadcastEvent::Crash(_) => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::Algorithm;
+
+    use super::super::tests::Network;
+    use super::*;
+
+    fn network<A>(algorithm: A) -> Network<A>
+    where
+        A: Algorithm<
+            Event = BroadcastEvent<u32, String>,
+            Action = BroadcastAction<u32, String, ReliableBroadcastContext<u32, String>>,
+            Context = ReliableBroadcastContext<u32, String>,
+        >,
+    {
+        Network::new(
+            algorithm,
+            (0..4)
+                .map(|p| (p, ReliableBroadcastContext::new(p, vec![0, 1, 2, 3])))
+                .collect(),
+        )
+    }
+
+    /// Test that when the sender crashes after sending its value to one process, every correct
+    /// process delivers the value once the crash is detected, exactly once.
+    #[test]
+    fn test_lazy_reliable_broadcast() {
+        let mut network = network(LazyReliableBroadcast::new());
+        network.event(0, BroadcastEvent::Broadcast("a".into()));
+        network.run();
+        assert_eq!(network.delivered_by("a"), vec![0, 1, 2, 3]);
+
+        network.event(0, BroadcastEvent::Broadcast("b".into()));
+        network.crash(0, 1);
+        network.run();
+        assert_eq!(network.delivered_by("b"), vec![0, 1]);
+
+        network.detect_crashes();
+        network.run();
+        assert_eq!(network.delivered_by("b"), vec![0, 1, 2, 3]);
+        assert_eq!(network.delivered.len(), 8);
+    }
+
+    /// Test that when the sender crashes after sending its value to one process, every correct
+    /// process delivers the value, exactly once, without any failure detection.
+    #[test]
+    fn test_eager_reliable_broadcast() {
+        let mut network = network(EagerReliableBroadcast::new());
+        network.event(0, BroadcastEvent::Broadcast("a".into()));
+        network.crash(0, 1);
+        network.run();
+        assert_eq!(network.delivered_by("a"), vec![0, 1, 2, 3]);
+        assert_eq!(network.delivered.len(), 4);
+    }
+
+    /// Test that messages from or originating at unknown processes are dropped.
+    #[test]
+    fn test_unknown_process() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = EagerReliableBroadcast::new();
+        let context = ReliableBroadcastContext::new(0, vec![0, 1]);
+        let message = |origin| BroadcastMessage {
+            id: MessageId {
+                origin,
+                sequence: 0,
+            },
+            value: "a".to_string(),
+        };
+
+        for (from, origin) in [(2, 1), (1, 2)] {
+            assert!(matches!(
+                algorithm
+                    .event(
+                        BroadcastEvent::Deliver(from, message(origin)),
+                        context.clone()
+                    )?
+                    .as_slice(),
+                [BroadcastAction::Notify(
+                    BroadcastNotification::MessageDropped(_)
+                )]
+            ));
+        }
+
+        Ok(())
+    }
+}
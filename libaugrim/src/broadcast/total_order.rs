@@ -0,0 +1,511 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the consensus-based total order broadcast algorithm.
+
+use crate::algorithm::{Algorithm, Value};
+use crate::consensus::{ConsensusAction, ConsensusEvent, ConsensusNotification};
+use crate::error::AlgorithmError;
+use crate::message::Message;
+use crate::process::Process;
+
+use super::{BroadcastAction, BroadcastEvent, BroadcastMessage, BroadcastNotification, MessageId};
+
+/// A message of the total order broadcast: a message of the inner broadcast, or of the
+/// consensus instance of the given round.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TotalOrderMessage<B, M> {
+    Broadcast(B),
+    Consensus(u64, M),
+}
+
+impl<B: Message, M: Message> Message for TotalOrderMessage<B, M> {}
+
+/// An action of the total order broadcast with context `C`; `M` is the message sent between
+/// processes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TotalOrderAction<P, V, C, M> {
+    Update(C),
+    SendMessage(P, M),
+    Notify(TotalOrderNotification<V>),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TotalOrderNotification<V> {
+    /// The value was delivered at the given position of the total order, counting from 0. Every
+    /// process delivers the same values at the same positions.
+    Deliver(u64, V),
+    /// A message was dropped, for the given reason.
+    MessageDropped(String),
+}
+
+/// The context of the total order broadcast, with the contexts `B` of the inner broadcast and
+/// `C` of the consensus, whose messages are `M`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TotalOrderContext<P, V, B, C, M> {
+    broadcast: B,
+    consensus: C,
+    this_process: P,
+    sequence: u64,
+    unordered: Vec<BroadcastMessage<P, V>>,
+    delivered: Vec<MessageId<P>>,
+    round: u64,
+    instance: Option<C>,
+    crashed: Vec<P>,
+    early: Vec<(P, u64, M)>,
+}
+
+impl<P: Process, V: Value, B, C: Clone, M> TotalOrderContext<P, V, B, C, M> {
+    /// Create the context of `this_process`, with the context of the inner broadcast, and the
+    /// context from which the consensus instance of each round starts.
+    pub fn new(this_process: P, broadcast: B, consensus: C) -> Self {
+        Self {
+            broadcast,
+            consensus,
+            this_process,
+            sequence: 0,
+            unordered: Vec::new(),
+            delivered: Vec::new(),
+            round: 0,
+            instance: None,
+            crashed: Vec::new(),
+            early: Vec::new(),
+        }
+    }
+
+    /// Returns the context of the inner broadcast.
+    pub fn broadcast(&self) -> &B {
+        &self.broadcast
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    /// Returns the round, the number of consensus instances decided.
+    pub fn round(&self) -> u64 {
+        self.round
+    }
+
+    /// Returns the context of the consensus instance of the current round, if it has started.
+    pub fn instance(&self) -> Option<&C> {
+        self.instance.as_ref()
+    }
+
+    /// Returns the messages delivered by the inner broadcast but not yet ordered.
+    pub fn unordered(&self) -> &[BroadcastMessage<P, V>] {
+        &self.unordered
+    }
+
+    /// Returns the identifiers of the messages delivered, in total order.
+    pub fn delivered(&self) -> &[MessageId<P>] {
+        &self.delivered
+    }
+
+    // Deliver the values decided by a round's consensus instance which have not been delivered,
+    // in the decided order, and move on to the next round.
+    fn decide<S>(
+        &mut self,
+        decided: Vec<BroadcastMessage<P, V>>,
+        actions: &mut Vec<TotalOrderAction<P, V, Self, S>>,
+    ) {
+        for message in decided {
+            if self.delivered.contains(&message.id) {
+                continue;
+            }
+            actions.push(TotalOrderAction::Notify(TotalOrderNotification::Deliver(
+                self.delivered.len() as u64,
+                message.value,
+            )));
+            self.delivered.push(message.id);
+        }
+        let delivered = &self.delivered;
+        self.unordered
+            .retain(|message| !delivered.contains(&message.id));
+
+        self.round += 1;
+        self.instance = None;
+        let round = self.round;
+        self.early.retain(|(_, r, _)| *r >= round);
+    }
+}
+
+/// Consensus-based total order broadcast (6.1.3): values are broadcast with the inner reliable
+/// broadcast `B`, and each process proposes the values it has received but not delivered to a
+/// consensus instance `A`, one per round; the values decided are delivered, in the decided
+/// order, by every process.
+///
+/// The inner broadcast broadcasts [`BroadcastMessage`]s, so that each value is identified, and
+/// may be any of the reliable broadcasts, such as
+/// [`EagerReliableBroadcast`](super::EagerReliableBroadcast). The consensus decides lists of
+/// them, and may be any algorithm with the events and actions of the
+/// [`consensus`](crate::consensus) module, such as
+/// [`HierarchicalConsensus`](crate::consensus::HierarchicalConsensus); its context given to
+/// [`TotalOrderContext::new`] is cloned to start each round's instance. Crashes are passed on
+/// to both, and to each later instance.
+///
+/// A consensus message for a round which has not started is kept until it starts: a process
+/// starts a round once it has a value to propose.
+pub struct TotalOrderBroadcast<B, A> {
+    broadcast: B,
+    consensus: A,
+}
+
+impl<B, A> TotalOrderBroadcast<B, A> {
+    pub fn new(broadcast: B, consensus: A) -> Self {
+        Self {
+            broadcast,
+            consensus,
+        }
+    }
+}
+
+type InnerMessage<P, V> = BroadcastMessage<P, BroadcastMessage<P, V>>;
+
+type Context<P, V, BC, CC, M> = TotalOrderContext<P, V, BC, CC, M>;
+
+type Actions<P, V, BC, CC, M> =
+    Vec<TotalOrderAction<P, V, Context<P, V, BC, CC, M>, TotalOrderMessage<InnerMessage<P, V>, M>>>;
+
+impl<B, A, P, V, BC, CC, M> TotalOrderBroadcast<B, A>
+where
+    B: Algorithm<
+        Event = BroadcastEvent<P, BroadcastMessage<P, V>>,
+        Action = BroadcastAction<P, BroadcastMessage<P, V>, BC>,
+        Context = BC,
+    >,
+    A: Algorithm<
+        Event = ConsensusEvent<P, Vec<BroadcastMessage<P, V>>, M>,
+        Action = ConsensusAction<P, Vec<BroadcastMessage<P, V>>, CC, M>,
+        Context = CC,
+    >,
+    P: Process,
+    V: Value,
+    BC: Clone,
+    CC: Clone,
+{
+    // Input the event to the inner broadcast, keeping the values it delivers to be ordered.
+    fn broadcast_event(
+        &self,
+        event: BroadcastEvent<P, BroadcastMessage<P, V>>,
+        context: &mut Context<P, V, BC, CC, M>,
+        actions: &mut Actions<P, V, BC, CC, M>,
+    ) -> Result<(), AlgorithmError> {
+        for action in self.broadcast.event(event, context.broadcast.clone())? {
+            match action {
+                BroadcastAction::Update(broadcast) => context.broadcast = broadcast,
+                BroadcastAction::SendMessage(process, message) => actions.push(
+                    TotalOrderAction::SendMessage(process, TotalOrderMessage::Broadcast(message)),
+                ),
+                BroadcastAction::Notify(BroadcastNotification::Deliver(origin, message)) => {
+                    if message.id.origin != origin {
+                        actions.push(TotalOrderAction::Notify(
+                            TotalOrderNotification::MessageDropped(
+                                "origin does not match the broadcast message".into(),
+                            ),
+                        ));
+                    } else if !context.delivered.contains(&message.id)
+                        && !context.unordered.iter().any(|m| m.id == message.id)
+                    {
+                        context.unordered.push(message);
+                    }
+                }
+                BroadcastAction::Notify(BroadcastNotification::MessageDropped(reason)) => actions
+                    .push(TotalOrderAction::Notify(
+                        TotalOrderNotification::MessageDropped(reason),
+                    )),
+            }
+        }
+        Ok(())
+    }
+
+    // Input the event to the consensus instance of the current round, which must have started,
+    // delivering its decision.
+    fn consensus_event(
+        &self,
+        event: ConsensusEvent<P, Vec<BroadcastMessage<P, V>>, M>,
+        context: &mut Context<P, V, BC, CC, M>,
+        actions: &mut Actions<P, V, BC, CC, M>,
+    ) -> Result<(), AlgorithmError> {
+        let instance = match &context.instance {
+            Some(instance) => instance.clone(),
+            None => return Ok(()),
+        };
+        let round = context.round;
+        let mut decided = None;
+        for action in self.consensus.event(event, instance)? {
+            match action {
+                ConsensusAction::Update(instance) => context.instance = Some(instance),
+                ConsensusAction::SendMessage(process, message) => {
+                    actions.push(TotalOrderAction::SendMessage(
+                        process,
+                        TotalOrderMessage::Consensus(round, message),
+                    ))
+                }
+                ConsensusAction::Notify(ConsensusNotification::Decide(values)) => {
+                    decided = Some(values)
+                }
+                ConsensusAction::Notify(ConsensusNotification::MessageDropped(reason)) => actions
+                    .push(TotalOrderAction::Notify(
+                        TotalOrderNotification::MessageDropped(reason),
+                    )),
+            }
+        }
+        if let Some(values) = decided {
+            context.decide(values, actions);
+        }
+        Ok(())
+    }
+
+    // Start the consensus instance of each round in turn while there are values to order,
+    // proposing them, until an instance does not decide immediately.
+    fn start_rounds(
+        &self,
+        context: &mut Context<P, V, BC, CC, M>,
+        actions: &mut Actions<P, V, BC, CC, M>,
+    ) -> Result<(), AlgorithmError> {
+        while context.instance.is_none() && !context.unordered.is_empty() {
+            let round = context.round;
+            context.instance = Some(context.consensus.clone());
+
+            let mut events: Vec<_> = context
+                .crashed
+                .iter()
+                .cloned()
+                .map(ConsensusEvent::Crash)
+                .collect();
+            events.push(ConsensusEvent::Propose(context.unordered.clone()));
+            let (early, later) = context.early.drain(..).partition(|(_, r, _)| *r == round);
+            context.early = later;
+            events.extend(
+                early
+                    .into_iter()
+                    .map(|(process, _, message)| ConsensusEvent::Deliver(process, message)),
+            );
+
+            for event in events {
+                if context.round != round {
+                    break;
+                }
+                self.consensus_event(event, context, actions)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<B, A, P, V, BC, CC, M> Algorithm for TotalOrderBroadcast<B, A>
+where
+    B: Algorithm<
+        Event = BroadcastEvent<P, BroadcastMessage<P, V>>,
+        Action = BroadcastAction<P, BroadcastMessage<P, V>, BC>,
+        Context = BC,
+    >,
+    A: Algorithm<
+        Event = ConsensusEvent<P, Vec<BroadcastMessage<P, V>>, M>,
+        Action = ConsensusAction<P, Vec<BroadcastMessage<P, V>>, CC, M>,
+        Context = CC,
+    >,
+    P: Process,
+    V: Value,
+    BC: Clone,
+    CC: Clone,
+{
+    type Event = BroadcastEvent<P, V, TotalOrderMessage<InnerMessage<P, V>, M>>;
+    type Action = TotalOrderAction<
+        P,
+        V,
+        TotalOrderContext<P, V, BC, CC, M>,
+        TotalOrderMessage<InnerMessage<P, V>, M>,
+    >;
+    type Context = TotalOrderContext<P, V, BC, CC, M>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let mut actions = Vec::new();
+        match event {
+            BroadcastEvent::Broadcast(value) => {
+                let message = BroadcastMessage {
+                    id: MessageId {
+                        origin: context.this_process.clone(),
+                        sequence: context.sequence,
+                    },
+                    value,
+                };
+                context.sequence += 1;
+                self.broadcast_event(
+                    BroadcastEvent::Broadcast(message),
+                    &mut context,
+                    &mut actions,
+                )?;
+            }
+            BroadcastEvent::Deliver(process, TotalOrderMessage::Broadcast(message)) => {
+                self.broadcast_event(
+                    BroadcastEvent::Deliver(process, message),
+                    &mut context,
+                    &mut actions,
+                )?;
+            }
+            BroadcastEvent::Deliver(process, TotalOrderMessage::Consensus(round, message)) => {
+                if round < context.round {
+                    // The instance has decided, and its messages are no longer needed.
+                    return Ok(vec![]);
+                }
+                if round > context.round || context.instance.is_none() {
+                    context.early.push((process, round, message));
+                } else {
+                    self.consensus_event(
+                        ConsensusEvent::Deliver(process, message),
+                        &mut context,
+                        &mut actions,
+                    )?;
+                }
+            }
+            BroadcastEvent::Crash(process) => {
+                if context.crashed.contains(&process) {
+                    return Ok(vec![]);
+                }
+                context.crashed.push(process.clone());
+                self.broadcast_event(
+                    BroadcastEvent::Crash(process.clone()),
+                    &mut context,
+                    &mut actions,
+                )?;
+                self.consensus_event(ConsensusEvent::Crash(process), &mut context, &mut actions)?;
+            }
+        }
+
+        self.start_rounds(&mut context, &mut actions)?;
+        actions.insert(0, TotalOrderAction::Update(context));
+        Ok(actions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::consensus::{HierarchicalConsensus, HierarchicalConsensusContext};
+
+    use super::super::tests::{Network, NetworkAction, NetworkStep};
+    use super::super::{EagerReliableBroadcast, ReliableBroadcastContext};
+    use super::*;
+
+    type TestBroadcast = TotalOrderBroadcast<
+        EagerReliableBroadcast<u32, BroadcastMessage<u32, String>>,
+        HierarchicalConsensus<u32, Vec<BroadcastMessage<u32, String>>>,
+    >;
+    type TestMessage = TotalOrderMessage<
+        InnerMessage<u32, String>,
+        crate::consensus::HierarchicalMessage<Vec<BroadcastMessage<u32, String>>>,
+    >;
+
+    const PROCESSES: [u32; 3] = [0, 1, 2];
+
+    impl<C, M> NetworkAction<C, M, u64> for TotalOrderAction<u32, String, C, M> {
+        fn into_step(self) -> NetworkStep<C, M, u64> {
+            match self {
+                TotalOrderAction::Update(context) => NetworkStep::Update(context),
+                TotalOrderAction::SendMessage(to, message) => NetworkStep::SendMessage(to, message),
+                TotalOrderAction::Notify(TotalOrderNotification::Deliver(position, value)) => {
+                    NetworkStep::Deliver(position, value)
+                }
+                TotalOrderAction::Notify(TotalOrderNotification::MessageDropped(reason)) => {
+                    NetworkStep::MessageDropped(reason)
+                }
+            }
+        }
+    }
+
+    fn network() -> Network<TestBroadcast, TestMessage, u64> {
+        Network::new(
+            TotalOrderBroadcast::new(EagerReliableBroadcast::new(), HierarchicalConsensus::new()),
+            PROCESSES
+                .iter()
+                .map(|p| {
+                    let context = TotalOrderContext::new(
+                        *p,
+                        ReliableBroadcastContext::new(*p, PROCESSES.to_vec()),
+                        HierarchicalConsensusContext::new(*p, PROCESSES.to_vec()),
+                    );
+                    (*p, context)
+                })
+                .collect(),
+        )
+    }
+
+    // Returns the positions and values delivered by the process, in the order they were
+    // delivered.
+    fn order(network: &Network<TestBroadcast, TestMessage, u64>, process: u32) -> Vec<(u64, &str)> {
+        network
+            .delivered
+            .iter()
+            .filter(|(p, _, _)| *p == process)
+            .map(|(_, position, value)| (*position, value.as_str()))
+            .collect()
+    }
+
+    /// Test that values broadcast concurrently by every process are delivered in the same order,
+    /// at the same positions, by every process, whichever order messages arrive in.
+    #[test]
+    fn test_total_order_broadcast() {
+        for newest_first in [false, true] {
+            let mut network = network();
+            for process in PROCESSES {
+                network.event(process, BroadcastEvent::Broadcast(format!("a{process}")));
+            }
+            network.event(2, BroadcastEvent::Broadcast("b2".into()));
+            if newest_first {
+                network.run_newest_first();
+            } else {
+                network.run();
+            }
+
+            let expected = order(&network, 0);
+            assert_eq!(expected.len(), 4);
+            assert!(expected
+                .iter()
+                .enumerate()
+                .all(|(i, (position, _))| *position == i as u64));
+            for process in PROCESSES {
+                assert_eq!(order(&network, process), expected);
+            }
+        }
+    }
+
+    /// Test that when the first process crashes after broadcasting a value to only one other
+    /// process, the remaining processes agree on the order of every value, once the crash is
+    /// detected.
+    #[test]
+    fn test_total_order_broadcast_crash() {
+        let mut network = network();
+        network.event(0, BroadcastEvent::Broadcast("a0".into()));
+        network.event(1, BroadcastEvent::Broadcast("a1".into()));
+        network.crash(0, 1);
+        network.run();
+        network.detect_crashes();
+        network.run();
+
+        let expected = order(&network, 1);
+        let mut values: Vec<&str> = expected.iter().map(|(_, value)| *value).collect();
+        values.sort_unstable();
+        assert_eq!(values, vec!["a0", "a1"]);
+        assert_eq!(order(&network, 2), expected);
+    }
+}
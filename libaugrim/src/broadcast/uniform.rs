@@ -0,0 +1,264 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains UniformReliableBroadcast.
+
+use std::marker::PhantomData;
+
+use crate::algorithm::{Algorithm, Value};
+use crate::error::AlgorithmError;
+use crate::process::Process;
+
+use super::{
+    message_dropped, send_to_others, BroadcastAction, BroadcastEvent, BroadcastMessage,
+    BroadcastNotification, MessageId,
+};
+
+/// A message received but not necessarily delivered, and the processes known to have received
+/// it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PendingMessage<P, V> {
+    pub message: BroadcastMessage<P, V>,
+    pub acks: Vec<P>,
+}
+
+/// The context of [`UniformReliableBroadcast`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct UniformReliableBroadcastContext<P, V> {
+    this_process: P,
+    processes: Vec<P>,
+    correct: Vec<P>,
+    sequence: u64,
+    pending: Vec<PendingMessage<P, V>>,
+    delivered: Vec<MessageId<P>>,
+}
+
+impl<P: Process, V: Value> UniformReliableBroadcastContext<P, V> {
+    /// Create the context of `this_process`, which broadcasts to `processes`; `this_process` is
+    /// added to the processes if it is not among them.
+    pub fn new(this_process: P, mut processes: Vec<P>) -> Self {
+        if !processes.contains(&this_process) {
+            processes.push(this_process.clone());
+        }
+        Self {
+            this_process,
+            correct: processes.clone(),
+            processes,
+            sequence: 0,
+            pending: Vec::new(),
+            delivered: Vec::new(),
+        }
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    pub fn processes(&self) -> &[P] {
+        &self.processes
+    }
+
+    /// Returns the processes which have not been detected to have crashed.
+    pub fn correct(&self) -> &[P] {
+        &self.correct
+    }
+
+    /// Returns the number of values this process has broadcast.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Returns the messages received, whether or not they have been delivered.
+    pub fn pending(&self) -> &[PendingMessage<P, V>] {
+        &self.pending
+    }
+
+    /// Returns the identifiers of the messages delivered, in the order they were delivered.
+    pub fn delivered(&self) -> &[MessageId<P>] {
+        &self.delivered
+    }
+
+    // Deliver each pending message acknowledged by every correct process, returning the actions
+    // to notify the application.
+    fn deliver_acknowledged<C>(&mut self) -> Vec<BroadcastAction<P, V, C>> {
+        let mut actions = Vec::new();
+        for pending in &self.pending {
+            if self.delivered.contains(&pending.message.id)
+                || !self.correct.iter().all(|p| pending.acks.contains(p))
+            {
+                continue;
+            }
+            self.delivered.push(pending.message.id.clone());
+            actions.push(BroadcastAction::Notify(BroadcastNotification::Deliver(
+                pending.message.id.origin.clone(),
+                pending.message.value.clone(),
+            )));
+        }
+        actions
+    }
+}
+
+/// All-ack uniform reliable broadcast: each process relays every value the first time it
+/// receives it, which acknowledges it, and delivers the value once every process which has not
+/// crashed has acknowledged it.
+///
+/// The crashes must be reported by a perfect failure detector, with
+/// [`BroadcastEvent::Crash`]. Until a crashed process is reported, the values it has not
+/// acknowledged are not delivered.
+pub struct UniformReliableBroadcast<P, V> {
+    _process: PhantomData<P>,
+    _value: PhantomData<V>,
+}
+
+impl<P, V> Default for UniformReliableBroadcast<P, V> {
+    fn default() -> Self {
+        Self {
+            _process: PhantomData,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<P, V> UniformReliableBroadcast<P, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<P, V> Algorithm for UniformReliableBroadcast<P, V>
+where
+    P: Process,
+    V: Value,
+{
+    type Event = BroadcastEvent<P, V>;
+    type Action = BroadcastAction<P, V, UniformReliableBroadcastContext<P, V>>;
+    type Context = UniformReliableBroadcastContext<P, V>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let mut actions = Vec::new();
+
+        match event {
+            BroadcastEvent::Broadcast(value) => {
+                let message = BroadcastMessage {
+                    id: MessageId {
+                        origin: context.this_process.clone(),
+                        sequence: context.sequence,
+                    },
+                    value,
+                };
+                context.sequence += 1;
+
+                send_to_others(
+                    &context.processes,
+                    &context.this_process,
+                    &message,
+                    &mut actions,
+                );
+                context.pending.push(PendingMessage {
+                    message,
+                    acks: vec![context.this_process.clone()],
+                });
+            }
+            BroadcastEvent::Deliver(process, message) => {
+                if !context.processes.contains(&process) {
+                    return Ok(message_dropped("sender is not a broadcast process"));
+                }
+                if !context.processes.contains(&message.id.origin) {
+                    return Ok(message_dropped("origin is not a broadcast process"));
+                }
+
+                match context
+                    .pending
+                    .iter_mut()
+                    .find(|pending| pending.message.id == message.id)
+                {
+                    Some(pending) if pending.acks.contains(&process) => return Ok(vec![]),
+                    Some(pending) => pending.acks.push(process),
+                    None => {
+                        // Relaying the message acknowledges it.
+                        send_to_others(
+                            &context.processes,
+                            &context.this_process,
+                            &message,
+                            &mut actions,
+                        );
+                        let mut acks = vec![process];
+                        if acks[0] != context.this_process {
+                            acks.push(context.this_process.clone());
+                        }
+                        context.pending.push(PendingMessage { message, acks });
+                    }
+                }
+            }
+            BroadcastEvent::Crash(process) => {
+                if !context.correct.contains(&process) {
+                    return Ok(vec![]);
+                }
+                context.correct.retain(|p| *p != process);
+            }
+        }
+
+        actions.extend(context.deliver_acknowledged());
+        actions.insert(0, BroadcastAction::Update(context));
+        Ok(actions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::Network;
+    use super::*;
+
+    fn network() -> Network<UniformReliableBroadcast<u32, String>> {
+        Network::new(
+            UniformReliableBroadcast::new(),
+            (0..3)
+                .map(|p| (p, UniformReliableBroadcastContext::new(p, vec![0, 1, 2])))
+                .collect(),
+        )
+    }
+
+    /// Test that a value is only delivered once every process has acknowledged it, and then by
+    /// every process.
+    #[test]
+    fn test_uniform_reliable_broadcast() {
+        let mut network = network();
+        network.event(0, BroadcastEvent::Broadcast("a".into()));
+        assert_eq!(network.delivered_by("a"), Vec::<u32>::new());
+
+        network.run();
+        assert_eq!(network.delivered_by("a"), vec![0, 1, 2]);
+        assert_eq!(network.delivered.len(), 3);
+    }
+
+    /// Test that a value which a crashed process never acknowledged is delivered once the crash
+    /// is detected.
+    #[test]
+    fn test_crash() {
+        let mut network = network();
+        network.crash(2, 0);
+        network.event(0, BroadcastEvent::Broadcast("a".into()));
+        network.run();
+        assert_eq!(network.delivered_by("a"), Vec::<u32>::new());
+
+        network.detect_crashes();
+        assert_eq!(network.delivered_by("a"), vec![0, 1]);
+    }
+}
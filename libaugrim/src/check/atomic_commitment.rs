@@ -0,0 +1,479 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains a checker for the atomic commitment properties of a run.
+//!
+//! A [`CommitmentRun`] records the votes cast and the decisions reached by every process, in each
+//! epoch of a run. [`check`] verifies the properties of atomic commitment given by Bernstein,
+//! Hadzilacos and Goodman in _Concurrency Control and Recovery in Database Systems_:
+//!
+//! - AC1 (agreement): all processes which reach a decision reach the same one.
+//! - AC2: a process cannot reverse its decision after it has reached one.
+//! - AC3 (validity): the Commit decision can only be reached if all processes voted Yes.
+//! - AC4 (non-triviality): if there are no failures and all processes voted Yes, the decision
+//!   will be Commit.
+//! - AC5 (termination): once all failures are repaired and no new failures occur for
+//!   sufficiently long, all processes will reach a decision.
+//!
+//! AC4 and AC5 depend on the failures during the run, which are described by [`Failures`].
+//!
+//! With the `trace` and `algorithm-two-phase-commit` features, a run can be built from the
+//! [`Trace`](crate::trace::Trace) of a two-phase commit cluster, such as that of a simulation or
+//! one deserialized from a production trace dump. Each accepted `Vote` event is a vote in the
+//! epoch of the context it was processed with, and each `Commit` or `Abort` notification is a
+//! decision.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// The failures which occurred during a run; AC4 and AC5 only apply to some runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Failures {
+    /// No process crashed and no message was lost; AC1 to AC5 are checked.
+    None,
+    /// Failures occurred, but were repaired long enough before the end of the run for every
+    /// process to decide; AC1, AC2, AC3 and AC5 are checked.
+    Repaired,
+    /// Failures may be outstanding at the end of the run; AC1, AC2 and AC3 are checked.
+    #[default]
+    Outstanding,
+}
+
+/// A vote cast by a process.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Vote<P> {
+    pub process: P,
+    pub epoch: u64,
+    /// `true` for Yes, and `false` for No.
+    pub vote: bool,
+}
+
+/// A decision reached by a process.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Decision<P> {
+    pub process: P,
+    pub epoch: u64,
+    /// `true` for Commit, and `false` for Abort.
+    pub commit: bool,
+}
+
+/// The processes of a run, and the votes and decisions of each, in the order they occurred.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CommitmentRun<P: Ord> {
+    processes: BTreeSet<P>,
+    votes: Vec<Vote<P>>,
+    decisions: Vec<Decision<P>>,
+    failures: Failures,
+}
+
+impl<P: Ord> Default for CommitmentRun<P> {
+    fn default() -> Self {
+        Self {
+            processes: BTreeSet::new(),
+            votes: Vec::new(),
+            decisions: Vec::new(),
+            failures: Failures::default(),
+        }
+    }
+}
+
+impl<P: Ord + Clone> CommitmentRun<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Describe the failures which occurred during the run.
+    pub fn with_failures(mut self, failures: Failures) -> Self {
+        self.failures = failures;
+        self
+    }
+
+    /// Add a process to the run. Processes which vote or decide are added implicitly; a process
+    /// which did neither must be added for AC3 and AC4 to account for its missing vote.
+    pub fn add_process(&mut self, process: P) {
+        self.processes.insert(process);
+    }
+
+    /// Record that the process voted in the epoch.
+    pub fn vote(&mut self, process: P, epoch: u64, vote: bool) {
+        self.processes.insert(process.clone());
+        self.votes.push(Vote {
+            process,
+            epoch,
+            vote,
+        });
+    }
+
+    /// Record that the process decided in the epoch.
+    pub fn decide(&mut self, process: P, epoch: u64, commit: bool) {
+        self.processes.insert(process.clone());
+        self.decisions.push(Decision {
+            process,
+            epoch,
+            commit,
+        });
+    }
+
+    pub fn processes(&self) -> &BTreeSet<P> {
+        &self.processes
+    }
+
+    pub fn votes(&self) -> &[Vote<P>] {
+        &self.votes
+    }
+
+    pub fn decisions(&self) -> &[Decision<P>] {
+        &self.decisions
+    }
+
+    pub fn failures(&self) -> Failures {
+        self.failures
+    }
+}
+
+/// A violation of one of the atomic commitment properties.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Violation<P> {
+    /// AC1: some processes committed the epoch and others aborted it.
+    Agreement {
+        epoch: u64,
+        committed: Vec<P>,
+        aborted: Vec<P>,
+    },
+    /// AC2: the process reached a decision, and later the opposite one.
+    Reversal { epoch: u64, process: P },
+    /// AC3: the epoch was committed, but the given processes did not vote Yes.
+    Validity { epoch: u64, not_yes: Vec<P> },
+    /// AC4: every process voted Yes and there were no failures, but the given processes aborted.
+    NonTriviality { epoch: u64, aborted: Vec<P> },
+    /// AC5: the given processes voted in the epoch but never decided.
+    Termination { epoch: u64, undecided: Vec<P> },
+}
+
+impl<P> Violation<P> {
+    /// Returns the name of the violated property, `AC1` to `AC5`.
+    pub fn property(&self) -> &'static str {
+        match self {
+            Violation::Agreement { .. } => "AC1",
+            Violation::Reversal { .. } => "AC2",
+            Violation::Validity { .. } => "AC3",
+            Violation::NonTriviality { .. } => "AC4",
+            Violation::Termination { .. } => "AC5",
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        match self {
+            Violation::Agreement { epoch, .. }
+            | Violation::Reversal { epoch, .. }
+            | Violation::Validity { epoch, .. }
+            | Violation::NonTriviality { epoch, .. }
+            | Violation::Termination { epoch, .. } => *epoch,
+        }
+    }
+}
+
+impl<P: fmt::Debug> fmt::Display for Violation<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} violated in epoch {}: ",
+            self.property(),
+            self.epoch()
+        )?;
+        match self {
+            Violation::Agreement {
+                committed, aborted, ..
+            } => write!(f, "{committed:?} committed but {aborted:?} aborted"),
+            Violation::Reversal { process, .. } => {
+                write!(f, "{process:?} reversed its decision")
+            }
+            Violation::Validity { not_yes, .. } => {
+                write!(f, "committed although {not_yes:?} did not vote yes")
+            }
+            Violation::NonTriviality { aborted, .. } => write!(
+                f,
+                "{aborted:?} aborted although all processes voted yes without failures"
+            ),
+            Violation::Termination { undecided, .. } => {
+                write!(f, "{undecided:?} voted but never decided")
+            }
+        }
+    }
+}
+
+/// Check the run against the atomic commitment properties which apply to it, returning the
+/// violations found, by epoch; the run satisfies the properties if none are returned.
+pub fn check<P: Ord + Clone>(run: &CommitmentRun<P>) -> Vec<Violation<P>> {
+    struct EpochRecord<P> {
+        yes: BTreeSet<P>,
+        no: BTreeSet<P>,
+        // Each process's first decision.
+        decided: BTreeMap<P, bool>,
+        reversed: BTreeSet<P>,
+    }
+
+    impl<P> Default for EpochRecord<P> {
+        fn default() -> Self {
+            Self {
+                yes: BTreeSet::new(),
+                no: BTreeSet::new(),
+                decided: BTreeMap::new(),
+                reversed: BTreeSet::new(),
+            }
+        }
+    }
+
+    let mut epochs: BTreeMap<u64, EpochRecord<P>> = BTreeMap::new();
+    for vote in &run.votes {
+        let record = epochs.entry(vote.epoch).or_default();
+        if vote.vote {
+            record.yes.insert(vote.process.clone());
+        } else {
+            record.no.insert(vote.process.clone());
+        }
+    }
+    for decision in &run.decisions {
+        let record = epochs.entry(decision.epoch).or_default();
+        match record.decided.get(&decision.process) {
+            Some(commit) if *commit != decision.commit => {
+                record.reversed.insert(decision.process.clone());
+            }
+            Some(_) => (),
+            None => {
+                record
+                    .decided
+                    .insert(decision.process.clone(), decision.commit);
+            }
+        }
+    }
+
+    let mut violations = Vec::new();
+    for (epoch, record) in epochs {
+        let decided = |commit: bool| -> Vec<P> {
+            record
+                .decided
+                .iter()
+                .filter(|(_, c)| **c == commit)
+                .map(|(process, _)| process.clone())
+                .collect()
+        };
+        let committed = decided(true);
+        let aborted = decided(false);
+        let not_yes: Vec<P> = run
+            .processes
+            .iter()
+            .filter(|process| !record.yes.contains(*process) || record.no.contains(*process))
+            .cloned()
+            .collect();
+
+        if !committed.is_empty() && !aborted.is_empty() {
+            violations.push(Violation::Agreement {
+                epoch,
+                committed: committed.clone(),
+                aborted: aborted.clone(),
+            });
+        }
+
+        violations.extend(record.reversed.iter().map(|process| Violation::Reversal {
+            epoch,
+            process: process.clone(),
+        }));
+
+        if !committed.is_empty() && !not_yes.is_empty() {
+            violations.push(Violation::Validity { epoch, not_yes });
+        } else if run.failures == Failures::None && not_yes.is_empty() && !aborted.is_empty() {
+            violations.push(Violation::NonTriviality { epoch, aborted });
+        }
+
+        if run.failures != Failures::Outstanding {
+            let undecided: Vec<P> = record
+                .yes
+                .union(&record.no)
+                .filter(|process| !record.decided.contains_key(*process))
+                .cloned()
+                .collect();
+            if !undecided.is_empty() {
+                violations.push(Violation::Termination { epoch, undecided });
+            }
+        }
+    }
+
+    violations
+}
+
+#[cfg(all(feature = "trace", feature = "algorithm-two-phase-commit"))]
+mod two_phase_commit {
+    use crate::algorithm::Value;
+    use crate::process::Process;
+    use crate::time::Time;
+    use crate::trace::Trace;
+    use crate::two_phase_commit::{
+        TwoPhaseCommitAction, TwoPhaseCommitActionNotification, TwoPhaseCommitContext,
+        TwoPhaseCommitEvent,
+    };
+
+    use super::CommitmentRun;
+
+    type TwoPhaseCommitTrace<P, V, T> = Trace<
+        P,
+        TwoPhaseCommitEvent<P, V>,
+        TwoPhaseCommitContext<P, V, T>,
+        TwoPhaseCommitAction<P, V, T>,
+        T,
+    >;
+
+    /// Builds the run of a two-phase commit cluster from its trace. The failures of the run are
+    /// not known from the trace, and must be set with [`CommitmentRun::with_failures`].
+    impl<P, V, T> From<&TwoPhaseCommitTrace<P, V, T>> for CommitmentRun<P>
+    where
+        P: Process + Ord,
+        V: Value,
+        T: Time,
+    {
+        fn from(trace: &TwoPhaseCommitTrace<P, V, T>) -> Self {
+            let mut run = CommitmentRun::new();
+
+            for step in trace.steps() {
+                run.add_process(step.process.clone());
+
+                if let (TwoPhaseCommitEvent::Vote(vote), None) = (&step.event, &step.error) {
+                    run.vote(step.process.clone(), *step.context.epoch(), *vote);
+                }
+
+                for action in &step.actions {
+                    match action {
+                        TwoPhaseCommitAction::Notify(TwoPhaseCommitActionNotification::Commit(
+                            epoch,
+                            _,
+                        )) => run.decide(step.process.clone(), *epoch, true),
+                        TwoPhaseCommitAction::Notify(TwoPhaseCommitActionNotification::Abort(
+                            epoch,
+                            _,
+                        )) => run.decide(step.process.clone(), *epoch, false),
+                        _ => (),
+                    }
+                }
+            }
+
+            run
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that a run in which every process commits after voting yes, and every process
+    /// aborts after one voted no, satisfies every property.
+    #[test]
+    fn test_valid_run() {
+        let mut run = CommitmentRun::new().with_failures(Failures::None);
+        for process in ["c", "p1", "p2"] {
+            run.vote(process, 1, true);
+        }
+        for process in ["c", "p1", "p2"] {
+            run.decide(process, 1, true);
+        }
+        run.vote("c", 2, true);
+        run.vote("p1", 2, false);
+        run.decide("p1", 2, false);
+        run.decide("c", 2, false);
+        // p2 was never asked for its vote, and so need not decide.
+        run.decide("p1", 2, false);
+
+        assert_eq!(check(&run), vec![]);
+    }
+
+    /// Test that each property's violation is reported.
+    #[test]
+    fn test_violations() {
+        let mut run = CommitmentRun::new().with_failures(Failures::None);
+        run.add_process("p2");
+        run.vote("c", 1, true);
+        run.vote("p1", 1, true);
+        run.decide("c", 1, true);
+        run.decide("p1", 1, false);
+        run.decide("p1", 1, true);
+
+        run.vote("c", 2, true);
+        run.vote("p1", 2, true);
+        run.vote("p2", 2, true);
+        run.decide("c", 2, false);
+
+        let violations = check(&run);
+        assert_eq!(
+            violations,
+            vec![
+                Violation::Agreement {
+                    epoch: 1,
+                    committed: vec!["c"],
+                    aborted: vec!["p1"],
+                },
+                Violation::Reversal {
+                    epoch: 1,
+                    process: "p1"
+                },
+                Violation::Validity {
+                    epoch: 1,
+                    not_yes: vec!["p2"]
+                },
+                Violation::NonTriviality {
+                    epoch: 2,
+                    aborted: vec!["c"]
+                },
+                Violation::Termination {
+                    epoch: 2,
+                    undecided: vec!["p1", "p2"]
+                },
+            ]
+        );
+        assert_eq!(
+            violations[2].to_string(),
+            "AC3 violated in epoch 1: committed although [\"p2\"] did not vote yes"
+        );
+
+        // With failures outstanding, only AC1 to AC3 apply.
+        let run = run.with_failures(Failures::Outstanding);
+        assert_eq!(
+            check(&run)
+                .iter()
+                .map(Violation::property)
+                .collect::<Vec<_>>(),
+            vec!["AC1", "AC2", "AC3"]
+        );
+    }
+
+    /// Test that a simulated two-phase commit run, built from its trace, satisfies every
+    /// property.
+    #[cfg(all(feature = "sim", feature = "algorithm-two-phase-commit"))]
+    #[test]
+    fn test_two_phase_commit_trace() {
+        use std::time::Duration;
+
+        let mut simulation = crate::sim::tests::simulation(3, 2);
+        simulation.run_for(Duration::from_secs(60));
+
+        let run = CommitmentRun::from(simulation.trace()).with_failures(Failures::None);
+        assert_eq!(run.processes().len(), 3);
+        assert_eq!(run.votes().len(), 3);
+        assert_eq!(run.decisions().len(), 3);
+        assert_eq!(check(&run), vec![]);
+    }
+}
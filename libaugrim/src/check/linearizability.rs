@@ -0,0 +1,541 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains a linearizability checker for the histories observed by clients of replicated state.
+//!
+//! A [`History`] records, in real-time order, the invocation and completion of each client's
+//! operations. [`check`] searches for a linearization: a sequential order of the operations,
+//! consistent with their real-time order, in which a [`Model`] of the replicated state returns
+//! the results the clients observed. Operations which were invoked but never completed may have
+//! taken effect at any point after their invocation, or not at all.
+//!
+//! The search is the Wing–Gong algorithm, with the memoization of Lowe's refinement: a
+//! combination of linearized operations and model state is only explored once.
+//!
+//! ```
+//! use augrim::check::linearizability::{check, History, Register, RegisterOp, RegisterRet};
+//!
+//! let mut history = History::new();
+//! history.invoke("a", RegisterOp::Write(1));
+//! history.invoke("b", RegisterOp::Read);
+//! history.complete(&"b", RegisterRet::Value(Some(1)));
+//! history.complete(&"a", RegisterRet::Ok);
+//!
+//! let linearizability = check(&Register::new(), &history).unwrap();
+//! assert!(linearizability.is_linearizable());
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::error::InvalidStateError;
+
+/// A sequential specification of replicated state.
+pub trait Model {
+    /// An operation invoked by a client.
+    type Op;
+    /// The result of an operation, as observed by the client.
+    type Ret: PartialEq;
+    /// The state of the model.
+    type State: Clone + Eq + Hash;
+
+    /// Returns the initial state.
+    fn init(&self) -> Self::State;
+
+    /// Apply the operation to the state, returning the next state and the operation's result.
+    fn step(&self, state: &Self::State, op: &Self::Op) -> (Self::State, Self::Ret);
+}
+
+/// An event in a [`History`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum HistoryEvent<C, O, R> {
+    /// A client invoked an operation.
+    Invoke { client: C, op: O },
+    /// A client's outstanding operation completed with a result.
+    Complete { client: C, ret: R },
+}
+
+/// The invocations and completions of clients' operations, in real-time order.
+///
+/// Each client has at most one outstanding operation: a client invokes an operation, and then
+/// observes its completion before invoking the next.
+#[derive(Clone, Debug, PartialEq)]
+pub struct History<C, O, R> {
+    events: Vec<HistoryEvent<C, O, R>>,
+}
+
+impl<C, O, R> Default for History<C, O, R> {
+    fn default() -> Self {
+        Self { events: Vec::new() }
+    }
+}
+
+impl<C, O, R> From<Vec<HistoryEvent<C, O, R>>> for History<C, O, R> {
+    fn from(events: Vec<HistoryEvent<C, O, R>>) -> Self {
+        Self { events }
+    }
+}
+
+impl<C, O, R> History<C, O, R> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that the client invoked an operation.
+    pub fn invoke(&mut self, client: C, op: O) {
+        self.events.push(HistoryEvent::Invoke { client, op });
+    }
+
+    /// Record that the client's outstanding operation completed.
+    pub fn complete(&mut self, client: &C, ret: R)
+    where
+        C: Clone,
+    {
+        self.events.push(HistoryEvent::Complete {
+            client: client.clone(),
+            ret,
+        });
+    }
+
+    pub fn events(&self) -> &[HistoryEvent<C, O, R>] {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the operations of the history, pairing each invocation with its completion.
+    ///
+    /// Returns an error if a client completes an operation without one outstanding, or invokes
+    /// an operation while another is outstanding.
+    pub fn operations(&self) -> Result<Vec<Operation<'_, C, O, R>>, InvalidStateError>
+    where
+        C: Eq + Hash + Debug,
+    {
+        let mut operations: Vec<Operation<C, O, R>> = Vec::new();
+        let mut outstanding: HashMap<&C, usize> = HashMap::new();
+
+        for (index, event) in self.events.iter().enumerate() {
+            match event {
+                HistoryEvent::Invoke { client, op } => {
+                    if outstanding.insert(client, operations.len()).is_some() {
+                        return Err(InvalidStateError::with_message(format!(
+                            "client {client:?} invoked an operation at event {index} with \
+                             another outstanding"
+                        )));
+                    }
+                    operations.push(Operation {
+                        client,
+                        op,
+                        ret: None,
+                        invoked: index,
+                        completed: None,
+                    });
+                }
+                HistoryEvent::Complete { client, ret } => {
+                    let operation = outstanding.remove(client).ok_or_else(|| {
+                        InvalidStateError::with_message(format!(
+                            "client {client:?} completed an operation at event {index} with \
+                             none outstanding"
+                        ))
+                    })?;
+                    operations[operation].ret = Some(ret);
+                    operations[operation].completed = Some(index);
+                }
+            }
+        }
+
+        Ok(operations)
+    }
+
+    /// Returns the history restricted to the given operations, as indexed by
+    /// [`History::operations`].
+    fn subhistory(&self, operations: &[Operation<C, O, R>], keep: &[usize]) -> Self
+    where
+        C: Clone,
+        O: Clone,
+        R: Clone,
+    {
+        let mut events: Vec<usize> = keep
+            .iter()
+            .flat_map(|&i| std::iter::once(operations[i].invoked).chain(operations[i].completed))
+            .collect();
+        events.sort_unstable();
+        Self {
+            events: events.into_iter().map(|i| self.events[i].clone()).collect(),
+        }
+    }
+}
+
+/// An operation of a [`History`]: its invocation and, if it completed, its result.
+#[derive(Debug, PartialEq)]
+pub struct Operation<'a, C, O, R> {
+    pub client: &'a C,
+    pub op: &'a O,
+    /// The result, or `None` if the operation never completed.
+    pub ret: Option<&'a R>,
+    /// The index of the invocation in the history's events.
+    pub invoked: usize,
+    /// The index of the completion in the history's events, if the operation completed.
+    pub completed: Option<usize>,
+}
+
+/// The outcome of checking a history.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Linearizability<C, O, R> {
+    /// The history is linearizable.
+    Linearizable {
+        /// A linearization, as indices into [`History::operations`]. Operations which never
+        /// completed, and which need not have taken effect, are omitted.
+        witness: Vec<usize>,
+    },
+    /// The history is not linearizable.
+    NotLinearizable {
+        /// A subhistory which is not linearizable, but which is linearizable if any one of its
+        /// operations is removed.
+        subhistory: History<C, O, R>,
+    },
+}
+
+impl<C, O, R> Linearizability<C, O, R> {
+    pub fn is_linearizable(&self) -> bool {
+        matches!(self, Linearizability::Linearizable { .. })
+    }
+}
+
+/// Check whether the history is linearizable with respect to the model.
+///
+/// Returns an error if the history is malformed; see [`History::operations`].
+pub fn check<M, C>(
+    model: &M,
+    history: &History<C, M::Op, M::Ret>,
+) -> Result<Linearizability<C, M::Op, M::Ret>, InvalidStateError>
+where
+    M: Model,
+    M::Op: Clone,
+    M::Ret: Clone,
+    C: Clone + Eq + Hash + Debug,
+{
+    let operations = history.operations()?;
+    let all: Vec<usize> = (0..operations.len()).collect();
+
+    if let Some(witness) = linearize(model, &operations, &all) {
+        return Ok(Linearizability::Linearizable { witness });
+    }
+
+    // Shrink to a minimal subhistory by removing each operation in turn, keeping it removed if
+    // the remainder is still not linearizable.
+    let mut keep = all;
+    let mut i = 0;
+    while i < keep.len() {
+        let mut candidate = keep.clone();
+        candidate.remove(i);
+        if linearize(model, &operations, &candidate).is_none() {
+            keep = candidate;
+        } else {
+            i += 1;
+        }
+    }
+
+    Ok(Linearizability::NotLinearizable {
+        subhistory: history.subhistory(&operations, &keep),
+    })
+}
+
+/// Search for a linearization of the given operations, returning it if one exists.
+fn linearize<M, C>(
+    model: &M,
+    operations: &[Operation<C, M::Op, M::Ret>],
+    subset: &[usize],
+) -> Option<Vec<usize>>
+where
+    M: Model,
+{
+    let mut search = Search {
+        model,
+        operations,
+        subset,
+        linearized: vec![false; subset.len()],
+        witness: Vec::with_capacity(subset.len()),
+        visited: HashSet::new(),
+    };
+    if search.run(model.init()) {
+        Some(search.witness)
+    } else {
+        None
+    }
+}
+
+struct Search<'a, 'h, M: Model, C> {
+    model: &'a M,
+    operations: &'a [Operation<'h, C, M::Op, M::Ret>],
+    subset: &'a [usize],
+    /// Whether each operation of the subset has been linearized.
+    linearized: Vec<bool>,
+    witness: Vec<usize>,
+    /// The combinations of linearized operations and state which have already been explored.
+    visited: HashSet<(Vec<bool>, M::State)>,
+}
+
+impl<M: Model, C> Search<'_, '_, M, C> {
+    fn run(&mut self, state: M::State) -> bool {
+        // An operation may be linearized next if it was invoked before the earliest completion
+        // of the operations not yet linearized.
+        let earliest_completion = self
+            .remaining()
+            .filter_map(|i| self.operations[self.subset[i]].completed)
+            .min();
+        let Some(earliest_completion) = earliest_completion else {
+            // Every completed operation has been linearized; the remaining operations need not
+            // have taken effect.
+            return true;
+        };
+
+        if !self
+            .visited
+            .insert((self.linearized.clone(), state.clone()))
+        {
+            return false;
+        }
+
+        let candidates: Vec<usize> = self
+            .remaining()
+            .filter(|&i| self.operations[self.subset[i]].invoked < earliest_completion)
+            .collect();
+        for i in candidates {
+            let operation = &self.operations[self.subset[i]];
+            let (next, ret) = self.model.step(&state, operation.op);
+            if operation.ret.is_some_and(|observed| *observed != ret) {
+                continue;
+            }
+
+            self.linearized[i] = true;
+            self.witness.push(self.subset[i]);
+            if self.run(next) {
+                return true;
+            }
+            self.linearized[i] = false;
+            self.witness.pop();
+        }
+
+        false
+    }
+
+    fn remaining(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.subset.len()).filter(|&i| !self.linearized[i])
+    }
+}
+
+/// A model of a register which may be read, written and compared-and-set.
+#[derive(Clone, Debug)]
+pub struct Register<V> {
+    initial: Option<V>,
+}
+
+impl<V> Default for Register<V> {
+    fn default() -> Self {
+        Self { initial: None }
+    }
+}
+
+impl<V> Register<V> {
+    /// Create a register which is initially unset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a register which initially holds the value.
+    pub fn with_initial(value: V) -> Self {
+        Self {
+            initial: Some(value),
+        }
+    }
+}
+
+/// An operation on a [`Register`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum RegisterOp<V> {
+    Read,
+    Write(V),
+    /// Set the register to the second value if it holds the first.
+    CompareAndSet(V, V),
+}
+
+/// The result of an operation on a [`Register`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum RegisterRet<V> {
+    /// The value read, or `None` if the register is unset.
+    Value(Option<V>),
+    /// A write, or a compare-and-set which succeeded.
+    Ok,
+    /// A compare-and-set which failed.
+    Failed,
+}
+
+impl<V> Model for Register<V>
+where
+    V: Clone + Eq + Hash,
+{
+    type Op = RegisterOp<V>;
+    type Ret = RegisterRet<V>;
+    type State = Option<V>;
+
+    fn init(&self) -> Self::State {
+        self.initial.clone()
+    }
+
+    fn step(&self, state: &Self::State, op: &Self::Op) -> (Self::State, Self::Ret) {
+        match op {
+            RegisterOp::Read => (state.clone(), RegisterRet::Value(state.clone())),
+            RegisterOp::Write(value) => (Some(value.clone()), RegisterRet::Ok),
+            RegisterOp::CompareAndSet(expected, value) if state.as_ref() == Some(expected) => {
+                (Some(value.clone()), RegisterRet::Ok)
+            }
+            RegisterOp::CompareAndSet(..) => (state.clone(), RegisterRet::Failed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type RegisterHistory = History<&'static str, RegisterOp<u32>, RegisterRet<u32>>;
+
+    /// Test that sequential and concurrent linearizable histories are found linearizable, with a
+    /// witness consistent with the results.
+    #[test]
+    fn test_linearizable() -> Result<(), Box<dyn std::error::Error>> {
+        // a: |--write(1)--|
+        // b:      |------------read => 1-----------|
+        // c:                   |--read => 1--|
+        let mut history = RegisterHistory::new();
+        history.invoke("a", RegisterOp::Write(1));
+        history.invoke("b", RegisterOp::Read);
+        history.complete(&"a", RegisterRet::Ok);
+        history.invoke("c", RegisterOp::Read);
+        history.complete(&"c", RegisterRet::Value(Some(1)));
+        history.complete(&"b", RegisterRet::Value(Some(1)));
+
+        assert_eq!(
+            check(&Register::new(), &history)?,
+            Linearizability::Linearizable {
+                witness: vec![0, 1, 2]
+            }
+        );
+
+        // b's read of None must be linearized before a's concurrent write.
+        let mut history = RegisterHistory::new();
+        history.invoke("a", RegisterOp::Write(1));
+        history.invoke("b", RegisterOp::Read);
+        history.complete(&"a", RegisterRet::Ok);
+        history.complete(&"b", RegisterRet::Value(None));
+
+        assert_eq!(
+            check(&Register::new(), &history)?,
+            Linearizability::Linearizable {
+                witness: vec![1, 0]
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Test that an operation which never completed may take effect, or not.
+    #[test]
+    fn test_pending_operations() -> Result<(), Box<dyn std::error::Error>> {
+        let mut history = RegisterHistory::new();
+        history.invoke("a", RegisterOp::Write(1));
+        history.invoke("b", RegisterOp::Read);
+        history.complete(&"b", RegisterRet::Value(None));
+
+        assert_eq!(
+            check(&Register::new(), &history)?,
+            Linearizability::Linearizable { witness: vec![1] }
+        );
+
+        history.invoke("b", RegisterOp::Read);
+        history.complete(&"b", RegisterRet::Value(Some(1)));
+
+        assert_eq!(
+            check(&Register::new(), &history)?,
+            Linearizability::Linearizable {
+                witness: vec![1, 0, 2]
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Test that a stale read is reported, with a minimal subhistory which excludes the
+    /// unrelated operations.
+    #[test]
+    fn test_not_linearizable() -> Result<(), Box<dyn std::error::Error>> {
+        let mut history = RegisterHistory::new();
+        history.invoke("d", RegisterOp::CompareAndSet(0, 5));
+        history.invoke("a", RegisterOp::Write(1));
+        history.complete(&"a", RegisterRet::Ok);
+        history.complete(&"d", RegisterRet::Failed);
+        history.invoke("c", RegisterOp::Write(2));
+        history.invoke("b", RegisterOp::Read);
+        history.complete(&"b", RegisterRet::Value(None));
+
+        let Linearizability::NotLinearizable { subhistory } = check(&Register::new(), &history)?
+        else {
+            panic!("history was linearizable");
+        };
+        assert_eq!(
+            subhistory.events(),
+            &[
+                HistoryEvent::Invoke {
+                    client: "a",
+                    op: RegisterOp::Write(1)
+                },
+                HistoryEvent::Complete {
+                    client: "a",
+                    ret: RegisterRet::Ok
+                },
+                HistoryEvent::Invoke {
+                    client: "b",
+                    op: RegisterOp::Read
+                },
+                HistoryEvent::Complete {
+                    client: "b",
+                    ret: RegisterRet::Value(None)
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    /// Test that malformed histories are rejected.
+    #[test]
+    fn test_malformed_history() {
+        let mut history = RegisterHistory::new();
+        history.complete(&"a", RegisterRet::Ok);
+        assert!(check(&Register::new(), &history).is_err());
+
+        let mut history = RegisterHistory::new();
+        history.invoke("a", RegisterOp::Read);
+        history.invoke("a", RegisterOp::Read);
+        assert!(check(&Register::new(), &history).is_err());
+    }
+}
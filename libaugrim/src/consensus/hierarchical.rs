@@ -0,0 +1,310 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the hierarchical consensus algorithm.
+
+use std::marker::PhantomData;
+
+use crate::algorithm::{Algorithm, Value};
+use crate::error::{AlgorithmError, InvalidStateError};
+use crate::message::Message;
+use crate::process::Process;
+
+use super::{ConsensusAction, ConsensusEvent, ConsensusNotification};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum HierarchicalMessage<V> {
+    /// The sender decided the value.
+    Decided(V),
+}
+
+impl<V: Value> Message for HierarchicalMessage<V> {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct HierarchicalConsensusContext<P, V> {
+    this_process: P,
+    processes: Vec<P>,
+    round: usize,
+    proposal: Option<V>,
+    proposer: Option<usize>,
+    crashed: Vec<P>,
+    delivered: Vec<usize>,
+    decided: bool,
+}
+
+impl<P: Process, V: Value> HierarchicalConsensusContext<P, V> {
+    /// Create the context of `this_process`, one of `processes`, which take turns in the given
+    /// order; `this_process` is added to the end of the processes if it is not among them.
+    pub fn new(this_process: P, mut processes: Vec<P>) -> Self {
+        if !processes.contains(&this_process) {
+            processes.push(this_process.clone());
+        }
+        Self {
+            this_process,
+            processes,
+            round: 0,
+            proposal: None,
+            proposer: None,
+            crashed: Vec::new(),
+            delivered: Vec::new(),
+            decided: false,
+        }
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    pub fn processes(&self) -> &[P] {
+        &self.processes
+    }
+
+    /// Returns the value this process will decide when its turn comes: its own proposal, or
+    /// that of the last process before it which decided.
+    pub fn proposal(&self) -> Option<&V> {
+        self.proposal.as_ref()
+    }
+
+    /// Returns whether this process has decided.
+    pub fn decided(&self) -> bool {
+        self.decided
+    }
+
+    fn rank(&self, process: &P) -> Option<usize> {
+        self.processes.iter().position(|p| p == process)
+    }
+
+    // Take the turns which are over, deciding when this process's turn comes.
+    fn advance(
+        &mut self,
+        actions: &mut Vec<ConsensusAction<P, V, Self, HierarchicalMessage<V>>>,
+    ) -> Result<(), InvalidStateError> {
+        loop {
+            let process = self.processes.get(self.round).ok_or_else(|| {
+                InvalidStateError::with_message(
+                    "this process is not among the consensus processes".into(),
+                )
+            })?;
+            if *process == self.this_process {
+                if let (Some(proposal), false) = (&self.proposal, self.decided) {
+                    self.decided = true;
+                    for process in self.processes.iter().skip(self.round + 1) {
+                        actions.push(ConsensusAction::SendMessage(
+                            process.clone(),
+                            HierarchicalMessage::Decided(proposal.clone()),
+                        ));
+                    }
+                    actions.push(ConsensusAction::Notify(ConsensusNotification::Decide(
+                        proposal.clone(),
+                    )));
+                }
+                return Ok(());
+            }
+            if !self.delivered.contains(&self.round) && !self.crashed.contains(process) {
+                return Ok(());
+            }
+            self.round += 1;
+        }
+    }
+}
+
+/// Hierarchical consensus: the processes take turns, in the order given in the context, and on
+/// its turn a process decides its proposal and sends it to the processes after it. A process
+/// adopts the value decided by the last process before it, and its turn comes once every
+/// process before it has either decided or been detected to have crashed.
+///
+/// The crashes must be reported by a perfect failure detector, with
+/// [`ConsensusEvent::Crash`]. A process which has neither proposed nor received a decided value
+/// when its turn comes waits for a proposal.
+pub struct HierarchicalConsensus<P, V> {
+    _process: PhantomData<P>,
+    _value: PhantomData<V>,
+}
+
+impl<P, V> Default for HierarchicalConsensus<P, V> {
+    fn default() -> Self {
+        Self {
+            _process: PhantomData,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<P, V> HierarchicalConsensus<P, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<P, V> Algorithm for HierarchicalConsensus<P, V>
+where
+    P: Process,
+    V: Value,
+{
+    type Event = ConsensusEvent<P, V, HierarchicalMessage<V>>;
+    type Action = ConsensusAction<P, V, HierarchicalConsensusContext<P, V>, HierarchicalMessage<V>>;
+    type Context = HierarchicalConsensusContext<P, V>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        match event {
+            ConsensusEvent::Propose(value) => {
+                if context.proposal.is_some() {
+                    return Ok(vec![]);
+                }
+                context.proposal = Some(value);
+            }
+            ConsensusEvent::Deliver(process, HierarchicalMessage::Decided(value)) => {
+                let rank = match context.rank(&process) {
+                    Some(rank) => rank,
+                    None => {
+                        return Ok(vec![ConsensusAction::Notify(
+                            ConsensusNotification::MessageDropped(
+                                "sender is not a consensus process".into(),
+                            ),
+                        )])
+                    }
+                };
+                let this_rank = context.rank(&context.this_process).unwrap_or_default();
+                if rank < this_rank && context.proposer.is_none_or(|proposer| rank > proposer) {
+                    context.proposal = Some(value);
+                    context.proposer = Some(rank);
+                }
+                if !context.delivered.contains(&rank) {
+                    context.delivered.push(rank);
+                }
+            }
+            ConsensusEvent::Crash(process) => {
+                if context.crashed.contains(&process) {
+                    return Ok(vec![]);
+                }
+                context.crashed.push(process);
+            }
+        }
+
+        let mut actions = Vec::new();
+        context.advance(&mut actions)?;
+        actions.insert(0, ConsensusAction::Update(context));
+        Ok(actions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+
+    type Context = HierarchicalConsensusContext<u32, String>;
+
+    // Run the processes until no messages are in flight, returning the decisions of the correct
+    // processes. If `crashed` is given, that process crashes after sending its first `keep`
+    // messages, and the crash is detected once no messages are in flight.
+    fn run(proposals: &[(u32, &str)], crashed: Option<(u32, usize)>) -> Vec<(u32, String)> {
+        let algorithm = HierarchicalConsensus::new();
+        let mut contexts: Vec<(u32, Context)> = (0..3)
+            .map(|p| (p, HierarchicalConsensusContext::new(p, vec![0, 1, 2])))
+            .collect();
+        let mut events: VecDeque<(u32, ConsensusEvent<u32, String, _>)> = proposals
+            .iter()
+            .map(|(p, value)| (*p, ConsensusEvent::Propose(value.to_string())))
+            .collect();
+        let mut decisions = Vec::new();
+        let mut detected = false;
+
+        while let Some((process, event)) = events.pop_front() {
+            let (_, context) = &mut contexts[process as usize];
+            let mut sent = 0;
+            for action in algorithm.event(event, context.clone()).unwrap() {
+                match action {
+                    ConsensusAction::Update(next) => *context = next,
+                    ConsensusAction::SendMessage(to, message) => {
+                        sent += 1;
+                        match crashed {
+                            Some((p, keep)) if p == process && sent > keep => (),
+                            Some((p, _)) if p == to => (),
+                            _ => events.push_back((to, ConsensusEvent::Deliver(process, message))),
+                        }
+                    }
+                    ConsensusAction::Notify(ConsensusNotification::Decide(value)) => {
+                        decisions.push((process, value))
+                    }
+                    ConsensusAction::Notify(ConsensusNotification::MessageDropped(reason)) => {
+                        panic!("message dropped: {reason}")
+                    }
+                }
+            }
+            if events.is_empty() && !detected {
+                detected = true;
+                if let Some((p, _)) = crashed {
+                    events.extend(
+                        (0..3)
+                            .filter(|q| *q != p)
+                            .map(|q| (q, ConsensusEvent::Crash(p))),
+                    );
+                }
+            }
+        }
+
+        decisions.retain(|(process, _)| crashed.is_none_or(|(p, _)| *process != p));
+        decisions.sort();
+        decisions
+    }
+
+    /// Test that every process decides the first process's proposal, even if it proposed after
+    /// the others.
+    #[test]
+    fn test_hierarchical_consensus() {
+        let decisions = run(&[(2, "c"), (1, "b"), (0, "a")], None);
+        assert_eq!(
+            decisions,
+            vec![(0, "a".into()), (1, "a".into()), (2, "a".into())]
+        );
+    }
+
+    /// Test that when the first process crashes after sending its decision to only the second,
+    /// the remaining processes still agree on it, and when it crashes without sending it they
+    /// agree on the second process's proposal.
+    #[test]
+    fn test_hierarchical_consensus_crash() {
+        let proposals = [(0, "a"), (1, "b"), (2, "c")];
+        assert_eq!(
+            run(&proposals, Some((0, 1))),
+            vec![(1, "a".into()), (2, "a".into())]
+        );
+        assert_eq!(
+            run(&proposals, Some((0, 0))),
+            vec![(1, "b".into()), (2, "b".into())]
+        );
+    }
+
+    /// Test that a context whose processes do not include this process, such as one restored
+    /// from a corrupt copy, is reported as an invalid state once the turns before it are over.
+    #[test]
+    fn test_missing_this_process() {
+        let algorithm = HierarchicalConsensus::new();
+        let mut context: Context = HierarchicalConsensusContext::new(1, vec![0, 1]);
+        context.processes.retain(|process| *process != 1);
+
+        assert!(matches!(
+            algorithm.event(ConsensusEvent::Crash(0), context),
+            Err(AlgorithmError::InvalidState(_))
+        ));
+    }
+}
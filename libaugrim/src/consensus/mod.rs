@@ -0,0 +1,65 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Consensus algorithms, as described in the following source:
+//!
+//! - Cachin, Guerraoui, and Rodrigues, Reliable and Secure Distributed Programming, 2nd ed., 5.
+//!
+//! Each process proposes a value, and every correct process decides the same value, which was
+//! proposed by some process:
+//!
+//! - [`HierarchicalConsensus`] (5.1.3): the processes take turns, in order, to decide their
+//!   proposal and send it to the processes after them, which adopt it. Crashes are learned from
+//!   a perfect failure detector.
+//!
+//! The algorithms share their events and actions, so that algorithms built on consensus, such as
+//! [`TotalOrderBroadcast`](crate::broadcast::TotalOrderBroadcast), may use any of them; another
+//! consensus algorithm can be adapted to them with the algorithm adapters. A value is proposed
+//! with [`ConsensusEvent::Propose`], and the decision is reported with
+//! [`ConsensusNotification::Decide`], once per process.
+
+mod hierarchical;
+
+pub use hierarchical::{HierarchicalConsensus, HierarchicalConsensusContext, HierarchicalMessage};
+
+/// An event of a consensus algorithm; `M` is the message sent between processes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ConsensusEvent<P, V, M> {
+    /// Propose the value.
+    Propose(V),
+    /// A message was received from the process.
+    Deliver(P, M),
+    /// The perfect failure detector detected that the process crashed.
+    Crash(P),
+}
+
+/// An action of a consensus algorithm with context `C`; `M` is the message sent between
+/// processes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ConsensusAction<P, V, C, M> {
+    Update(C),
+    SendMessage(P, M),
+    Notify(ConsensusNotification<V>),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ConsensusNotification<V> {
+    /// The value was decided.
+    Decide(V),
+    /// A message was dropped, for the given reason.
+    MessageDropped(String),
+}
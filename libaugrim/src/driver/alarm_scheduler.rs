@@ -0,0 +1,107 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains AlarmScheduler, which tracks the pending alarm of an algorithm.
+
+use std::time::Duration;
+
+use crate::time::Time;
+
+// How long to wait before checking an alarm again when `Time::duration_since` can not measure
+// the time remaining until it.
+const ALARM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// The shortest time to wait for an alarm which has not yet passed. Algorithms check that a
+// timeout has strictly passed, so an alarm is not fired at exactly its own time.
+const MIN_ALARM_DELAY: Duration = Duration::from_millis(1);
+
+/// Tracks the alarm requested by an algorithm's `Update` actions and decides when it fires.
+///
+/// An algorithm has at most one pending alarm: each `Update` action replaces it, cancelling any
+/// alarm it supersedes, and an `Update` with no alarm cancels it. An alarm fires once the
+/// current time has passed it, after which it is no longer pending.
+///
+/// The drivers in this module use an `AlarmScheduler` internally; it is public so that
+/// applications with their own event loop can get the alarm bookkeeping right as well.
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+///
+/// use augrim::driver::AlarmScheduler;
+///
+/// let now = SystemTime::now();
+/// let mut scheduler = AlarmScheduler::new();
+///
+/// scheduler.set(Some(now + Duration::from_secs(30)));
+/// scheduler.set(Some(now + Duration::from_secs(5)));
+/// assert_eq!(scheduler.next_delay(&now), Some(Duration::from_secs(5)));
+///
+/// assert!(!scheduler.take_due(&now));
+/// assert!(scheduler.take_due(&(now + Duration::from_secs(6))));
+/// assert_eq!(scheduler.next_delay(&now), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AlarmScheduler<T> {
+    alarm: Option<T>,
+}
+
+impl<T> Default for AlarmScheduler<T> {
+    fn default() -> Self {
+        Self { alarm: None }
+    }
+}
+
+impl<T: Time> AlarmScheduler<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the pending alarm; `None` cancels it.
+    pub fn set(&mut self, alarm: Option<T>) {
+        self.alarm = alarm;
+    }
+
+    /// Returns the pending alarm, if any.
+    pub fn alarm(&self) -> Option<&T> {
+        self.alarm.as_ref()
+    }
+
+    /// Returns how long to wait before checking the alarm again, or `None` if no alarm is
+    /// pending.
+    ///
+    /// An alarm which has already passed returns `Duration::ZERO`.
+    pub fn next_delay(&self, now: &T) -> Option<Duration> {
+        let alarm = self.alarm.as_ref()?;
+        if now > alarm {
+            return Some(Duration::ZERO);
+        }
+        Some(
+            alarm
+                .duration_since(now)
+                .map(|remaining| remaining.max(MIN_ALARM_DELAY))
+                .unwrap_or(ALARM_POLL_INTERVAL),
+        )
+    }
+
+    /// Returns true, cancelling the alarm, if the pending alarm has passed and should fire.
+    pub fn take_due(&mut self, now: &T) -> bool {
+        match &self.alarm {
+            Some(alarm) if now > alarm => {
+                self.alarm = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,369 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains AsyncDriver, which runs an `AsyncAlgorithm` on the tokio runtime.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{mpsc, watch};
+use tokio::time::Instant;
+
+use crate::algorithm::AsyncAlgorithm;
+use crate::error::DriverError;
+use crate::time::{Time, TimeSource};
+
+use super::{apply_action, AlarmScheduler, DrivableAction, DrivableEvent};
+use super::{NotificationHandler, Transport};
+
+/// [`tokio::time::Instant`] can be used directly as Time. See also [`TokioTimeSource`].
+impl Time for Instant {
+    fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+        self.checked_duration_since(*earlier)
+    }
+}
+
+/// An implementation of [`TimeSource`] which works with [`tokio::time::Instant`].
+///
+/// Unlike [`SystemTimeFactory`](crate::SystemTimeFactory), this follows tokio's clock, so it
+/// may be paused and advanced in tests.
+#[derive(Default, Clone)]
+pub struct TokioTimeSource {}
+
+impl TokioTimeSource {
+    pub fn new() -> Self {
+        TokioTimeSource {}
+    }
+}
+
+impl TimeSource for TokioTimeSource {
+    type Time = Instant;
+
+    fn now(&self) -> Self::Time {
+        Instant::now()
+    }
+}
+
+/// Requests that an [`AsyncDriver`] stop.
+///
+/// Clones signal the same driver.
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl ShutdownHandle {
+    /// Request that the driver stop once it has finished processing the current event.
+    pub fn shutdown(&self) {
+        self.sender.send_replace(true);
+    }
+}
+
+/// A driver which runs an [`AsyncAlgorithm`] on the tokio runtime.
+///
+/// Events are received from an mpsc channel; messages received from other processes should be
+/// sent into the same channel as [`DrivableEvent::deliver`] events. The driver stores the
+/// context and alarm from each `Update` action, sends each `SendMessage` action with the
+/// [`Transport`] and passes each `Notify` action to the [`NotificationHandler`]. When the alarm
+/// passes, an [`DrivableEvent::alarm`] event is processed.
+///
+/// [`AsyncDriver::run`] returns the latest context when the driver is shut down with a
+/// [`ShutdownHandle`] or when all senders of the event channel have been dropped. An event
+/// which is being processed is always completed first; events remaining in the channel are not
+/// processed. If the transport or handler returns an error, the driver stops and returns it as a
+/// [`DriverError`]; an error returned by the algorithm only rejects the event, and is passed to
+/// [`NotificationHandler::algorithm_error`].
+///
+/// # Example
+///
+/// ```no_run
+/// use augrim::Algorithm;
+/// use augrim::driver::{AsyncDriver, TokioTimeSource};
+/// use augrim::error::InternalError;
+/// use augrim::two_phase_commit::{
+///     TwoPhaseCommitActionNotification, TwoPhaseCommitAlgorithm, TwoPhaseCommitContext,
+///     TwoPhaseCommitEvent, TwoPhaseCommitMessage,
+/// };
+/// use augrim::TimeSource;
+///
+/// # async fn run(
+/// #     context: TwoPhaseCommitContext<String, String, tokio::time::Instant>,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let algorithm = TwoPhaseCommitAlgorithm::new(TokioTimeSource::new()).into_async();
+///
+/// let transport = |to: String, message: TwoPhaseCommitMessage<String>| {
+///     // Send the message to the other process.
+///     Ok::<_, InternalError>(())
+/// };
+/// let handler = |notification: TwoPhaseCommitActionNotification<String>| {
+///     // Respond to the notification, possibly by sending a new event.
+///     Ok::<_, InternalError>(())
+/// };
+///
+/// let (sender, events) = tokio::sync::mpsc::channel::<TwoPhaseCommitEvent<String, String>>(64);
+/// let driver = AsyncDriver::new(algorithm, TokioTimeSource::new(), transport, handler);
+/// let shutdown = driver.shutdown_handle();
+///
+/// // Fire the alarm immediately, so the algorithm requests its first value.
+/// let context = driver
+///     .run(context, Some(TokioTimeSource::new().now()), events)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncDriver<A, TS, Tr, H> {
+    algorithm: A,
+    time_source: TS,
+    transport: Tr,
+    handler: H,
+    shutdown: Arc<watch::Sender<bool>>,
+}
+
+impl<A, TS, Tr, H> AsyncDriver<A, TS, Tr, H>
+where
+    A: AsyncAlgorithm,
+    A::Event: DrivableEvent,
+    A::Action: DrivableAction<Context = A::Context, Time = TS::Time>,
+    A::Context: Clone,
+    TS: TimeSource,
+    Tr: Transport<<A::Action as DrivableAction>::Process, <A::Action as DrivableAction>::Message>,
+    H: NotificationHandler<<A::Action as DrivableAction>::Notification>,
+{
+    pub fn new(algorithm: A, time_source: TS, transport: Tr, handler: H) -> Self {
+        Self {
+            algorithm,
+            time_source,
+            transport,
+            handler,
+            shutdown: Arc::new(watch::Sender::new(false)),
+        }
+    }
+
+    /// Returns a handle which may be used to stop the driver.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle {
+            sender: self.shutdown.clone(),
+        }
+    }
+
+    /// Run the algorithm, starting with the given context and alarm, until shut down.
+    ///
+    /// Returns the latest context.
+    pub async fn run(
+        mut self,
+        mut context: A::Context,
+        alarm: Option<TS::Time>,
+        mut events: mpsc::Receiver<A::Event>,
+    ) -> Result<A::Context, DriverError> {
+        let mut shutdown = self.shutdown.subscribe();
+        let mut alarm = {
+            let mut scheduler = AlarmScheduler::new();
+            scheduler.set(alarm);
+            scheduler
+        };
+
+        loop {
+            if *shutdown.borrow_and_update() {
+                return Ok(context);
+            }
+
+            let now = self.time_source.now();
+            if alarm.take_due(&now) {
+                if let Some(event) = A::Event::alarm() {
+                    self.process(event, &mut context, &mut alarm).await?;
+                }
+                continue;
+            }
+            let delay = alarm.next_delay(&now);
+
+            tokio::select! {
+                biased;
+
+                _ = shutdown.changed() => (),
+                event = events.recv() => match event {
+                    Some(event) => self.process(event, &mut context, &mut alarm).await?,
+                    None => return Ok(context),
+                },
+                // Once this sleep completes, the alarm is checked again at the top of the loop.
+                _ = tokio::time::sleep(delay.unwrap_or_default()), if delay.is_some() => (),
+            }
+        }
+    }
+
+    async fn process(
+        &mut self,
+        event: A::Event,
+        context: &mut A::Context,
+        alarm: &mut AlarmScheduler<TS::Time>,
+    ) -> Result<(), DriverError> {
+        let actions = match self.algorithm.event(event, context.clone()).await {
+            Ok(actions) => actions,
+            Err(err) => return Ok(self.handler.algorithm_error(err)?),
+        };
+        for action in actions {
+            apply_action(
+                action,
+                context,
+                alarm,
+                &mut self.transport,
+                &mut self.handler,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "algorithm-two-phase-commit"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::algorithm::Algorithm;
+    use crate::error::InternalError;
+    use crate::two_phase_commit::{
+        Participant, TwoPhaseCommitActionNotification, TwoPhaseCommitAlgorithm,
+        TwoPhaseCommitContext, TwoPhaseCommitContextBuilder, TwoPhaseCommitEvent,
+        TwoPhaseCommitMessage, TwoPhaseCommitState,
+    };
+
+    use super::*;
+
+    type Sent = Arc<Mutex<Vec<(String, TwoPhaseCommitMessage<String>)>>>;
+    type Notified = Arc<Mutex<Vec<TwoPhaseCommitActionNotification<String>>>>;
+    type Context = TwoPhaseCommitContext<String, String, Instant>;
+    type Task = tokio::task::JoinHandle<Result<Context, DriverError>>;
+
+    fn coordinator_context() -> Context {
+        TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![Participant::new("p1".to_string())])
+            .build()
+            .unwrap()
+    }
+
+    fn start_driver(
+        context: Context,
+    ) -> (
+        mpsc::Sender<TwoPhaseCommitEvent<String, String>>,
+        ShutdownHandle,
+        Sent,
+        Notified,
+        Task,
+    ) {
+        let sent = Sent::default();
+        let notified = Notified::default();
+
+        let transport = {
+            let sent = sent.clone();
+            move |to, message| {
+                sent.lock().unwrap().push((to, message));
+                Ok::<_, InternalError>(())
+            }
+        };
+        let handler = {
+            let notified = notified.clone();
+            move |notification| {
+                notified.lock().unwrap().push(notification);
+                Ok::<_, InternalError>(())
+            }
+        };
+
+        let algorithm = TwoPhaseCommitAlgorithm::new(TokioTimeSource::new()).into_async();
+        let driver = AsyncDriver::new(algorithm, TokioTimeSource::new(), transport, handler);
+        let shutdown = driver.shutdown_handle();
+
+        let (sender, events) = mpsc::channel(16);
+        let task = tokio::spawn(driver.run(context, Some(Instant::now()), events));
+
+        (sender, shutdown, sent, notified, task)
+    }
+
+    /// Test that the driver runs the coordinator through a commit, sending messages and
+    /// notifications, and returns the latest context on shutdown.
+    #[tokio::test(start_paused = true)]
+    async fn test_commit() -> Result<(), Box<dyn std::error::Error>> {
+        let (sender, shutdown, sent, notified, task) = start_driver(coordinator_context());
+
+        // Allow the initial alarm to fire, requesting the first value.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        sender.send(TwoPhaseCommitEvent::Start("v".into())).await?;
+        sender
+            .send(TwoPhaseCommitEvent::Deliver(
+                "p1".into(),
+                TwoPhaseCommitMessage::VoteResponse(1, true),
+            ))
+            .await?;
+        sender.send(TwoPhaseCommitEvent::Vote(true)).await?;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        shutdown.shutdown();
+        let context = task.await??;
+
+        assert!(matches!(
+            context.state(),
+            TwoPhaseCommitState::WaitingForDecisionAck { .. }
+        ));
+        assert_eq!(
+            *sent.lock().unwrap(),
+            vec![
+                (
+                    "p1".into(),
+                    TwoPhaseCommitMessage::VoteRequest(1, "v".into())
+                ),
+                ("p1".into(), TwoPhaseCommitMessage::Commit(1)),
+            ]
+        );
+        assert_eq!(
+            *notified.lock().unwrap(),
+            vec![
+                TwoPhaseCommitActionNotification::RequestForStart(),
+                TwoPhaseCommitActionNotification::CoordinatorRequestForVote(),
+                TwoPhaseCommitActionNotification::Commit(1, "v".into()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    /// Test that the alarm set by the coordinator fires and aborts the epoch when the
+    /// participant does not vote, and that the driver stops when the event channel closes.
+    #[tokio::test(start_paused = true)]
+    async fn test_vote_timeout() -> Result<(), Box<dyn std::error::Error>> {
+        let (sender, _shutdown, sent, notified, task) = start_driver(coordinator_context());
+
+        sender.send(TwoPhaseCommitEvent::Start("v".into())).await?;
+        tokio::time::sleep(Duration::from_secs(31)).await;
+
+        drop(sender);
+        task.await??;
+
+        // Abort is only sent to participants which voted yes.
+        assert_eq!(
+            *sent.lock().unwrap(),
+            vec![(
+                "p1".into(),
+                TwoPhaseCommitMessage::VoteRequest(1, "v".into())
+            )]
+        );
+        assert!(notified
+            .lock()
+            .unwrap()
+            .contains(&TwoPhaseCommitActionNotification::Abort(1, "v".into())));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Drivers which run an algorithm: feeding it events, storing its context, firing its alarms
+//! and carrying out its actions.
+//!
+//! A driver works with any algorithm whose actions implement [`DrivableAction`] and whose events
+//! implement [`DrivableEvent`]. Messages are sent with a [`Transport`] and notifications are
+//! passed to a [`NotificationHandler`].
+//!
+//! [`ThreadDriver`] runs a synchronous algorithm on a dedicated thread. With the `async` feature,
+//! `AsyncDriver` runs an asynchronous algorithm on the tokio runtime.
+
+mod alarm_scheduler;
+#[cfg(feature = "async")]
+mod async_driver;
+mod thread_driver;
+
+use crate::error::{AlgorithmError, InternalError};
+use crate::time::Time;
+
+pub use alarm_scheduler::AlarmScheduler;
+#[cfg(feature = "async")]
+pub use async_driver::{AsyncDriver, ShutdownHandle, TokioTimeSource};
+pub use thread_driver::{EventSender, ThreadDriver, ThreadDriverHandle};
+
+/// The actions a driver knows how to carry out.
+///
+/// `C` is the context type, `T` the time type, `P` the process type, `M` the message type and
+/// `N` the notification type of the algorithm.
+#[derive(Debug, PartialEq)]
+pub enum DriverAction<C, T, P, M, N> {
+    /// Replace the stored context and alarm. An alarm of `None` unsets any current alarm.
+    Update { context: C, alarm: Option<T> },
+    /// Send the message to the process.
+    SendMessage(P, M),
+    /// Pass the notification to the application.
+    Notify(N),
+}
+
+/// An action which may be carried out by a driver.
+pub trait DrivableAction {
+    type Context;
+    type Time: Time;
+    type Process;
+    type Message;
+    type Notification;
+
+    /// Convert this action into the common form understood by drivers.
+    #[allow(clippy::type_complexity)]
+    fn into_driver_action(
+        self,
+    ) -> DriverAction<Self::Context, Self::Time, Self::Process, Self::Message, Self::Notification>;
+}
+
+/// An event which may be constructed by a driver.
+pub trait DrivableEvent: Sized {
+    type Process;
+    type Message;
+
+    /// Returns the event which signals that the algorithm's alarm has fired, or `None` if the
+    /// algorithm sets no alarms, in which case a due alarm is discarded.
+    fn alarm() -> Option<Self>;
+
+    /// Returns the event which delivers a message received from another process.
+    fn deliver(from: Self::Process, message: Self::Message) -> Self;
+}
+
+/// Sends messages to other processes on behalf of a driver.
+///
+/// Sending must not block; a transport which needs to wait (for example, on a network
+/// connection) should queue the message and send it from another task or thread. This is
+/// implemented for closures with a matching signature.
+pub trait Transport<P, M> {
+    fn send(&mut self, to: P, message: M) -> Result<(), InternalError>;
+}
+
+impl<P, M, F> Transport<P, M> for F
+where
+    F: FnMut(P, M) -> Result<(), InternalError>,
+{
+    fn send(&mut self, to: P, message: M) -> Result<(), InternalError> {
+        self(to, message)
+    }
+}
+
+/// Receives the notifications of an algorithm run by a driver.
+///
+/// Like [`Transport::send`], handling a notification must not block. This is implemented for
+/// closures with a matching signature.
+pub trait NotificationHandler<N> {
+    fn notify(&mut self, notification: N) -> Result<(), InternalError>;
+
+    /// Handle an error returned by the algorithm for an event. The event has no effect, and the
+    /// driver continues with the next; an event may be rejected in a normal race, such as a vote
+    /// arriving after the participant has stopped waiting for it. Returning an error stops the driver.
+    ///
+    /// By default the error is discarded.
+    fn algorithm_error(&mut self, error: AlgorithmError) -> Result<(), InternalError> {
+        let _ = error;
+        Ok(())
+    }
+}
+
+impl<N, F> NotificationHandler<N> for F
+where
+    F: FnMut(N) -> Result<(), InternalError>,
+{
+    fn notify(&mut self, notification: N) -> Result<(), InternalError> {
+        self(notification)
+    }
+}
+
+// Carry out a single action, updating the stored context and scheduled alarm.
+fn apply_action<A, Tr, H>(
+    action: A,
+    context: &mut A::Context,
+    alarm: &mut AlarmScheduler<A::Time>,
+    transport: &mut Tr,
+    handler: &mut H,
+) -> Result<(), InternalError>
+where
+    A: DrivableAction,
+    Tr: Transport<A::Process, A::Message>,
+    H: NotificationHandler<A::Notification>,
+{
+    match action.into_driver_action() {
+        DriverAction::Update {
+            context: next_context,
+            alarm: next_alarm,
+        } => {
+            *context = next_context;
+            alarm.set(next_alarm);
+            Ok(())
+        }
+        DriverAction::SendMessage(to, message) => transport.send(to, message),
+        DriverAction::Notify(notification) => handler.notify(notification),
+    }
+}
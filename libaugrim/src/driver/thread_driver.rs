@@ -0,0 +1,462 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains ThreadDriver, which runs an `Algorithm` on a dedicated thread.
+
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+
+use crate::algorithm::Algorithm;
+use crate::error::{DriverError, InternalError, InvalidStateError};
+use crate::time::TimeSource;
+
+use super::{apply_action, AlarmScheduler, DrivableAction, DrivableEvent};
+use super::{NotificationHandler, Transport};
+
+/// Sends outbound messages into a channel, as `(to, message)` pairs.
+impl<P, M> Transport<P, M> for mpsc::Sender<(P, M)> {
+    fn send(&mut self, to: P, message: M) -> Result<(), InternalError> {
+        mpsc::Sender::send(self, (to, message))
+            .map_err(|_| InternalError::with_message("outbound message channel closed"))
+    }
+}
+
+/// Sends notifications into a channel.
+impl<N> NotificationHandler<N> for mpsc::Sender<N> {
+    fn notify(&mut self, notification: N) -> Result<(), InternalError> {
+        self.send(notification)
+            .map_err(|_| InternalError::with_message("notification channel closed"))
+    }
+}
+
+enum Command<E> {
+    Event(E),
+    Shutdown,
+}
+
+/// A driver which runs an [`Algorithm`] on a dedicated thread.
+///
+/// The thread owns the context and the [`AlarmScheduler`]. Events, including messages received
+/// from other processes, are sent to it with an [`EventSender`]; when the pending alarm passes,
+/// an [`DrivableEvent::alarm`] event is processed. Each `SendMessage` action is sent with the
+/// [`Transport`] and each `Notify` action is passed to the [`NotificationHandler`]; both are
+/// implemented for [`std::sync::mpsc::Sender`], so outbound actions may be received from
+/// channels.
+///
+/// The thread stops when [`ThreadDriverHandle::shutdown`] is called, when every
+/// [`EventSender`] and the handle itself have been dropped, or when the transport or handler
+/// returns an error. An error returned by the algorithm only rejects the event, and is passed to
+/// [`NotificationHandler::algorithm_error`].
+///
+/// # Example
+///
+/// ```
+/// use std::sync::mpsc;
+/// use std::time::SystemTime;
+///
+/// use augrim::driver::ThreadDriver;
+/// use augrim::two_phase_commit::{
+///     Participant, TwoPhaseCommitActionNotification, TwoPhaseCommitAlgorithm,
+///     TwoPhaseCommitContextBuilder, TwoPhaseCommitEvent, TwoPhaseCommitState,
+/// };
+/// use augrim::SystemTimeFactory;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = TwoPhaseCommitContextBuilder::new()
+///     .with_coordinator("c".to_string())
+///     .with_epoch(1)
+///     .with_state(TwoPhaseCommitState::WaitingForStart)
+///     .with_this_process("c".to_string())
+///     .with_participants(vec![Participant::new("p".to_string())])
+///     .build()?;
+///
+/// let (messages, outbound) = mpsc::channel();
+/// let (notifications, notified) = mpsc::channel();
+///
+/// let driver = ThreadDriver::new(
+///     TwoPhaseCommitAlgorithm::<String, String, _>::new(SystemTimeFactory::new()),
+///     SystemTimeFactory::new(),
+///     messages,
+///     notifications,
+/// );
+/// let handle = driver.spawn(context, None);
+///
+/// handle.send(TwoPhaseCommitEvent::Start("value".to_string()))?;
+/// let (to, message) = outbound.recv()?;
+/// assert_eq!(to, "p");
+///
+/// let context = handle.shutdown()?;
+/// assert!(matches!(context.state(), TwoPhaseCommitState::Voting { .. }));
+/// # let _ = notified;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ThreadDriver<A, TS, Tr, H> {
+    algorithm: A,
+    time_source: TS,
+    transport: Tr,
+    handler: H,
+}
+
+impl<A, TS, Tr, H> ThreadDriver<A, TS, Tr, H>
+where
+    A: Algorithm + Send + 'static,
+    A::Event: DrivableEvent + Send + 'static,
+    A::Action: DrivableAction<Context = A::Context, Time = TS::Time>,
+    A::Context: Clone + Send + 'static,
+    TS: TimeSource + Send + 'static,
+    TS::Time: Send,
+    Tr: Transport<<A::Action as DrivableAction>::Process, <A::Action as DrivableAction>::Message>
+        + Send
+        + 'static,
+    H: NotificationHandler<<A::Action as DrivableAction>::Notification> + Send + 'static,
+{
+    pub fn new(algorithm: A, time_source: TS, transport: Tr, handler: H) -> Self {
+        Self {
+            algorithm,
+            time_source,
+            transport,
+            handler,
+        }
+    }
+
+    /// Start the driver thread with the given context and alarm.
+    ///
+    /// To have the algorithm process an alarm immediately (for example, so that a 2PC
+    /// coordinator requests its first value), pass an alarm which has already passed.
+    pub fn spawn(
+        self,
+        context: A::Context,
+        alarm: Option<TS::Time>,
+    ) -> ThreadDriverHandle<A::Event, A::Context> {
+        let (sender, receiver) = mpsc::channel();
+        let join_handle = thread::spawn(move || self.run(context, alarm, receiver));
+
+        ThreadDriverHandle {
+            sender: EventSender { sender },
+            join_handle,
+        }
+    }
+
+    fn run(
+        mut self,
+        mut context: A::Context,
+        alarm: Option<TS::Time>,
+        receiver: mpsc::Receiver<Command<A::Event>>,
+    ) -> Result<A::Context, DriverError> {
+        let mut scheduler = AlarmScheduler::new();
+        scheduler.set(alarm);
+
+        loop {
+            let now = self.time_source.now();
+            if scheduler.take_due(&now) {
+                if let Some(event) = A::Event::alarm() {
+                    self.process(event, &mut context, &mut scheduler)?;
+                }
+                continue;
+            }
+
+            let command = match scheduler.next_delay(&now) {
+                Some(delay) => match receiver.recv_timeout(delay) {
+                    Ok(command) => command,
+                    // The alarm is checked again at the top of the loop.
+                    Err(RecvTimeoutError::Timeout) => continue,
+                    Err(RecvTimeoutError::Disconnected) => return Ok(context),
+                },
+                None => match receiver.recv() {
+                    Ok(command) => command,
+                    Err(_) => return Ok(context),
+                },
+            };
+
+            match command {
+                Command::Event(event) => self.process(event, &mut context, &mut scheduler)?,
+                Command::Shutdown => return Ok(context),
+            }
+        }
+    }
+
+    fn process(
+        &mut self,
+        event: A::Event,
+        context: &mut A::Context,
+        scheduler: &mut AlarmScheduler<TS::Time>,
+    ) -> Result<(), DriverError> {
+        let actions = match self.algorithm.event(event, context.clone()) {
+            Ok(actions) => actions,
+            Err(err) => return Ok(self.handler.algorithm_error(err)?),
+        };
+        for action in actions {
+            apply_action(
+                action,
+                context,
+                scheduler,
+                &mut self.transport,
+                &mut self.handler,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Sends events to a [`ThreadDriver`].
+pub struct EventSender<E> {
+    sender: mpsc::Sender<Command<E>>,
+}
+
+impl<E> Clone for EventSender<E> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<E> EventSender<E> {
+    /// Send an event to the driver. Returns an error if the driver has stopped.
+    pub fn send(&self, event: E) -> Result<(), InvalidStateError> {
+        self.sender
+            .send(Command::Event(event))
+            .map_err(|_| InvalidStateError::with_message("driver has stopped".into()))
+    }
+}
+
+impl<E> EventSender<E>
+where
+    E: DrivableEvent,
+{
+    /// Deliver a message received from another process to the driver. Returns an error if the
+    /// driver has stopped.
+    pub fn deliver(&self, from: E::Process, message: E::Message) -> Result<(), InvalidStateError> {
+        self.send(E::deliver(from, message))
+    }
+}
+
+/// A handle to a running [`ThreadDriver`].
+pub struct ThreadDriverHandle<E, C> {
+    sender: EventSender<E>,
+    join_handle: JoinHandle<Result<C, DriverError>>,
+}
+
+impl<E, C> ThreadDriverHandle<E, C> {
+    /// Returns a sender which may be used to send events to the driver from other threads.
+    pub fn sender(&self) -> EventSender<E> {
+        self.sender.clone()
+    }
+
+    /// Send an event to the driver. Returns an error if the driver has stopped.
+    pub fn send(&self, event: E) -> Result<(), InvalidStateError> {
+        self.sender.send(event)
+    }
+
+    /// Returns true if the driver thread has stopped.
+    pub fn is_finished(&self) -> bool {
+        self.join_handle.is_finished()
+    }
+
+    /// Stop the driver once it has processed the events already sent to it, returning the
+    /// latest context.
+    ///
+    /// If the driver has already stopped due to an error, that error is returned.
+    pub fn shutdown(self) -> Result<C, DriverError> {
+        // If the driver has already stopped, the result is available from the join handle.
+        let _ = self.sender.sender.send(Command::Shutdown);
+        self.join_handle
+            .join()
+            .unwrap_or(Err(DriverError::Panicked))
+    }
+}
+
+#[cfg(all(test, feature = "algorithm-two-phase-commit"))]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::error::AlgorithmError;
+    use crate::time::SystemTimeFactory;
+    use crate::two_phase_commit::{
+        Participant, TwoPhaseCommitActionNotification, TwoPhaseCommitAlgorithm,
+        TwoPhaseCommitContext, TwoPhaseCommitContextBuilder, TwoPhaseCommitEvent,
+        TwoPhaseCommitMessage, TwoPhaseCommitState,
+    };
+
+    use super::*;
+
+    fn participant_context() -> TwoPhaseCommitContext<String, String, SystemTime> {
+        TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(0)
+            .with_state(TwoPhaseCommitState::WaitingForVoteRequest)
+            .with_this_process("p".to_string())
+            .with_participant_processes(vec!["p".to_string()])
+            .build()
+            .unwrap()
+    }
+
+    /// Test that the driver processes inbound messages and events on its thread, emitting
+    /// outbound messages and notifications on the channels, and returns the context on shutdown.
+    #[test]
+    fn test_participant_commit() -> Result<(), Box<dyn std::error::Error>> {
+        let (messages, outbound) = mpsc::channel();
+        let (notifications, notified) = mpsc::channel();
+
+        let handle = ThreadDriver::new(
+            TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new()),
+            SystemTimeFactory::new(),
+            messages,
+            notifications,
+        )
+        .spawn(participant_context(), None);
+
+        let sender = handle.sender();
+        sender.deliver(
+            "c".into(),
+            TwoPhaseCommitMessage::VoteRequest(1, "v".into()),
+        )?;
+        assert_eq!(
+            notified.recv_timeout(Duration::from_secs(5))?,
+            TwoPhaseCommitActionNotification::ParticipantRequestForVote("v".into())
+        );
+
+        sender.send(TwoPhaseCommitEvent::Vote(true))?;
+        assert_eq!(
+            outbound.recv_timeout(Duration::from_secs(5))?,
+            ("c".into(), TwoPhaseCommitMessage::VoteResponse(1, true))
+        );
+
+        sender.deliver("c".into(), TwoPhaseCommitMessage::Commit(1))?;
+        assert_eq!(
+            notified.recv_timeout(Duration::from_secs(5))?,
+            TwoPhaseCommitActionNotification::Commit(1, "v".into())
+        );
+
+        let context = handle.shutdown()?;
+        assert_eq!(context.state(), TwoPhaseCommitState::WaitingForVoteRequest);
+
+        Ok(())
+    }
+
+    /// Test that a passed alarm is fired, and that a superseded alarm is not: the coordinator's
+    /// initial alarm is replaced by its vote timeout, which has not passed, so no abort occurs.
+    #[test]
+    fn test_alarms() -> Result<(), Box<dyn std::error::Error>> {
+        let (messages, outbound) = mpsc::channel();
+        let (notifications, notified) = mpsc::channel();
+
+        let context: TwoPhaseCommitContext<String, String, SystemTime> =
+            TwoPhaseCommitContextBuilder::new()
+                .with_coordinator("c".to_string())
+                .with_epoch(1)
+                .with_state(TwoPhaseCommitState::WaitingForStart)
+                .with_this_process("c".to_string())
+                .with_participants(vec![Participant::new("p".to_string())])
+                .build()?;
+
+        let handle = ThreadDriver::new(
+            TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new()),
+            SystemTimeFactory::new(),
+            messages,
+            notifications,
+        )
+        .spawn(context, Some(SystemTime::now() - Duration::from_secs(1)));
+
+        assert_eq!(
+            notified.recv_timeout(Duration::from_secs(5))?,
+            TwoPhaseCommitActionNotification::RequestForStart()
+        );
+
+        handle.send(TwoPhaseCommitEvent::Start("v".into()))?;
+        assert_eq!(
+            outbound.recv_timeout(Duration::from_secs(5))?,
+            (
+                "p".into(),
+                TwoPhaseCommitMessage::VoteRequest(1, "v".into())
+            )
+        );
+
+        let context = handle.shutdown()?;
+        assert!(matches!(
+            context.state(),
+            TwoPhaseCommitState::Voting { .. }
+        ));
+        assert!(notified.try_recv().is_err());
+
+        Ok(())
+    }
+
+    // Passes notifications, and the errors returned by the algorithm, to channels.
+    struct Handler {
+        notifications: mpsc::Sender<TwoPhaseCommitActionNotification<String>>,
+        errors: mpsc::Sender<String>,
+    }
+
+    impl NotificationHandler<TwoPhaseCommitActionNotification<String>> for Handler {
+        fn notify(
+            &mut self,
+            notification: TwoPhaseCommitActionNotification<String>,
+        ) -> Result<(), InternalError> {
+            self.notifications.notify(notification)
+        }
+
+        fn algorithm_error(&mut self, error: AlgorithmError) -> Result<(), InternalError> {
+            self.errors
+                .send(error.to_string())
+                .map_err(|_| InternalError::with_message("error channel closed"))
+        }
+    }
+
+    /// Test that an event rejected by the algorithm is reported to the handler without stopping
+    /// the driver: a participant's vote arriving when no vote is requested, as when the
+    /// application answers a request twice.
+    #[test]
+    fn test_algorithm_error() -> Result<(), Box<dyn std::error::Error>> {
+        let (messages, _outbound) = mpsc::channel();
+        let (notifications, notified) = mpsc::channel();
+        let (errors, errored) = mpsc::channel();
+
+        let handle = ThreadDriver::new(
+            TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new()),
+            SystemTimeFactory::new(),
+            messages,
+            Handler {
+                notifications,
+                errors,
+            },
+        )
+        .spawn(participant_context(), None);
+
+        handle.send(TwoPhaseCommitEvent::Vote(true))?;
+        errored.recv_timeout(Duration::from_secs(5))?;
+
+        // The driver is still running, with its context unchanged.
+        handle.send(TwoPhaseCommitEvent::Deliver(
+            "c".into(),
+            TwoPhaseCommitMessage::VoteRequest(1, "v".into()),
+        ))?;
+        assert_eq!(
+            notified.recv_timeout(Duration::from_secs(5))?,
+            TwoPhaseCommitActionNotification::ParticipantRequestForVote("v".into())
+        );
+        handle.send(TwoPhaseCommitEvent::Vote(true))?;
+        handle.send(TwoPhaseCommitEvent::Vote(true))?;
+        errored.recv_timeout(Duration::from_secs(5))?;
+
+        let context = handle.shutdown()?;
+        assert!(matches!(
+            context.state(),
+            TwoPhaseCommitState::Voted { vote: true, .. }
+        ));
+
+        Ok(())
+    }
+}
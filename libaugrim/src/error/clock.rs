@@ -0,0 +1,44 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains ClockDriftError
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FormatResult};
+use std::time::Duration;
+
+/// A remote clock's timestamp was further ahead of the local physical clock than the maximum
+/// drift allowed.
+///
+/// This usually means that either the local or the remote physical clock is badly out of sync.
+/// The timestamp is rejected so that a single faulty clock can not drag every clock forward.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClockDriftError {
+    /// How far the remote timestamp was ahead of the local physical clock.
+    pub ahead: Duration,
+    /// The maximum drift allowed.
+    pub max_drift: Duration,
+}
+
+impl Error for ClockDriftError {}
+
+impl Display for ClockDriftError {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        write!(
+            f,
+            "remote clock is {:?} ahead of the local clock, more than the maximum drift of {:?}",
+            self.ahead, self.max_drift
+        )
+    }
+}
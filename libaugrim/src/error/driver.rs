@@ -0,0 +1,55 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains DriverError
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FormatResult};
+
+use super::InternalError;
+
+/// An error which stops a driver.
+///
+/// Unlike [`AlgorithmError`](super::AlgorithmError), this error may be sent between threads, so
+/// that it can be returned from a driver running on another thread or task. The source error is
+/// kept as a message.
+///
+/// An error returned by the algorithm does not stop a driver; it is passed to
+/// [`NotificationHandler::algorithm_error`](crate::driver::NotificationHandler::algorithm_error),
+/// and stops the driver only if the handler returns an error.
+#[derive(Debug)]
+pub enum DriverError {
+    /// Carrying out an action failed; the transport or notification handler returned an error.
+    Action(String),
+
+    /// The driver's thread panicked.
+    Panicked,
+}
+
+impl Error for DriverError {}
+
+impl Display for DriverError {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match self {
+            DriverError::Action(msg) => write!(f, "action failed: {msg}"),
+            DriverError::Panicked => write!(f, "driver thread panicked"),
+        }
+    }
+}
+
+impl From<InternalError> for DriverError {
+    fn from(err: InternalError) -> Self {
+        DriverError::Action(err.to_string())
+    }
+}
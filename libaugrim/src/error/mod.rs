@@ -76,8 +76,16 @@
 
 #[cfg(feature = "algorithm")]
 mod algorithm;
+#[cfg(feature = "time")]
+mod clock;
+#[cfg(feature = "driver")]
+mod driver;
 
 #[cfg(feature = "algorithm")]
 pub use algorithm::AlgorithmError;
+#[cfg(feature = "time")]
+pub use clock::ClockDriftError;
+#[cfg(feature = "driver")]
+pub use driver::DriverError;
 
 pub use errling::{InternalError, InvalidStateError};
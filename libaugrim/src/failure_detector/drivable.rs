@@ -0,0 +1,66 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementations of the driver traits, allowing the failure detectors to be run by any driver.
+
+use crate::driver::{DrivableAction, DrivableEvent, DriverAction};
+use crate::process::Process;
+use crate::time::Time;
+
+use super::{
+    FailureDetectorAction, FailureDetectorEvent, FailureDetectorNotification, HeartbeatMessage,
+};
+
+impl<P, C, T> DrivableAction for FailureDetectorAction<P, C, T>
+where
+    P: Process,
+    T: Time,
+{
+    type Context = C;
+    type Time = T;
+    type Process = P;
+    type Message = HeartbeatMessage;
+    type Notification = FailureDetectorNotification<P>;
+
+    fn into_driver_action(
+        self,
+    ) -> DriverAction<Self::Context, Self::Time, Self::Process, Self::Message, Self::Notification>
+    {
+        match self {
+            FailureDetectorAction::Update { context, alarm } => {
+                DriverAction::Update { context, alarm }
+            }
+            FailureDetectorAction::SendMessage(to, message) => {
+                DriverAction::SendMessage(to, message)
+            }
+            FailureDetectorAction::Notify(notification) => DriverAction::Notify(notification),
+        }
+    }
+}
+
+impl<P> DrivableEvent for FailureDetectorEvent<P>
+where
+    P: Process,
+{
+    type Process = P;
+    type Message = HeartbeatMessage;
+
+    fn alarm() -> Option<Self> {
+        Some(FailureDetectorEvent::Alarm())
+    }
+
+    fn deliver(from: Self::Process, message: Self::Message) -> Self {
+        FailureDetectorEvent::Deliver(from, message)
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the eventually perfect failure detector.
+
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use crate::algorithm::Algorithm;
+use crate::error::AlgorithmError;
+use crate::process::Process;
+use crate::time::{Time, TimeSource};
+
+use super::{
+    answer, request_heartbeats, FailureDetectorAction, FailureDetectorEvent,
+    FailureDetectorNotification,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct EventuallyPerfectFailureDetectorContext<P, T> {
+    this_process: P,
+    processes: Vec<P>,
+    increment: Duration,
+    delay: Duration,
+    alive: Vec<P>,
+    suspected: Vec<P>,
+    alarm: Option<T>,
+}
+
+impl<P: Process, T: Time> EventuallyPerfectFailureDetectorContext<P, T> {
+    /// Create the context of `this_process`, which monitors `processes`, asking for heartbeats
+    /// every `timeout` at first; `this_process` is removed from the processes if it is among
+    /// them. The timeout is increased by the initial `timeout` each time a suspicion proves
+    /// false.
+    pub fn new(this_process: P, mut processes: Vec<P>, timeout: Duration) -> Self {
+        processes.retain(|process| *process != this_process);
+        Self {
+            this_process,
+            alive: processes.clone(),
+            processes,
+            increment: timeout,
+            delay: timeout,
+            suspected: Vec::new(),
+            alarm: None,
+        }
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    /// Returns the monitored processes.
+    pub fn processes(&self) -> &[P] {
+        &self.processes
+    }
+
+    /// Returns the current timeout, which grows with each false suspicion.
+    pub fn timeout(&self) -> Duration {
+        self.delay
+    }
+
+    /// Returns the processes currently suspected.
+    pub fn suspected(&self) -> &[P] {
+        &self.suspected
+    }
+
+    /// Returns the time of the next alarm, if the detector has started.
+    pub fn alarm(&self) -> Option<T> {
+        self.alarm
+    }
+}
+
+/// Eventually perfect failure detector, by increasing timeout (2.6.5): a process which does not
+/// reply to a heartbeat request before the next alarm is suspected, and restored when it replies
+/// before a later alarm. Whenever a suspected process replies, the timeout is increased.
+///
+/// Suspicions may be false, but once messages are delivered within some unknown bound, the
+/// timeout eventually exceeds it, after which only crashed processes are suspected.
+pub struct EventuallyPerfectFailureDetector<P, TS> {
+    time_source: TS,
+    _process: PhantomData<P>,
+}
+
+impl<P, TS> EventuallyPerfectFailureDetector<P, TS> {
+    pub fn new(time_source: TS) -> Self {
+        Self {
+            time_source,
+            _process: PhantomData,
+        }
+    }
+}
+
+impl<P, TS> Algorithm for EventuallyPerfectFailureDetector<P, TS>
+where
+    P: Process,
+    TS: TimeSource,
+{
+    type Event = FailureDetectorEvent<P>;
+    type Action =
+        FailureDetectorAction<P, EventuallyPerfectFailureDetectorContext<P, TS::Time>, TS::Time>;
+    type Context = EventuallyPerfectFailureDetectorContext<P, TS::Time>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        match event {
+            FailureDetectorEvent::Alarm() => {
+                // An alarm before the one set, such as one left from an earlier schedule, is
+                // ignored, so that processes are given the whole timeout to reply.
+                if context
+                    .alarm
+                    .is_some_and(|alarm| self.time_source.now() < alarm)
+                {
+                    return Ok(vec![]);
+                }
+
+                if context
+                    .alive
+                    .iter()
+                    .any(|process| context.suspected.contains(process))
+                {
+                    context.delay += context.increment;
+                }
+
+                let mut actions = Vec::new();
+                for process in &context.processes {
+                    let alive = context.alive.contains(process);
+                    let suspected = context.suspected.contains(process);
+                    if !alive && !suspected {
+                        context.suspected.push(process.clone());
+                        actions.push(FailureDetectorAction::Notify(
+                            FailureDetectorNotification::Suspect(process.clone()),
+                        ));
+                    } else if alive && suspected {
+                        context.suspected.retain(|p| p != process);
+                        actions.push(FailureDetectorAction::Notify(
+                            FailureDetectorNotification::Restore(process.clone()),
+                        ));
+                    }
+                }
+                request_heartbeats(&context.processes, &mut actions);
+                context.alive.clear();
+
+                let alarm = Some(self.time_source.now() + context.delay);
+                context.alarm = alarm;
+                actions.insert(0, FailureDetectorAction::Update { context, alarm });
+                Ok(actions)
+            }
+            FailureDetectorEvent::Deliver(process, message) => {
+                if let Some(actions) = answer(&context.processes, &process, message) {
+                    return Ok(actions);
+                }
+                if context.alive.contains(&process) {
+                    return Ok(vec![]);
+                }
+                context.alive.push(process);
+                let alarm = context.alarm;
+                Ok(vec![FailureDetectorAction::Update { context, alarm }])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use crate::time::ManualTimeSource;
+
+    use super::super::HeartbeatMessage;
+    use super::*;
+
+    /// Test that a process which misses a reply is suspected, then restored when it replies,
+    /// with the timeout increased.
+    #[test]
+    fn test_eventually_perfect_failure_detector() -> Result<(), AlgorithmError> {
+        let time_source = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let detector = EventuallyPerfectFailureDetector::new(time_source.clone());
+        let timeout = Duration::from_secs(1);
+        let mut context = EventuallyPerfectFailureDetectorContext::new(0, vec![0, 1], timeout);
+
+        let mut notifications = Vec::new();
+        for replies in [vec![], vec![1], vec![1], vec![]] {
+            let mut events = vec![FailureDetectorEvent::Alarm()];
+            events.extend(
+                replies
+                    .into_iter()
+                    .map(|p| FailureDetectorEvent::Deliver(p, HeartbeatMessage::Reply)),
+            );
+            for event in events {
+                for action in detector.event(event, context.clone())? {
+                    match action {
+                        FailureDetectorAction::Update { context: next, .. } => context = next,
+                        FailureDetectorAction::Notify(notification) => {
+                            notifications.push(notification)
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            time_source.advance(context.timeout());
+        }
+
+        assert_eq!(
+            notifications,
+            vec![
+                FailureDetectorNotification::Suspect(1),
+                FailureDetectorNotification::Restore(1)
+            ]
+        );
+        assert_eq!(context.timeout(), timeout * 2);
+        assert_eq!(context.alarm(), Some(time_source.now()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Failure detectors, as described in the following sources:
+//!
+//! - Cachin, Guerraoui, and Rodrigues, Reliable and Secure Distributed Programming, 2nd ed.,
+//!   2.6.
+//! - Hayashibara, Défago, Yared, and Katayama, The φ Accrual Failure Detector, 2004.
+//!
+//! Each detector monitors a fixed set of other processes by heartbeats: at each alarm it asks
+//! every process for a heartbeat, and decides whether to suspect each process from the replies:
+//!
+//! - [`PerfectFailureDetector`] (2.6.2): a process which does not reply before the next alarm
+//!   is suspected, permanently. The suspicions are only accurate if every reply arrives within
+//!   the timeout, as in a synchronous system.
+//! - [`EventuallyPerfectFailureDetector`] (2.6.5): a process which does not reply before the
+//!   next alarm is suspected, and restored if it later replies; each time a suspicion proves
+//!   false, the timeout is increased, so that once messages are delivered within some unknown
+//!   bound, correct processes are eventually never suspected.
+//! - [`PhiAccrualFailureDetector`]: the time since a process last replied is compared with the
+//!   times between its earlier replies, giving a suspicion level φ; a process is suspected while
+//!   φ is above a threshold, adapting to the delays of each process.
+//!
+//! The detectors share their events and actions. A detector is started by inputting
+//! [`FailureDetectorEvent::Alarm`], or by giving a driver an initial alarm, and reports its
+//! suspicions with [`FailureDetectorNotification::Suspect`] and
+//! [`FailureDetectorNotification::Restore`]. An algorithm which expects a perfect failure
+//! detector, such as those of the [`broadcast`](crate::broadcast) module, can be given a
+//! crash event for each suspicion of the perfect detector.
+//!
+//! An `Update` is always the first action of an event which changes the context, so a driver
+//! stores the context before carrying out the messages and notifications which follow from it.
+
+#[cfg(feature = "driver")]
+mod drivable;
+mod eventually_perfect;
+mod perfect;
+mod phi_accrual;
+
+use crate::message::Message;
+use crate::process::Process;
+
+pub use eventually_perfect::{
+    EventuallyPerfectFailureDetector, EventuallyPerfectFailureDetectorContext,
+};
+pub use perfect::{PerfectFailureDetector, PerfectFailureDetectorContext};
+pub use phi_accrual::{PhiAccrualFailureDetector, PhiAccrualFailureDetectorContext};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum HeartbeatMessage {
+    /// Ask the receiver for a heartbeat.
+    Request,
+    /// A heartbeat, in reply to a request.
+    Reply,
+}
+
+impl Message for HeartbeatMessage {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FailureDetectorEvent<P> {
+    Alarm(),
+    Deliver(P, HeartbeatMessage),
+}
+
+/// An action of a failure detector with context `C` and time `T`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FailureDetectorAction<P, C, T> {
+    Update { context: C, alarm: Option<T> },
+    SendMessage(P, HeartbeatMessage),
+    Notify(FailureDetectorNotification<P>),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FailureDetectorNotification<P> {
+    /// The process is suspected to have crashed.
+    Suspect(P),
+    /// The process, which was suspected, is no longer suspected.
+    Restore(P),
+    /// A message was dropped, for the given reason.
+    MessageDropped(String),
+}
+
+// Answer a heartbeat request from the process, or drop a message from a process which is not
+// monitored. Returns the actions, or `None` if the message is a reply to be recorded.
+fn answer<P, C, T>(
+    processes: &[P],
+    process: &P,
+    message: HeartbeatMessage,
+) -> Option<Vec<FailureDetectorAction<P, C, T>>>
+where
+    P: Process,
+{
+    if !processes.contains(process) {
+        return Some(vec![FailureDetectorAction::Notify(
+            FailureDetectorNotification::MessageDropped("sender is not a monitored process".into()),
+        )]);
+    }
+    match message {
+        HeartbeatMessage::Request => Some(vec![FailureDetectorAction::SendMessage(
+            process.clone(),
+            HeartbeatMessage::Reply,
+        )]),
+        HeartbeatMessage::Reply => None,
+    }
+}
+
+// Ask each of the processes for a heartbeat.
+fn request_heartbeats<'a, P, C, T>(
+    processes: impl IntoIterator<Item = &'a P>,
+    actions: &mut Vec<FailureDetectorAction<P, C, T>>,
+) where
+    P: Process + 'a,
+{
+    for process in processes {
+        actions.push(FailureDetectorAction::SendMessage(
+            process.clone(),
+            HeartbeatMessage::Request,
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the perfect failure detector.
+
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use crate::algorithm::Algorithm;
+use crate::error::AlgorithmError;
+use crate::process::Process;
+use crate::time::{Time, TimeSource};
+
+use super::{
+    answer, request_heartbeats, FailureDetectorAction, FailureDetectorEvent,
+    FailureDetectorNotification,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PerfectFailureDetectorContext<P, T> {
+    this_process: P,
+    processes: Vec<P>,
+    timeout: Duration,
+    alive: Vec<P>,
+    detected: Vec<P>,
+    alarm: Option<T>,
+}
+
+impl<P: Process, T: Time> PerfectFailureDetectorContext<P, T> {
+    /// Create the context of `this_process`, which monitors `processes`, asking for heartbeats
+    /// every `timeout`; `this_process` is removed from the processes if it is among them.
+    pub fn new(this_process: P, mut processes: Vec<P>, timeout: Duration) -> Self {
+        processes.retain(|process| *process != this_process);
+        Self {
+            this_process,
+            alive: processes.clone(),
+            processes,
+            timeout,
+            detected: Vec::new(),
+            alarm: None,
+        }
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    /// Returns the monitored processes.
+    pub fn processes(&self) -> &[P] {
+        &self.processes
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the processes detected to have crashed, in the order they were detected.
+    pub fn detected(&self) -> &[P] {
+        &self.detected
+    }
+
+    /// Returns the time of the next alarm, if the detector has started.
+    pub fn alarm(&self) -> Option<T> {
+        self.alarm
+    }
+}
+
+/// Perfect failure detector, by exclusion on timeout (2.6.2): a process which does not reply to
+/// a heartbeat request before the next alarm, a timeout later, is detected to have crashed, and
+/// is no longer monitored.
+pub struct PerfectFailureDetector<P, TS> {
+    time_source: TS,
+    _process: PhantomData<P>,
+}
+
+impl<P, TS> PerfectFailureDetector<P, TS> {
+    pub fn new(time_source: TS) -> Self {
+        Self {
+            time_source,
+            _process: PhantomData,
+        }
+    }
+}
+
+impl<P, TS> Algorithm for PerfectFailureDetector<P, TS>
+where
+    P: Process,
+    TS: TimeSource,
+{
+    type Event = FailureDetectorEvent<P>;
+    type Action = FailureDetectorAction<P, PerfectFailureDetectorContext<P, TS::Time>, TS::Time>;
+    type Context = PerfectFailureDetectorContext<P, TS::Time>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        match event {
+            FailureDetectorEvent::Alarm() => {
+                // An alarm before the one set, such as one left from an earlier schedule, is
+                // ignored, so that processes are given the whole timeout to reply.
+                if context
+                    .alarm
+                    .is_some_and(|alarm| self.time_source.now() < alarm)
+                {
+                    return Ok(vec![]);
+                }
+
+                let mut actions = Vec::new();
+                for process in &context.processes {
+                    if !context.alive.contains(process) && !context.detected.contains(process) {
+                        context.detected.push(process.clone());
+                        actions.push(FailureDetectorAction::Notify(
+                            FailureDetectorNotification::Suspect(process.clone()),
+                        ));
+                    }
+                }
+                request_heartbeats(
+                    context
+                        .processes
+                        .iter()
+                        .filter(|process| !context.detected.contains(process)),
+                    &mut actions,
+                );
+                context.alive.clear();
+
+                let alarm = Some(self.time_source.now() + context.timeout);
+                context.alarm = alarm;
+                actions.insert(0, FailureDetectorAction::Update { context, alarm });
+                Ok(actions)
+            }
+            FailureDetectorEvent::Deliver(process, message) => {
+                if let Some(actions) = answer(&context.processes, &process, message) {
+                    return Ok(actions);
+                }
+                if context.alive.contains(&process) {
+                    return Ok(vec![]);
+                }
+                context.alive.push(process);
+                let alarm = context.alarm;
+                Ok(vec![FailureDetectorAction::Update { context, alarm }])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use crate::time::ManualTimeSource;
+
+    use super::super::HeartbeatMessage;
+    use super::*;
+
+    /// Test that a process which stops replying is detected once, at the first alarm after its
+    /// last reply is due, and that replies leave the alarm set.
+    #[test]
+    fn test_perfect_failure_detector() -> Result<(), AlgorithmError> {
+        let time_source = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let detector = PerfectFailureDetector::new(time_source.clone());
+        let timeout = Duration::from_secs(1);
+        let mut context = PerfectFailureDetectorContext::new(0, vec![0, 1, 2], timeout);
+
+        let mut suspected = Vec::new();
+        for replies in [vec![1, 2], vec![1], vec![1], vec![]] {
+            let mut events = vec![FailureDetectorEvent::Alarm()];
+            events.extend(
+                replies
+                    .into_iter()
+                    .map(|p| FailureDetectorEvent::Deliver(p, HeartbeatMessage::Reply)),
+            );
+            for event in events {
+                for action in detector.event(event, context.clone())? {
+                    match action {
+                        FailureDetectorAction::Update {
+                            context: next,
+                            alarm,
+                        } => {
+                            assert_eq!(alarm, Some(time_source.now() + timeout));
+                            context = next;
+                        }
+                        FailureDetectorAction::Notify(FailureDetectorNotification::Suspect(p)) => {
+                            suspected.push(p)
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            time_source.advance(timeout);
+        }
+
+        assert_eq!(suspected, vec![2]);
+        assert_eq!(context.detected(), &[2]);
+
+        let alarm = detector.event(FailureDetectorEvent::Alarm(), context)?;
+        assert!(alarm.contains(&FailureDetectorAction::Notify(
+            FailureDetectorNotification::Suspect(1)
+        )));
+        assert!(!alarm.contains(&FailureDetectorAction::SendMessage(
+            2,
+            HeartbeatMessage::Request
+        )));
+
+        Ok(())
+    }
+
+    /// Test that an alarm before the one set is ignored, rather than detecting processes which
+    /// have not yet had the whole timeout to reply.
+    #[test]
+    fn test_early_alarm() -> Result<(), AlgorithmError> {
+        let time_source = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let detector = PerfectFailureDetector::new(time_source.clone());
+        let timeout = Duration::from_secs(1);
+        let context = PerfectFailureDetectorContext::new(0, vec![0, 1], timeout);
+
+        let context = match detector
+            .event(FailureDetectorEvent::Alarm(), context)?
+            .remove(0)
+        {
+            FailureDetectorAction::Update { context, .. } => context,
+            action => panic!("unexpected first action {action:?}"),
+        };
+
+        time_source.advance(timeout / 2);
+        assert_eq!(
+            detector.event(FailureDetectorEvent::Alarm(), context.clone())?,
+            vec![]
+        );
+
+        time_source.advance(timeout / 2);
+        assert!(detector
+            .event(FailureDetectorEvent::Alarm(), context)?
+            .contains(&FailureDetectorAction::Notify(
+                FailureDetectorNotification::Suspect(1)
+            )));
+
+        Ok(())
+    }
+}
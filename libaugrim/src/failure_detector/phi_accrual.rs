@@ -0,0 +1,350 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the φ accrual failure detector.
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use crate::algorithm::Algorithm;
+use crate::error::AlgorithmError;
+use crate::process::Process;
+use crate::time::{Time, TimeSource};
+
+use super::{
+    answer, request_heartbeats, FailureDetectorAction, FailureDetectorEvent,
+    FailureDetectorNotification,
+};
+
+const DEFAULT_THRESHOLD: f64 = 8.0;
+const DEFAULT_WINDOW: usize = 1000;
+const DEFAULT_MIN_STD_DEVIATION: Duration = Duration::from_millis(100);
+
+// The heartbeats received from a monitored process: when monitoring started and the last
+// arrived, and the intervals between the most recent.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct Heartbeats<P, T> {
+    process: P,
+    start: Option<T>,
+    last: Option<T>,
+    intervals: VecDeque<Duration>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PhiAccrualFailureDetectorContext<P, T> {
+    this_process: P,
+    interval: Duration,
+    threshold: f64,
+    window: usize,
+    min_std_deviation: Duration,
+    heartbeats: Vec<Heartbeats<P, T>>,
+    suspected: Vec<P>,
+    alarm: Option<T>,
+}
+
+impl<P: Process, T: Time> PhiAccrualFailureDetectorContext<P, T> {
+    /// Create the context of `this_process`, which monitors `processes`, asking for heartbeats
+    /// every `interval`; `this_process` is removed from the processes if it is among them.
+    ///
+    /// A process is suspected while φ is at least 8, estimated from the last 1000 intervals
+    /// between its heartbeats, with a standard deviation of at least 100ms.
+    pub fn new(this_process: P, processes: Vec<P>, interval: Duration) -> Self {
+        let heartbeats = processes
+            .into_iter()
+            .filter(|process| *process != this_process)
+            .map(|process| Heartbeats {
+                process,
+                start: None,
+                last: None,
+                intervals: VecDeque::new(),
+            })
+            .collect();
+        Self {
+            this_process,
+            interval,
+            threshold: DEFAULT_THRESHOLD,
+            window: DEFAULT_WINDOW,
+            min_std_deviation: DEFAULT_MIN_STD_DEVIATION,
+            heartbeats,
+            suspected: Vec::new(),
+            alarm: None,
+        }
+    }
+
+    /// Set the value of φ at which a process is suspected. A threshold of 1 suspects a process
+    /// which is correct with a probability of about 10%, 2 with 1%, and so on.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the number of intervals between heartbeats from which φ is estimated.
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    /// Set the least standard deviation of the intervals between heartbeats, so that a process
+    /// replying very regularly is not suspected for the slightest delay.
+    pub fn with_min_std_deviation(mut self, min_std_deviation: Duration) -> Self {
+        self.min_std_deviation = min_std_deviation;
+        self
+    }
+
+    pub fn this_process(&self) -> &P {
+        &self.this_process
+    }
+
+    /// Returns the monitored processes.
+    pub fn processes(&self) -> impl Iterator<Item = &P> {
+        self.heartbeats.iter().map(|heartbeats| &heartbeats.process)
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Returns the processes currently suspected.
+    pub fn suspected(&self) -> &[P] {
+        &self.suspected
+    }
+
+    /// Returns the time of the next alarm, if the detector has started.
+    pub fn alarm(&self) -> Option<T> {
+        self.alarm
+    }
+
+    /// Returns the level of suspicion φ of the process at the given time: the negated base 10
+    /// logarithm of the probability that a heartbeat would take longer than the time since the
+    /// last, given the intervals between earlier heartbeats. Returns `None` if the process is
+    /// not monitored.
+    ///
+    /// Measuring the intervals relies on [`Time::duration_since`]; if the time can not measure
+    /// durations, φ is always 0.
+    pub fn phi(&self, process: &P, now: T) -> Option<f64> {
+        self.heartbeats
+            .iter()
+            .find(|heartbeats| heartbeats.process == *process)
+            .map(|heartbeats| self.phi_of(heartbeats, now))
+    }
+
+    fn phi_of(&self, heartbeats: &Heartbeats<P, T>, now: T) -> f64 {
+        let last = match heartbeats.last.as_ref().or(heartbeats.start.as_ref()) {
+            Some(last) => last,
+            None => return 0.0,
+        };
+        let elapsed = now.duration_since(last).unwrap_or_default().as_secs_f64();
+
+        // Until a heartbeat has been received, the interval is estimated from the requests.
+        let (mean, std_deviation) = if heartbeats.intervals.is_empty() {
+            let interval = self.interval.as_secs_f64();
+            (interval, interval / 4.0)
+        } else {
+            let count = heartbeats.intervals.len() as f64;
+            let mean = heartbeats
+                .intervals
+                .iter()
+                .map(Duration::as_secs_f64)
+                .sum::<f64>()
+                / count;
+            let variance = heartbeats
+                .intervals
+                .iter()
+                .map(|interval| (interval.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / count;
+            (mean, variance.sqrt())
+        };
+        let std_deviation = std_deviation.max(self.min_std_deviation.as_secs_f64());
+
+        // A logistic approximation of the cumulative distribution function of the normal
+        // distribution, accurate to within 0.01%.
+        let y = (elapsed - mean) / std_deviation;
+        let e = (-y * (1.5976 + 0.070566 * y * y)).exp();
+        if elapsed > mean {
+            -(e / (1.0 + e)).log10()
+        } else {
+            -(1.0 - 1.0 / (1.0 + e)).log10()
+        }
+    }
+}
+
+/// φ accrual failure detector: the level of suspicion φ of each process grows with the time
+/// since its last heartbeat, relative to the distribution of the intervals between its earlier
+/// heartbeats, so that the timeout adapts to the network and load of each process. A process is
+/// suspected at an alarm when φ has reached the threshold, and restored when it next replies.
+pub struct PhiAccrualFailureDetector<P, TS> {
+    time_source: TS,
+    _process: PhantomData<P>,
+}
+
+impl<P, TS> PhiAccrualFailureDetector<P, TS> {
+    pub fn new(time_source: TS) -> Self {
+        Self {
+            time_source,
+            _process: PhantomData,
+        }
+    }
+}
+
+impl<P, TS> Algorithm for PhiAccrualFailureDetector<P, TS>
+where
+    P: Process,
+    TS: TimeSource,
+{
+    type Event = FailureDetectorEvent<P>;
+    type Action = FailureDetectorAction<P, PhiAccrualFailureDetectorContext<P, TS::Time>, TS::Time>;
+    type Context = PhiAccrualFailureDetectorContext<P, TS::Time>;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        mut context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let now = self.time_source.now();
+        match event {
+            FailureDetectorEvent::Alarm() => {
+                let mut actions = Vec::new();
+                let mut suspects = Vec::new();
+                for heartbeats in &context.heartbeats {
+                    if !context.suspected.contains(&heartbeats.process)
+                        && context.phi_of(heartbeats, now) >= context.threshold
+                    {
+                        suspects.push(heartbeats.process.clone());
+                    }
+                }
+                for process in suspects {
+                    context.suspected.push(process.clone());
+                    actions.push(FailureDetectorAction::Notify(
+                        FailureDetectorNotification::Suspect(process),
+                    ));
+                }
+                // Monitoring starts from the first request.
+                for heartbeats in &mut context.heartbeats {
+                    heartbeats.start.get_or_insert(now);
+                }
+                request_heartbeats(context.processes(), &mut actions);
+
+                let alarm = Some(now + context.interval);
+                context.alarm = alarm;
+                actions.insert(0, FailureDetectorAction::Update { context, alarm });
+                Ok(actions)
+            }
+            FailureDetectorEvent::Deliver(process, message) => {
+                let processes: Vec<P> = context.processes().cloned().collect();
+                if let Some(actions) = answer(&processes, &process, message) {
+                    return Ok(actions);
+                }
+
+                let window = context.window;
+                if let Some(heartbeats) = context
+                    .heartbeats
+                    .iter_mut()
+                    .find(|heartbeats| heartbeats.process == process)
+                {
+                    if let Some(last) = heartbeats.last {
+                        heartbeats
+                            .intervals
+                            .push_back(now.duration_since(&last).unwrap_or_default());
+                        while heartbeats.intervals.len() > window {
+                            heartbeats.intervals.pop_front();
+                        }
+                    }
+                    heartbeats.last = Some(now);
+                }
+
+                let mut actions = Vec::new();
+                if context.suspected.contains(&process)
+                    && context.phi(&process, now).unwrap_or_default() < context.threshold
+                {
+                    context.suspected.retain(|p| *p != process);
+                    actions.push(FailureDetectorAction::Notify(
+                        FailureDetectorNotification::Restore(process),
+                    ));
+                }
+                let alarm = context.alarm;
+                actions.insert(0, FailureDetectorAction::Update { context, alarm });
+                Ok(actions)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use crate::time::ManualTimeSource;
+
+    use super::super::HeartbeatMessage;
+    use super::*;
+
+    /// Test that a process which replies regularly is not suspected, that it is suspected once
+    /// its heartbeats stop for twice the usual interval, and that it is restored by its next
+    /// heartbeat.
+    #[test]
+    fn test_phi_accrual_failure_detector() -> Result<(), AlgorithmError> {
+        let time_source = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let detector = PhiAccrualFailureDetector::new(time_source.clone());
+        let interval = Duration::from_secs(1);
+        let mut context = PhiAccrualFailureDetectorContext::new(0, vec![0, 1], interval);
+
+        let mut notifications = Vec::new();
+        let mut event = |event, context: &mut PhiAccrualFailureDetectorContext<_, _>| {
+            for action in detector.event(event, context.clone())? {
+                match action {
+                    FailureDetectorAction::Update { context: next, .. } => *context = next,
+                    FailureDetectorAction::Notify(notification) => notifications.push(notification),
+                    FailureDetectorAction::SendMessage(..) => (),
+                }
+            }
+            Ok::<_, AlgorithmError>(())
+        };
+
+        for round in 0..8 {
+            event(FailureDetectorEvent::Alarm(), &mut context)?;
+            if round < 5 {
+                event(
+                    FailureDetectorEvent::Deliver(1, HeartbeatMessage::Reply),
+                    &mut context,
+                )?;
+            }
+            time_source.advance(interval);
+        }
+        let phi = context.phi(&1, time_source.now()).unwrap();
+        assert!(phi > context.threshold(), "phi {phi}");
+        assert_eq!(context.suspected(), &[1]);
+
+        time_source.advance(interval / 2);
+        event(
+            FailureDetectorEvent::Deliver(1, HeartbeatMessage::Reply),
+            &mut context,
+        )?;
+        assert!(context.suspected().is_empty());
+        assert_eq!(context.phi(&2, time_source.now()), None);
+
+        assert_eq!(
+            notifications,
+            vec![
+                FailureDetectorNotification::Suspect(1),
+                FailureDetectorNotification::Restore(1)
+            ]
+        );
+
+        Ok(())
+    }
+}
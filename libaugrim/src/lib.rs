@@ -30,17 +30,61 @@
 
 #[cfg(feature = "algorithm")]
 mod algorithm;
+#[cfg(feature = "algorithm-broadcast")]
+pub mod broadcast;
+#[cfg(feature = "check")]
+pub mod check;
+#[cfg(feature = "algorithm-consensus")]
+pub mod consensus;
+#[cfg(feature = "driver")]
+pub mod driver;
 pub mod error;
+#[cfg(feature = "algorithm-failure-detector")]
+pub mod failure_detector;
 mod message;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 mod process;
+#[cfg(feature = "sim")]
+pub mod sim;
+#[cfg(feature = "store")]
+pub mod store;
+#[cfg(any(feature = "metrics", feature = "time"))]
+mod sync;
+#[cfg(feature = "testing")]
+pub mod testing;
 #[cfg(feature = "time")]
 mod time;
+#[cfg(feature = "trace")]
+pub mod trace;
 #[cfg(feature = "algorithm-two-phase-commit")]
 pub mod two_phase_commit;
+#[cfg(feature = "wal")]
+pub mod wal;
 
+#[cfg(feature = "tracing")]
+pub use algorithm::TracingObserver;
 #[cfg(feature = "algorithm")]
 pub use algorithm::{Algorithm, IntoAlgorithm, Value};
-pub use message::Message;
+#[cfg(feature = "algorithm-boxed")]
+pub use algorithm::{AlgorithmRegistry, BoxedAlgorithm, DynAlgorithm};
+#[cfg(feature = "algorithm-async")]
+pub use algorithm::{AsyncAdapter, AsyncAlgorithm};
+#[cfg(feature = "algorithm-checked")]
+pub use algorithm::{Checked, Invariant};
+#[cfg(feature = "algorithm-adapters")]
+pub use algorithm::{
+    FilterActions, Inspect, MapAction, MapContext, MapEvent, TryMapAction, TryMapContext,
+    TryMapEvent,
+};
+#[cfg(feature = "algorithm-multiplexed")]
+pub use algorithm::{Multiplexed, MultiplexedContext};
+#[cfg(feature = "algorithm-observed")]
+pub use algorithm::{Observed, Observer};
+pub use message::{Message, Stamped};
 pub use process::Process;
 #[cfg(feature = "time")]
-pub use time::{SystemTimeFactory, Time, TimeSource};
+pub use time::{
+    HybridClock, HybridTimestamp, LamportClock, LamportTimestamp, LogicalClock, ManualTimeSource,
+    SystemTimeFactory, Time, TimeSource, VectorClock, VectorTimestamp,
+};
@@ -21,3 +21,28 @@
 /// [^note]: For a full explanation of messages and the relation to other components, see Cachin,
 /// Guerraoui, and Rodrigues, Reliable and Secure Distributed Programming, 2nd ed., 2.1.1.
 pub trait Message: Clone {}
+
+/// A message carrying a stamp, such as the timestamp of a
+/// [`LogicalClock`](crate::LogicalClock) at the time it was sent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Stamped<M, S> {
+    pub stamp: S,
+    pub message: M,
+}
+
+impl<M, S> Stamped<M, S> {
+    pub fn new(stamp: S, message: M) -> Self {
+        Self { stamp, message }
+    }
+
+    /// Returns the stamp and the message.
+    pub fn into_parts(self) -> (S, M) {
+        (self.stamp, self.message)
+    }
+}
+
+impl<M: Message, S: Clone> Message for Stamped<M, S> {}
+
+#[cfg(feature = "algorithm")]
+impl<V: crate::algorithm::Value, S: Clone> crate::algorithm::Value for Stamped<V, S> {}
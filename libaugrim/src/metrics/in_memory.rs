@@ -0,0 +1,239 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains InMemoryMetrics and Histogram.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use crate::sync::lock_recover;
+
+use super::MetricsSink;
+
+// Bucket `i` holds durations of at most 2^i microseconds; the final bucket is unbounded.
+const BUCKET_COUNT: usize = 40;
+
+/// A histogram of durations using exponentially-sized buckets.
+///
+/// Bucket boundaries are powers of two microseconds, so quantiles are approximate: they are
+/// reported as the upper bound of the bucket which contains them, clamped to the observed
+/// maximum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Histogram {
+    buckets: Vec<u64>,
+    count: u64,
+    sum: Duration,
+    min: Option<Duration>,
+    max: Option<Duration>,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Histogram {
+    pub fn new() -> Self {
+        Histogram {
+            buckets: vec![0; BUCKET_COUNT],
+            count: 0,
+            sum: Duration::ZERO,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Record a duration.
+    pub fn record(&mut self, duration: Duration) {
+        let micros = duration.as_micros();
+        let bucket = (0..BUCKET_COUNT - 1)
+            .find(|i| micros <= 1u128 << i)
+            .unwrap_or(BUCKET_COUNT - 1);
+
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.sum = self.sum.saturating_add(duration);
+        self.min = Some(self.min.map_or(duration, |min| min.min(duration)));
+        self.max = Some(self.max.map_or(duration, |max| max.max(duration)));
+    }
+
+    /// Returns the number of durations recorded.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the sum of all durations recorded.
+    pub fn sum(&self) -> Duration {
+        self.sum
+    }
+
+    /// Returns the smallest duration recorded, if any.
+    pub fn min(&self) -> Option<Duration> {
+        self.min
+    }
+
+    /// Returns the largest duration recorded, if any.
+    pub fn max(&self) -> Option<Duration> {
+        self.max
+    }
+
+    /// Returns the mean of all durations recorded, if any.
+    pub fn mean(&self) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.sum / u32::try_from(self.count).unwrap_or(u32::MAX))
+    }
+
+    /// Returns the approximate duration at the given quantile, between 0.0 and 1.0 inclusive.
+    ///
+    /// Returns `None` if no durations have been recorded, or if the quantile is outside that range
+    /// (including NaN).
+    pub fn quantile(&self, quantile: f64) -> Option<Duration> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return None;
+        }
+        let max = self.max?;
+        let rank = ((self.count as f64) * quantile).ceil().max(1.0) as u64;
+
+        let mut seen = 0;
+        for (i, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                if i == BUCKET_COUNT - 1 {
+                    return Some(max);
+                }
+                return Some(Duration::from_micros(1 << i).min(max));
+            }
+        }
+
+        Some(max)
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    counters: BTreeMap<&'static str, u64>,
+    histograms: BTreeMap<&'static str, Histogram>,
+}
+
+/// A [`MetricsSink`] which keeps all metrics in memory.
+///
+/// Clones share the same underlying storage, so one clone may be given to an algorithm while
+/// another is used to read the metrics.
+#[derive(Clone, Default)]
+pub struct InMemoryMetrics {
+    registry: Arc<Mutex<Registry>>,
+}
+
+impl InMemoryMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current value of the named counter, or 0 if it has never been incremented.
+    pub fn counter(&self, name: &str) -> u64 {
+        self.lock().counters.get(name).copied().unwrap_or(0)
+    }
+
+    /// Returns a copy of all counters.
+    pub fn counters(&self) -> BTreeMap<&'static str, u64> {
+        self.lock().counters.clone()
+    }
+
+    /// Returns a copy of the named histogram, if any durations have been recorded to it.
+    pub fn histogram(&self, name: &str) -> Option<Histogram> {
+        self.lock().histograms.get(name).cloned()
+    }
+
+    /// Returns a copy of all histograms.
+    pub fn histograms(&self) -> BTreeMap<&'static str, Histogram> {
+        self.lock().histograms.clone()
+    }
+
+    /// Discard all recorded metrics.
+    pub fn reset(&self) {
+        *self.lock() = Registry::default();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Registry> {
+        lock_recover(&self.registry)
+    }
+}
+
+impl MetricsSink for InMemoryMetrics {
+    fn increment_counter(&self, name: &'static str, value: u64) {
+        *self.lock().counters.entry(name).or_insert(0) += value;
+    }
+
+    fn record_duration(&self, name: &'static str, duration: Duration) {
+        self.lock()
+            .histograms
+            .entry(name)
+            .or_default()
+            .record(duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that the histogram reports count, sum, min, max and mean exactly, and quantiles as
+    /// bucket upper bounds clamped to the maximum.
+    #[test]
+    fn test_histogram() {
+        let mut histogram = Histogram::new();
+        assert_eq!(histogram.quantile(0.5), None);
+        assert_eq!(histogram.mean(), None);
+
+        for millis in [1, 2, 3, 4, 100] {
+            histogram.record(Duration::from_millis(millis));
+        }
+
+        assert_eq!(histogram.count(), 5);
+        assert_eq!(histogram.sum(), Duration::from_millis(110));
+        assert_eq!(histogram.min(), Some(Duration::from_millis(1)));
+        assert_eq!(histogram.max(), Some(Duration::from_millis(100)));
+        assert_eq!(histogram.mean(), Some(Duration::from_millis(22)));
+
+        // 3ms falls in the (2048us, 4096us] bucket.
+        assert_eq!(histogram.quantile(0.5), Some(Duration::from_micros(4096)));
+        assert_eq!(histogram.quantile(1.0), Some(Duration::from_millis(100)));
+        assert_eq!(histogram.quantile(0.0), Some(Duration::from_micros(1024)));
+        assert_eq!(histogram.quantile(-0.1), None);
+        assert_eq!(histogram.quantile(1.1), None);
+        assert_eq!(histogram.quantile(f64::NAN), None);
+    }
+
+    /// Test that clones of InMemoryMetrics share storage.
+    #[test]
+    fn test_in_memory_metrics() {
+        let metrics = InMemoryMetrics::new();
+        let sink = metrics.clone();
+
+        sink.increment_counter("a", 1);
+        sink.increment_counter("a", 2);
+        sink.record_duration("b", Duration::from_secs(1));
+
+        assert_eq!(metrics.counter("a"), 3);
+        assert_eq!(metrics.counter("c"), 0);
+        assert_eq!(metrics.histogram("b").map(|h| h.count()), Some(1));
+
+        metrics.reset();
+        assert_eq!(sink.counter("a"), 0);
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metrics reported by algorithms.
+//!
+//! Algorithms which support metrics report them to a [`MetricsSink`] as they process events. The
+//! names of the metrics reported are defined by each algorithm; for example, see
+//! [`two_phase_commit::metrics`](crate::two_phase_commit::metrics).
+//!
+//! Two implementations are provided: [`NoopMetrics`], which discards everything, and
+//! [`InMemoryMetrics`], which accumulates counters and latency [`Histogram`]s that may be read
+//! back by the application (for example, to export them to a monitoring system).
+
+mod in_memory;
+
+use std::time::Duration;
+
+pub use in_memory::{Histogram, InMemoryMetrics};
+
+/// A destination for metrics reported by an algorithm.
+///
+/// Methods take `&self` because algorithms process events via `&self`; implementations which
+/// accumulate state will need interior mutability.
+pub trait MetricsSink {
+    /// Increment the counter with the given name by `value`.
+    fn increment_counter(&self, name: &'static str, value: u64);
+
+    /// Record a duration in the histogram with the given name.
+    fn record_duration(&self, name: &'static str, duration: Duration);
+}
+
+/// A [`MetricsSink`] which discards all metrics.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopMetrics;
+
+impl MetricsSink for NoopMetrics {
+    fn increment_counter(&self, _name: &'static str, _value: u64) {}
+
+    fn record_duration(&self, _name: &'static str, _duration: Duration) {}
+}
@@ -0,0 +1,104 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains FaultSchedule, a declarative description of the faults to inject into a simulation.
+
+use std::time::Duration;
+
+/// A fault injected into a simulated cluster.
+///
+/// With the `serde` feature enabled, faults are (de)serialized with a `kind` field naming the
+/// variant in snake case, alongside the variant's fields.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+pub enum Fault<P> {
+    /// Stop the process. Events and messages for it are dropped, and its alarm does not fire,
+    /// until it is restarted.
+    Crash { process: P },
+    /// Restart a crashed process with its persisted context and alarm: those of the last
+    /// `Update` action it carried out before crashing, as a driver with a
+    /// [`ContextStore`](crate::store::ContextStore) would recover them.
+    Restart { process: P },
+    /// Partition the network into groups; messages between processes in different groups are
+    /// dropped. Processes which are not listed form one further group.
+    Partition { groups: Vec<Vec<P>> },
+    /// Remove any partition.
+    Heal,
+    /// Run the process's clock ahead of simulated time by the given number of milliseconds.
+    ClockSkew { process: P, ahead_ms: u64 },
+    /// Set the range from which the delay of each message is drawn, in milliseconds.
+    Delay { min_ms: u64, max_ms: u64 },
+    /// Set the probability that a message is delivered twice.
+    Duplicate { probability: f64 },
+}
+
+/// A fault, and the time since the start of the simulation at which it is injected.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ScheduledFault<P> {
+    pub at_ms: u64,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub fault: Fault<P>,
+}
+
+impl<P> ScheduledFault<P> {
+    pub fn new(at: Duration, fault: Fault<P>) -> Self {
+        Self {
+            at_ms: at.as_millis() as u64,
+            fault,
+        }
+    }
+
+    /// Returns the time since the start of the simulation at which the fault is injected.
+    pub fn at(&self) -> Duration {
+        Duration::from_millis(self.at_ms)
+    }
+}
+
+/// A schedule of faults to inject into a simulation, such as one read from a file.
+///
+/// For example, in JSON:
+///
+/// ```json
+/// { "faults": [
+///     { "at_ms": 15, "kind": "crash", "process": "p0" },
+///     { "at_ms": 5000, "kind": "partition", "groups": [["p1"], ["p2", "p3"]] },
+///     { "at_ms": 60000, "kind": "restart", "process": "p0" },
+///     { "at_ms": 60000, "kind": "heal" }
+/// ] }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FaultSchedule<P> {
+    pub faults: Vec<ScheduledFault<P>>,
+}
+
+impl<P> Default for FaultSchedule<P> {
+    fn default() -> Self {
+        Self { faults: Vec::new() }
+    }
+}
+
+impl<P> FaultSchedule<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a fault to the schedule.
+    pub fn with_fault(mut self, at: Duration, fault: Fault<P>) -> Self {
+        self.faults.push(ScheduledFault::new(at, fault));
+        self
+    }
+}
@@ -0,0 +1,683 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic, in-process simulation of a cluster running an algorithm.
+//!
+//! A [`Simulation`] runs one instance of an algorithm per process, on simulated time, delivering
+//! messages between them over a simulated network with randomized delays. Every random choice
+//! is drawn from a seeded [`SimRng`], so a run is reproduced exactly by reusing its seed; every
+//! step is recorded in a [`Trace`].
+//!
+//! Crashes, restarts, network partitions, clock skew, message delay and message duplication may
+//! be injected at given simulated times with a [`FaultSchedule`].
+
+mod faults;
+mod rng;
+#[cfg(feature = "algorithm-two-phase-commit")]
+pub mod two_phase_commit;
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::time::Duration;
+
+use crate::algorithm::Algorithm;
+use crate::driver::{AlarmScheduler, DrivableAction, DrivableEvent, DriverAction};
+use crate::error::InvalidStateError;
+use crate::time::{ManualTimeSource, Time};
+use crate::trace::{Trace, TraceStep};
+
+pub use faults::{Fault, FaultSchedule, ScheduledFault};
+pub use rng::SimRng;
+
+const DEFAULT_TICK: Duration = Duration::from_millis(10);
+const DEFAULT_MIN_DELAY: Duration = Duration::from_millis(1);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_millis(50);
+
+/// Counts of what happened during a simulation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimulationStats {
+    /// The number of events processed by the algorithms, including alarms.
+    pub events: usize,
+    /// The number of alarms fired.
+    pub alarms: usize,
+    /// The number of messages sent.
+    pub messages_sent: usize,
+    /// The number of messages delivered.
+    pub messages_delivered: usize,
+    /// The number of messages which were not delivered, because they were addressed to an
+    /// unknown or crashed process, or crossed a partition.
+    pub messages_dropped: usize,
+    /// The number of messages which were delivered a second time.
+    pub messages_duplicated: usize,
+    /// The number of processes crashed.
+    pub crashes: usize,
+    /// The number of processes restarted.
+    pub restarts: usize,
+    /// The number of events for which the algorithm returned an error.
+    pub errors: usize,
+}
+
+/// Something which happened to a process at a point in simulated time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationRecord<P, R> {
+    /// The time since the start of the simulation.
+    pub at: Duration,
+    pub process: P,
+    pub record: R,
+}
+
+// A process of the simulated cluster.
+struct SimProcess<A, T>
+where
+    A: Algorithm,
+{
+    algorithm: A,
+    clock: ManualTimeSource<T>,
+    context: A::Context,
+    alarm: AlarmScheduler<T>,
+    // How far the process's clock runs ahead of simulated time.
+    skew: Duration,
+    crashed: bool,
+}
+
+// An event which is due to be processed.
+struct Pending<P, E> {
+    process: P,
+    event: E,
+    // The sender, if the event is the delivery of a message.
+    from: Option<P>,
+}
+
+// Passes each notification to the application, which may respond with events for the notified
+// process.
+type Application<P, N, E> = Box<dyn FnMut(&P, &N) -> Vec<E>>;
+
+/// A simulated cluster of processes running an algorithm.
+///
+/// Processes are added with [`Simulation::add_process`], each with its own instance of the
+/// algorithm, created from a [`ManualTimeSource`] which the simulation controls. External events
+/// (for example, a request to start a commit) are scheduled with [`Simulation::schedule`], and
+/// notifications may be answered by an application callback set with
+/// [`Simulation::with_application`].
+///
+/// The simulation advances in ticks. At each tick, it processes every event which is due, in
+/// the order in which they were scheduled, then fires every alarm which has passed. Faults
+/// scheduled with [`Simulation::schedule_faults`] are injected before anything else due at the
+/// same time.
+pub struct Simulation<A, P, T>
+where
+    A: Algorithm,
+    A::Action: DrivableAction,
+{
+    start: T,
+    now: Duration,
+    tick: Duration,
+    min_delay: Duration,
+    max_delay: Duration,
+    rng: SimRng,
+    processes: BTreeMap<P, SimProcess<A, T>>,
+    // Pending events, keyed by the time they are due and the order in which they were
+    // scheduled.
+    pending: BTreeMap<(Duration, u64), Pending<P, A::Event>>,
+    faults: BTreeMap<(Duration, u64), Fault<P>>,
+    next_seq: u64,
+    partition: Vec<Vec<P>>,
+    duplicate_probability: f64,
+    application: Option<Application<P, <A::Action as DrivableAction>::Notification, A::Event>>,
+    trace: Trace<P, A::Event, A::Context, A::Action, T>,
+    notifications: Vec<SimulationRecord<P, <A::Action as DrivableAction>::Notification>>,
+    errors: Vec<SimulationRecord<P, String>>,
+    stats: SimulationStats,
+}
+
+impl<A, P, M, N, T> Simulation<A, P, T>
+where
+    A: Algorithm,
+    A::Event: DrivableEvent<Process = P, Message = M> + Clone,
+    A::Context: Clone,
+    A::Action: DrivableAction<Context = A::Context, Time = T, Process = P, Message = M, Notification = N>
+        + Clone,
+    M: Clone,
+    P: Ord + Clone + Debug,
+    T: Time,
+{
+    /// Create a simulation which starts at the given time, drawing random choices from the
+    /// given seed.
+    pub fn new(start: T, seed: u64) -> Self {
+        Self {
+            start,
+            now: Duration::ZERO,
+            tick: DEFAULT_TICK,
+            min_delay: DEFAULT_MIN_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            rng: SimRng::new(seed),
+            processes: BTreeMap::new(),
+            pending: BTreeMap::new(),
+            faults: BTreeMap::new(),
+            next_seq: 0,
+            partition: Vec::new(),
+            duplicate_probability: 0.0,
+            application: None,
+            trace: Trace::new(),
+            notifications: Vec::new(),
+            errors: Vec::new(),
+            stats: SimulationStats::default(),
+        }
+    }
+
+    /// Set the amount by which simulated time advances at each step.
+    pub fn with_tick(mut self, tick: Duration) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Set the range from which the delay of each message is drawn.
+    pub fn with_message_delay(mut self, min: Duration, max: Duration) -> Self {
+        self.min_delay = min;
+        self.max_delay = max;
+        self
+    }
+
+    /// Set the callback which is passed each notification, and which may respond with events
+    /// for the notified process.
+    pub fn with_application<F>(mut self, application: F) -> Self
+    where
+        F: FnMut(&P, &N) -> Vec<A::Event> + 'static,
+    {
+        self.application = Some(Box::new(application));
+        self
+    }
+
+    /// Add a process, with its initial context and alarm.
+    ///
+    /// `algorithm` is called with the time source the process's algorithm must use.
+    pub fn add_process<F>(
+        &mut self,
+        process: P,
+        algorithm: F,
+        context: A::Context,
+        alarm: Option<T>,
+    ) where
+        F: FnOnce(ManualTimeSource<T>) -> A,
+    {
+        let clock = ManualTimeSource::new(self.start);
+        let mut scheduler = AlarmScheduler::new();
+        scheduler.set(alarm);
+        self.processes.insert(
+            process,
+            SimProcess {
+                algorithm: algorithm(clock.clone()),
+                clock,
+                context,
+                alarm: scheduler,
+                skew: Duration::ZERO,
+                crashed: false,
+            },
+        );
+    }
+
+    /// Schedule an event for the process, at the given time since the start of the simulation.
+    pub fn schedule(&mut self, at: Duration, process: P, event: A::Event) {
+        self.push_pending(at, process, event, None);
+    }
+
+    /// Schedule the faults of the schedule to be injected.
+    ///
+    /// Returns an error, scheduling none of the faults, if any fault names a process which has
+    /// not been added.
+    pub fn schedule_faults(&mut self, schedule: FaultSchedule<P>) -> Result<(), InvalidStateError> {
+        for scheduled in &schedule.faults {
+            let unknown = match &scheduled.fault {
+                Fault::Crash { process }
+                | Fault::Restart { process }
+                | Fault::ClockSkew { process, .. } => {
+                    (!self.processes.contains_key(process)).then_some(process)
+                }
+                Fault::Partition { groups } => groups
+                    .iter()
+                    .flatten()
+                    .find(|process| !self.processes.contains_key(*process)),
+                Fault::Heal | Fault::Delay { .. } | Fault::Duplicate { .. } => None,
+            };
+            if let Some(process) = unknown {
+                return Err(InvalidStateError::with_message(format!(
+                    "fault at {}ms names unknown process {process:?}",
+                    scheduled.at_ms
+                )));
+            }
+        }
+
+        for scheduled in schedule.faults {
+            self.faults
+                .insert((scheduled.at(), self.next_seq), scheduled.fault);
+            self.next_seq += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Run the simulation for the given amount of simulated time.
+    pub fn run_for(&mut self, duration: Duration) {
+        let end = self.now + duration;
+        loop {
+            self.run_due();
+            if self.now + self.tick > end {
+                break;
+            }
+            self.now += self.tick;
+        }
+    }
+
+    /// Returns the time since the start of the simulation.
+    pub fn elapsed(&self) -> Duration {
+        self.now
+    }
+
+    /// Returns the current simulated time.
+    pub fn now(&self) -> T {
+        self.start + self.now
+    }
+
+    /// Returns the current context of the process.
+    pub fn context(&self, process: &P) -> Option<&A::Context> {
+        self.processes.get(process).map(|p| &p.context)
+    }
+
+    pub fn stats(&self) -> &SimulationStats {
+        &self.stats
+    }
+
+    /// Returns every notification, in the order in which they occurred.
+    pub fn notifications(&self) -> &[SimulationRecord<P, N>] {
+        &self.notifications
+    }
+
+    /// Returns the messages of every error returned by the algorithms.
+    pub fn errors(&self) -> &[SimulationRecord<P, String>] {
+        &self.errors
+    }
+
+    /// Returns the trace of every step taken so far.
+    pub fn trace(&self) -> &Trace<P, A::Event, A::Context, A::Action, T> {
+        &self.trace
+    }
+
+    fn push_pending(&mut self, at: Duration, process: P, event: A::Event, from: Option<P>) {
+        self.pending.insert(
+            (at, self.next_seq),
+            Pending {
+                process,
+                event,
+                from,
+            },
+        );
+        self.next_seq += 1;
+    }
+
+    fn inject(&mut self, fault: Fault<P>) {
+        match fault {
+            Fault::Crash { process } => {
+                if let Some(target) = self.processes.get_mut(&process) {
+                    target.crashed = true;
+                    self.stats.crashes += 1;
+                }
+            }
+            // The context and alarm are only ever replaced by `Update` actions, so they are
+            // already those which would have been persisted.
+            Fault::Restart { process } => {
+                if let Some(target) = self.processes.get_mut(&process) {
+                    if target.crashed {
+                        target.crashed = false;
+                        self.stats.restarts += 1;
+                    }
+                }
+            }
+            Fault::Partition { groups } => self.partition = groups,
+            Fault::Heal => self.partition.clear(),
+            Fault::ClockSkew { process, ahead_ms } => {
+                if let Some(target) = self.processes.get_mut(&process) {
+                    target.skew = Duration::from_millis(ahead_ms);
+                }
+            }
+            Fault::Delay { min_ms, max_ms } => {
+                self.min_delay = Duration::from_millis(min_ms);
+                self.max_delay = Duration::from_millis(max_ms);
+            }
+            Fault::Duplicate { probability } => self.duplicate_probability = probability,
+        }
+    }
+
+    // Returns whether a partition separates the two processes.
+    fn partitioned(&self, from: &P, to: &P) -> bool {
+        let group = |process: &P| {
+            self.partition
+                .iter()
+                .position(|group| group.contains(process))
+        };
+        !self.partition.is_empty() && group(from) != group(to)
+    }
+
+    // Returns whether the pending event can reach its process, counting the message if it is
+    // one.
+    fn deliverable(&mut self, pending: &Pending<P, A::Event>) -> bool {
+        let running = self
+            .processes
+            .get(&pending.process)
+            .map(|target| !target.crashed)
+            .unwrap_or(false);
+        match &pending.from {
+            Some(from) if running && !self.partitioned(from, &pending.process) => {
+                self.stats.messages_delivered += 1;
+                true
+            }
+            Some(_) => {
+                self.stats.messages_dropped += 1;
+                false
+            }
+            None => running,
+        }
+    }
+
+    // Process everything which is due now. Processing an event may schedule further events
+    // which are also due, so this continues until nothing is left to do.
+    fn run_due(&mut self) {
+        loop {
+            if let Some(entry) = self.faults.first_entry() {
+                if entry.key().0 <= self.now {
+                    let fault = entry.remove();
+                    self.inject(fault);
+                    continue;
+                }
+            }
+
+            if let Some(entry) = self.pending.first_entry() {
+                if entry.key().0 <= self.now {
+                    let pending = entry.remove();
+                    if self.deliverable(&pending) {
+                        self.process_event(pending.process, pending.event);
+                    }
+                    continue;
+                }
+            }
+
+            let now = self.now();
+            let due: Vec<P> = self
+                .processes
+                .iter_mut()
+                .filter(|(_, p)| !p.crashed)
+                .filter_map(|(process, p)| {
+                    p.alarm.take_due(&(now + p.skew)).then(|| process.clone())
+                })
+                .collect();
+            if due.is_empty() {
+                break;
+            }
+            for process in due {
+                if let Some(event) = A::Event::alarm() {
+                    self.stats.alarms += 1;
+                    self.process_event(process, event);
+                }
+            }
+        }
+    }
+
+    fn process_event(&mut self, process: P, event: A::Event) {
+        let Some(target) = self.processes.get_mut(&process) else {
+            return;
+        };
+
+        let now = self.start + self.now + target.skew;
+        target.clock.set(now);
+        let context = target.context.clone();
+        let result = target.algorithm.event(event.clone(), context.clone());
+        self.stats.events += 1;
+
+        let actions = match result {
+            Ok(actions) => actions,
+            Err(err) => {
+                self.stats.errors += 1;
+                self.errors.push(SimulationRecord {
+                    at: self.now,
+                    process: process.clone(),
+                    record: err.to_string(),
+                });
+                self.trace.push(TraceStep {
+                    process,
+                    time: now,
+                    event,
+                    context,
+                    actions: Vec::new(),
+                    error: Some(err.to_string()),
+                });
+                return;
+            }
+        };
+
+        self.trace.push(TraceStep {
+            process: process.clone(),
+            time: now,
+            event,
+            context,
+            actions: actions.clone(),
+            error: None,
+        });
+
+        for action in actions {
+            match action.into_driver_action() {
+                DriverAction::Update { context, alarm } => {
+                    if let Some(target) = self.processes.get_mut(&process) {
+                        target.context = context;
+                        target.alarm.set(alarm);
+                    }
+                }
+                DriverAction::SendMessage(to, message) => {
+                    self.stats.messages_sent += 1;
+                    let at = self.now + self.rng.duration(self.min_delay, self.max_delay);
+                    if self.duplicate_probability > 0.0
+                        && self.rng.chance(self.duplicate_probability)
+                    {
+                        self.stats.messages_duplicated += 1;
+                        let again = self.now + self.rng.duration(self.min_delay, self.max_delay);
+                        let event = A::Event::deliver(process.clone(), message.clone());
+                        self.push_pending(again, to.clone(), event, Some(process.clone()));
+                    }
+                    let event = A::Event::deliver(process.clone(), message);
+                    self.push_pending(at, to, event, Some(process.clone()));
+                }
+                DriverAction::Notify(notification) => {
+                    let events = self
+                        .application
+                        .as_mut()
+                        .map(|application| application(&process, &notification))
+                        .unwrap_or_default();
+                    for event in events {
+                        self.schedule(self.now, process.clone(), event);
+                    }
+                    self.notifications.push(SimulationRecord {
+                        at: self.now,
+                        process: process.clone(),
+                        record: notification,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "algorithm-two-phase-commit"))]
+pub(crate) mod tests {
+    use std::time::SystemTime;
+
+    use crate::two_phase_commit::{TwoPhaseCommitActionNotification, TwoPhaseCommitState};
+
+    use super::two_phase_commit::{add_cluster, application};
+    use super::*;
+
+    pub(crate) type TwoPhaseCommitSimulation =
+        two_phase_commit::TwoPhaseCommitSimulation<String, String, SystemTime>;
+
+    // A coordinator and the given number of participants which always vote yes, with the
+    // coordinator starting a single commit of "v".
+    pub(crate) fn simulation(seed: u64, participants: usize) -> TwoPhaseCommitSimulation {
+        let mut simulation = Simulation::new(SystemTime::UNIX_EPOCH, seed)
+            .with_application(application(["v".to_string()], || true));
+        add_cluster(
+            &mut simulation,
+            "c".to_string(),
+            (1..=participants).map(|i| format!("p{i}")).collect(),
+        )
+        .unwrap();
+        simulation
+    }
+
+    /// Test that every process of a simulated cluster learns of the commit.
+    #[test]
+    fn test_commit() {
+        let mut simulation = simulation(1, 2);
+        simulation.run_for(Duration::from_secs(1));
+
+        let commits: Vec<_> = simulation
+            .notifications()
+            .iter()
+            .filter(|n| n.record == TwoPhaseCommitActionNotification::Commit(1, "v".into()))
+            .map(|n| n.process.as_str())
+            .collect();
+        assert_eq!(commits.len(), 3);
+        for process in ["c", "p1", "p2"] {
+            assert!(commits.contains(&process));
+        }
+
+        let stats = simulation.stats();
+        assert_eq!(stats.errors, 0);
+        assert_eq!(stats.messages_sent, stats.messages_delivered);
+        assert_eq!(simulation.elapsed(), Duration::from_secs(1));
+    }
+
+    /// Test that runs with the same seed are identical, and that the seed affects the run.
+    #[test]
+    fn test_determinism() {
+        let run = |seed| {
+            let mut simulation = simulation(seed, 2);
+            simulation.run_for(Duration::from_secs(1));
+            simulation.trace().clone()
+        };
+
+        assert_eq!(run(7), run(7));
+        assert_ne!(run(7), run(8));
+    }
+
+    fn decisions(simulation: &TwoPhaseCommitSimulation, process: &str) -> Vec<String> {
+        simulation
+            .notifications()
+            .iter()
+            .filter(|n| n.process == process)
+            .filter_map(|n| match &n.record {
+                TwoPhaseCommitActionNotification::Commit(epoch, _) => {
+                    Some(format!("commit {epoch}"))
+                }
+                TwoPhaseCommitActionNotification::Abort(epoch, _) => Some(format!("abort {epoch}")),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Test that a participant blocks while the coordinator is down after requesting votes, and
+    /// that the commit is aborted once the coordinator restarts with its persisted context.
+    #[test]
+    fn test_coordinator_crash_and_restart() -> Result<(), Box<dyn std::error::Error>> {
+        let mut simulation = simulation(1, 1)
+            .with_message_delay(Duration::from_millis(20), Duration::from_millis(20));
+        simulation.schedule_faults(
+            FaultSchedule::new()
+                .with_fault(
+                    Duration::from_millis(15),
+                    Fault::Crash {
+                        process: "c".into(),
+                    },
+                )
+                .with_fault(
+                    Duration::from_secs(120),
+                    Fault::Restart {
+                        process: "c".into(),
+                    },
+                ),
+        )?;
+
+        simulation.run_for(Duration::from_secs(119));
+        assert!(decisions(&simulation, "p1").is_empty());
+        assert!(matches!(
+            simulation.context(&"p1".to_string()).map(|c| c.state()),
+            Some(TwoPhaseCommitState::Voted { vote: true, .. })
+        ));
+
+        simulation.run_for(Duration::from_secs(60));
+        assert_eq!(decisions(&simulation, "c"), vec!["abort 1"]);
+        assert_eq!(decisions(&simulation, "p1"), vec!["abort 1"]);
+
+        let stats = simulation.stats();
+        assert_eq!((stats.crashes, stats.restarts), (1, 1));
+        assert!(stats.messages_dropped > 0);
+
+        Ok(())
+    }
+
+    /// Test that the coordinator times out and aborts when one of several participants has
+    /// crashed, although the votes of the others arrive in the meantime.
+    #[test]
+    fn test_participant_crash() -> Result<(), Box<dyn std::error::Error>> {
+        let mut simulation = simulation(1, 2);
+        simulation.schedule_faults(FaultSchedule::new().with_fault(
+            Duration::ZERO,
+            Fault::Crash {
+                process: "p2".into(),
+            },
+        ))?;
+        simulation.run_for(Duration::from_secs(40));
+
+        assert_eq!(decisions(&simulation, "c"), vec!["abort 1"]);
+        assert_eq!(decisions(&simulation, "p1"), vec!["abort 1"]);
+        assert_eq!(simulation.stats().errors, 0);
+
+        Ok(())
+    }
+
+    /// Test that the coordinator aborts when partitioned from its participant, and that faults
+    /// naming unknown processes are rejected.
+    #[test]
+    fn test_partition() -> Result<(), Box<dyn std::error::Error>> {
+        let mut simulation = simulation(1, 1);
+        simulation.schedule_faults(FaultSchedule::new().with_fault(
+            Duration::ZERO,
+            Fault::Partition {
+                groups: vec![vec!["p1".into()]],
+            },
+        ))?;
+        simulation.run_for(Duration::from_secs(40));
+
+        assert_eq!(decisions(&simulation, "c"), vec!["abort 1"]);
+        assert!(decisions(&simulation, "p1").is_empty());
+        assert_eq!(simulation.stats().messages_delivered, 0);
+
+        assert!(simulation
+            .schedule_faults(FaultSchedule::new().with_fault(
+                Duration::ZERO,
+                Fault::Crash {
+                    process: "x".into()
+                },
+            ))
+            .is_err());
+
+        Ok(())
+    }
+}
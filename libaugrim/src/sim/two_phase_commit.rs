@@ -0,0 +1,103 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains helpers for simulating two-phase commit clusters.
+
+use std::fmt::Debug;
+
+use crate::algorithm::Value;
+use crate::error::InvalidStateError;
+use crate::process::Process;
+use crate::time::{ManualTimeSource, Time};
+use crate::two_phase_commit::{
+    Participant, TwoPhaseCommitActionNotification, TwoPhaseCommitAlgorithm,
+    TwoPhaseCommitContextBuilder, TwoPhaseCommitEvent, TwoPhaseCommitState,
+};
+
+use super::Simulation;
+
+/// A simulated two-phase commit cluster, with processes `P`, values `V` and time `T`.
+pub type TwoPhaseCommitSimulation<P, V, T> =
+    Simulation<TwoPhaseCommitAlgorithm<P, V, ManualTimeSource<T>>, P, T>;
+
+/// Returns the application of a two-phase commit cluster, for
+/// [`Simulation::with_application`]: the coordinator starts each of the values in turn, as it
+/// requests them, and every request for a vote is answered with the result of `vote`.
+pub fn application<P, V, I, F>(
+    values: I,
+    mut vote: F,
+) -> impl FnMut(&P, &TwoPhaseCommitActionNotification<V>) -> Vec<TwoPhaseCommitEvent<P, V>>
+where
+    P: Process,
+    V: Value,
+    I: IntoIterator<Item = V>,
+    F: FnMut() -> bool,
+{
+    let mut values = values.into_iter();
+    move |_, notification| match notification {
+        TwoPhaseCommitActionNotification::RequestForStart() => values
+            .next()
+            .map(TwoPhaseCommitEvent::Start)
+            .into_iter()
+            .collect(),
+        TwoPhaseCommitActionNotification::CoordinatorRequestForVote()
+        | TwoPhaseCommitActionNotification::ParticipantRequestForVote(_) => {
+            vec![TwoPhaseCommitEvent::Vote(vote())]
+        }
+        _ => vec![],
+    }
+}
+
+/// Add a two-phase commit cluster to the simulation: the coordinator, which starts its first
+/// epoch as soon as the simulation runs, and its participants.
+///
+/// Returns an error if the contexts of the processes can not be built.
+pub fn add_cluster<P, V, T>(
+    simulation: &mut TwoPhaseCommitSimulation<P, V, T>,
+    coordinator: P,
+    participants: Vec<P>,
+) -> Result<(), InvalidStateError>
+where
+    P: Process + Ord + Debug,
+    V: Value,
+    T: Time,
+{
+    let context = TwoPhaseCommitContextBuilder::new()
+        .with_coordinator(coordinator.clone())
+        .with_epoch(1)
+        .with_state(TwoPhaseCommitState::WaitingForStart)
+        .with_this_process(coordinator.clone())
+        .with_participants(participants.iter().cloned().map(Participant::new).collect())
+        .build()?;
+    let start = simulation.now();
+    simulation.add_process(
+        coordinator.clone(),
+        TwoPhaseCommitAlgorithm::new,
+        context,
+        Some(start),
+    );
+
+    for process in &participants {
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator(coordinator.clone())
+            .with_epoch(0)
+            .with_state(TwoPhaseCommitState::WaitingForVoteRequest)
+            .with_this_process(process.clone())
+            .with_participant_processes(participants.clone())
+            .build()?;
+        simulation.add_process(process.clone(), TwoPhaseCommitAlgorithm::new, context, None);
+    }
+
+    Ok(())
+}
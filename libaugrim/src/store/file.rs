@@ -0,0 +1,312 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains FileContextStore.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+use crate::error::InternalError;
+
+use super::record::{encode_record, io_error, parse_records, read_file, sync_dir};
+use super::{Codec, ContextStore};
+
+const HISTORY_FILE: &str = "history.log";
+const CURRENT_FILE: &str = "current";
+const CURRENT_TEMP_FILE: &str = "current.tmp";
+
+/// A crash-safe [`ContextStore`] which keeps contexts in files within a directory.
+///
+/// Each stored context is first appended to a history log and flushed to disk (the write-ahead
+/// step). It is then written to a temporary file, flushed, and atomically renamed over the
+/// current context file. Records carry a sequence number and a checksum, so a record which was
+/// only partially written before a crash is detected and discarded when the store is opened; if
+/// the crash occurred after the history was written but before the rename, the current context
+/// is rolled forward from the history. A record left by a failed store is removed before the next
+/// context is logged, so that no context is logged after it.
+///
+/// The history grows with every stored context; [`FileContextStore::clear_history`] discards it
+/// without affecting the current context.
+pub struct FileContextStore<C, K> {
+    dir: PathBuf,
+    codec: K,
+    log: File,
+    // The length of the complete records in the history.
+    len: u64,
+    next_seq: u64,
+    _context: PhantomData<fn() -> C>,
+}
+
+impl<C, K> FileContextStore<C, K>
+where
+    K: Codec<C>,
+{
+    /// Open the store in the given directory, creating the directory if necessary and
+    /// recovering from any interrupted store.
+    pub fn open(dir: impl Into<PathBuf>, codec: K) -> Result<Self, InternalError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(io_error)?;
+
+        let history_path = dir.join(HISTORY_FILE);
+        let history = read_file(&history_path)?;
+        let (records, valid_len) = parse_records(&history);
+
+        let created = !history_path.exists();
+        let log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&history_path)
+            .map_err(io_error)?;
+        // Make the new history file's directory entry durable before anything is logged to it.
+        if created {
+            sync_dir(&dir)?;
+        }
+        // Discard a partially written record left by a crash.
+        if valid_len < history.len() {
+            log.set_len(valid_len as u64).map_err(io_error)?;
+            log.sync_all().map_err(io_error)?;
+        }
+
+        let current = parse_records(&read_file(&dir.join(CURRENT_FILE))?).0.pop();
+
+        let mut store = Self {
+            dir,
+            codec,
+            log,
+            len: valid_len as u64,
+            next_seq: 1,
+            _context: PhantomData,
+        };
+
+        let current_seq = current.as_ref().map(|(seq, _)| *seq).unwrap_or(0);
+        match records.last() {
+            Some((seq, payload)) if *seq > current_seq => {
+                store.write_current(*seq, payload)?;
+                store.next_seq = seq + 1;
+            }
+            _ => store.next_seq = current_seq + 1,
+        }
+
+        Ok(store)
+    }
+
+    /// Discard the history, keeping the current context.
+    pub fn clear_history(&mut self) -> Result<(), InternalError> {
+        self.log.set_len(0).map_err(io_error)?;
+        self.len = 0;
+        self.log.sync_all().map_err(io_error)
+    }
+
+    fn write_current(&self, seq: u64, payload: &[u8]) -> Result<(), InternalError> {
+        let temp_path = self.dir.join(CURRENT_TEMP_FILE);
+        let mut temp = File::create(&temp_path).map_err(io_error)?;
+        temp.write_all(&encode_record(seq, payload))
+            .map_err(io_error)?;
+        temp.sync_all().map_err(io_error)?;
+
+        fs::rename(&temp_path, self.dir.join(CURRENT_FILE)).map_err(io_error)?;
+        sync_dir(&self.dir)
+    }
+}
+
+impl<C, K> ContextStore<C> for FileContextStore<C, K>
+where
+    K: Codec<C>,
+{
+    fn load(&self) -> Result<Option<C>, InternalError> {
+        parse_records(&read_file(&self.dir.join(CURRENT_FILE))?)
+            .0
+            .pop()
+            .map(|(_, payload)| self.codec.decode(&payload))
+            .transpose()
+    }
+
+    fn store(&mut self, context: &C) -> Result<(), InternalError> {
+        let payload = self.codec.encode(context)?;
+        let seq = self.next_seq;
+        let record = encode_record(seq, &payload);
+
+        // Remove any part of a record left by a failed store; until it is removed, nothing more
+        // can be appended to the history.
+        if self.log.metadata().map_err(io_error)?.len() != self.len {
+            self.log.set_len(self.len).map_err(io_error)?;
+        }
+
+        self.log.write_all(&record).map_err(io_error)?;
+        self.log.sync_data().map_err(io_error)?;
+        self.len += record.len() as u64;
+        // The record is in the history even if the current file can not be written, so its
+        // sequence number must not be used again.
+        self.next_seq += 1;
+
+        self.write_current(seq, &payload)
+    }
+
+    fn history(&self) -> Result<Vec<C>, InternalError> {
+        parse_records(&read_file(&self.dir.join(HISTORY_FILE))?)
+            .0
+            .iter()
+            .map(|(_, payload)| self.codec.decode(payload))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    struct U64Codec;
+
+    impl Codec<u64> for U64Codec {
+        fn encode(&self, value: &u64) -> Result<Vec<u8>, InternalError> {
+            Ok(value.to_le_bytes().to_vec())
+        }
+
+        fn decode(&self, bytes: &[u8]) -> Result<u64, InternalError> {
+            bytes
+                .try_into()
+                .map(u64::from_le_bytes)
+                .map_err(|e| InternalError::from_source(Box::new(e)))
+        }
+    }
+
+    // A directory which is removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default();
+            Self(std::env::temp_dir().join(format!("augrim-{name}-{}-{nanos}", std::process::id())))
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Test that stored contexts survive reopening the store, along with their history.
+    #[test]
+    fn test_store_and_reopen() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("reopen");
+
+        let mut store = FileContextStore::open(&dir.0, U64Codec)?;
+        assert_eq!(store.load()?, None);
+        store.store(&1)?;
+        store.store(&2)?;
+        assert_eq!(store.load()?, Some(2));
+        drop(store);
+
+        let mut store = FileContextStore::open(&dir.0, U64Codec)?;
+        assert_eq!(store.load()?, Some(2));
+        store.store(&3)?;
+        assert_eq!(store.history()?, vec![1, 2, 3]);
+
+        store.clear_history()?;
+        assert!(store.history()?.is_empty());
+        assert_eq!(store.load()?, Some(3));
+
+        Ok(())
+    }
+
+    /// Test that a partially written history record is discarded, and that a context written to
+    /// the history but not renamed into place is rolled forward.
+    #[test]
+    fn test_recovery() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("recovery");
+
+        let mut store = FileContextStore::open(&dir.0, U64Codec)?;
+        store.store(&1)?;
+        drop(store);
+
+        // A crash after the write-ahead step, but before the rename.
+        let mut log = OpenOptions::new()
+            .append(true)
+            .open(dir.0.join(HISTORY_FILE))?;
+        log.write_all(&encode_record(2, &2u64.to_le_bytes()))?;
+        // A crash part way through writing the next record.
+        log.write_all(&encode_record(3, &3u64.to_le_bytes())[..10])?;
+        drop(log);
+
+        let mut store = FileContextStore::open(&dir.0, U64Codec)?;
+        assert_eq!(store.load()?, Some(2));
+        assert_eq!(store.history()?, vec![1, 2]);
+
+        store.store(&4)?;
+        assert_eq!(store.history()?, vec![1, 2, 4]);
+        assert_eq!(store.load()?, Some(4));
+
+        Ok(())
+    }
+
+    /// Test that a context which was logged but could not be written to the current file does
+    /// not share its sequence number with the next.
+    #[test]
+    fn test_store_after_failed_write() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("failed-write");
+
+        let mut store = FileContextStore::open(&dir.0, U64Codec)?;
+        // The temporary file can not be created while a directory is in its place.
+        fs::create_dir(dir.0.join(CURRENT_TEMP_FILE))?;
+        assert!(store.store(&1).is_err());
+        fs::remove_dir(dir.0.join(CURRENT_TEMP_FILE))?;
+        store.store(&2)?;
+
+        let seqs: Vec<u64> = parse_records(&read_file(&dir.0.join(HISTORY_FILE))?)
+            .0
+            .into_iter()
+            .map(|(seq, _)| seq)
+            .collect();
+        assert_eq!(seqs, vec![1, 2]);
+        assert_eq!(store.load()?, Some(2));
+
+        Ok(())
+    }
+
+    /// Test that part of a record left by a failed store is removed by the next store, rather
+    /// than the next context being written after it and lost from the history.
+    #[test]
+    fn test_store_after_torn_write() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("torn-write");
+
+        let mut store = FileContextStore::open(&dir.0, U64Codec)?;
+        store.store(&1)?;
+
+        // A failed store, which wrote only part of its record.
+        let mut log = OpenOptions::new()
+            .append(true)
+            .open(dir.0.join(HISTORY_FILE))?;
+        log.write_all(&encode_record(2, &2u64.to_le_bytes())[..10])?;
+        drop(log);
+
+        store.store(&3)?;
+        store.store(&4)?;
+        assert_eq!(store.history()?, vec![1, 3, 4]);
+        drop(store);
+
+        let store = FileContextStore::open(&dir.0, U64Codec)?;
+        assert_eq!(store.history()?, vec![1, 3, 4]);
+        assert_eq!(store.load()?, Some(4));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains JsonCodec.
+
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::InternalError;
+
+use super::Codec;
+
+/// A [`Codec`] which encodes values as JSON.
+///
+/// With the `serde` feature enabled, the 2PC contexts and [`Checkpoint`](super::Checkpoint)
+/// implement the required serde traits.
+pub struct JsonCodec<C> {
+    _value: PhantomData<fn(C) -> C>,
+}
+
+impl<C> Default for JsonCodec<C> {
+    fn default() -> Self {
+        Self {
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<C> JsonCodec<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C> Codec<C> for JsonCodec<C>
+where
+    C: Serialize + DeserializeOwned,
+{
+    fn encode(&self, value: &C) -> Result<Vec<u8>, InternalError> {
+        serde_json::to_vec(value).map_err(|e| InternalError::from_source(Box::new(e)))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<C, InternalError> {
+        serde_json::from_slice(bytes).map_err(|e| InternalError::from_source(Box::new(e)))
+    }
+}
+
+#[cfg(all(test, feature = "algorithm-two-phase-commit"))]
+mod tests {
+    use std::time::SystemTime;
+
+    use crate::store::Checkpoint;
+    use crate::two_phase_commit::{
+        Participant, TwoPhaseCommitContext, TwoPhaseCommitContextBuilder, TwoPhaseCommitState,
+    };
+
+    use super::*;
+
+    /// Test that a 2PC checkpoint survives a round trip through JSON.
+    #[test]
+    fn test_two_phase_commit_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let now = SystemTime::now();
+        let context: TwoPhaseCommitContext<String, String, SystemTime> =
+            TwoPhaseCommitContextBuilder::new()
+                .with_coordinator("c".to_string())
+                .with_epoch(3)
+                .with_last_commit_epoch(2)
+                .with_state(TwoPhaseCommitState::Voting {
+                    vote_timeout_start: now,
+                })
+                .with_this_process("c".to_string())
+                .with_participants(vec![Participant::new("p".to_string())])
+                .with_value("v".to_string())
+                .build()?;
+        let checkpoint = Checkpoint {
+            context,
+            alarm: Some(now),
+        };
+
+        let codec = JsonCodec::new();
+        let decoded = codec.decode(&codec.encode(&checkpoint)?)?;
+        assert_eq!(decoded, checkpoint);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains MemoryContextStore.
+
+use crate::error::InternalError;
+
+use super::ContextStore;
+
+/// A [`ContextStore`] which keeps contexts in memory.
+///
+/// This is not durable, and is intended for tests and for processes which do not need to
+/// survive a restart.
+#[derive(Clone, Debug)]
+pub struct MemoryContextStore<C> {
+    history: Vec<C>,
+}
+
+impl<C> Default for MemoryContextStore<C> {
+    fn default() -> Self {
+        Self {
+            history: Vec::new(),
+        }
+    }
+}
+
+impl<C> MemoryContextStore<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C> ContextStore<C> for MemoryContextStore<C>
+where
+    C: Clone,
+{
+    fn load(&self) -> Result<Option<C>, InternalError> {
+        Ok(self.history.last().cloned())
+    }
+
+    fn store(&mut self, context: &C) -> Result<(), InternalError> {
+        self.history.push(context.clone());
+        Ok(())
+    }
+
+    fn history(&self) -> Result<Vec<C>, InternalError> {
+        Ok(self.history.clone())
+    }
+}
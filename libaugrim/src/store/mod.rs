@@ -0,0 +1,237 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Durable storage for algorithm contexts.
+//!
+//! The context carried by an `Update` action must be durably stored before any message which
+//! follows it is sent; otherwise a process which crashes and restarts may contradict a message it
+//! has already sent. A [`ContextStore`] stores contexts, and [`execute_actions`] carries out a
+//! batch of actions in an order which respects this rule.
+
+mod file;
+#[cfg(feature = "store-json")]
+mod json;
+mod memory;
+pub(crate) mod record;
+
+use crate::driver::{DrivableAction, DriverAction, NotificationHandler, Transport};
+use crate::error::InternalError;
+
+pub use file::FileContextStore;
+#[cfg(feature = "store-json")]
+pub use json::JsonCodec;
+pub use memory::MemoryContextStore;
+
+/// Durable storage for the context of an algorithm.
+pub trait ContextStore<C> {
+    /// Returns the most recently stored context, or `None` if no context has been stored.
+    fn load(&self) -> Result<Option<C>, InternalError>;
+
+    /// Store the context, replacing the current context.
+    ///
+    /// The store must be atomic: once this returns successfully, [`ContextStore::load`] returns
+    /// the new context, even after a crash; if it fails or is interrupted, `load` returns either
+    /// the new or the previous context, never a partially written one.
+    fn store(&mut self, context: &C) -> Result<(), InternalError>;
+
+    /// Returns every stored context, oldest first.
+    fn history(&self) -> Result<Vec<C>, InternalError>;
+}
+
+/// Converts values to and from bytes, for stores which write to files.
+pub trait Codec<C> {
+    fn encode(&self, value: &C) -> Result<Vec<u8>, InternalError>;
+
+    fn decode(&self, bytes: &[u8]) -> Result<C, InternalError>;
+}
+
+/// The state stored for an algorithm: its context along with its pending alarm.
+///
+/// The alarm is stored so that a restarted process resumes waiting for the same timeout.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Checkpoint<C, T> {
+    pub context: C,
+    pub alarm: Option<T>,
+}
+
+/// Carry out a batch of actions, storing each `Update` before any later action is carried out.
+///
+/// Consecutive `Update` actions are coalesced, so only the last of them is stored; it is stored
+/// before the next `SendMessage` or `Notify` action, or at the end of the batch. If storing
+/// fails, no further actions are carried out.
+///
+/// Returns the last `Update` in the batch, if any, which should become the context and alarm
+/// used for the next event.
+#[allow(clippy::type_complexity)]
+pub fn execute_actions<A, S, Tr, H>(
+    actions: impl IntoIterator<Item = A>,
+    store: &mut S,
+    transport: &mut Tr,
+    handler: &mut H,
+) -> Result<Option<Checkpoint<A::Context, A::Time>>, InternalError>
+where
+    A: DrivableAction,
+    S: ContextStore<Checkpoint<A::Context, A::Time>>,
+    Tr: Transport<A::Process, A::Message>,
+    H: NotificationHandler<A::Notification>,
+{
+    let mut pending = None;
+    let mut latest = None;
+
+    for action in actions {
+        match action.into_driver_action() {
+            DriverAction::Update { context, alarm } => {
+                pending = Some(Checkpoint { context, alarm });
+            }
+            DriverAction::SendMessage(to, message) => {
+                flush(&mut pending, &mut latest, store)?;
+                transport.send(to, message)?;
+            }
+            DriverAction::Notify(notification) => {
+                flush(&mut pending, &mut latest, store)?;
+                handler.notify(notification)?;
+            }
+        }
+    }
+    flush(&mut pending, &mut latest, store)?;
+
+    Ok(latest)
+}
+
+fn flush<C, S>(
+    pending: &mut Option<C>,
+    latest: &mut Option<C>,
+    store: &mut S,
+) -> Result<(), InternalError>
+where
+    S: ContextStore<C>,
+{
+    if let Some(checkpoint) = pending.take() {
+        store.store(&checkpoint)?;
+        *latest = Some(checkpoint);
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "algorithm-two-phase-commit"))]
+mod tests {
+    use std::cell::RefCell;
+    use std::time::SystemTime;
+
+    use crate::algorithm::Algorithm;
+    use crate::time::SystemTimeFactory;
+    use crate::two_phase_commit::{
+        Participant, TwoPhaseCommitAlgorithm, TwoPhaseCommitContext, TwoPhaseCommitContextBuilder,
+        TwoPhaseCommitEvent, TwoPhaseCommitMessage, TwoPhaseCommitState,
+    };
+
+    use super::*;
+
+    type Context = TwoPhaseCommitContext<String, String, SystemTime>;
+
+    // Records stores in a shared log, so that their order relative to sends can be checked.
+    struct RecordingStore<'a> {
+        log: &'a RefCell<Vec<String>>,
+        inner: MemoryContextStore<Checkpoint<Context, SystemTime>>,
+    }
+
+    impl ContextStore<Checkpoint<Context, SystemTime>> for RecordingStore<'_> {
+        fn load(&self) -> Result<Option<Checkpoint<Context, SystemTime>>, InternalError> {
+            self.inner.load()
+        }
+
+        fn store(
+            &mut self,
+            checkpoint: &Checkpoint<Context, SystemTime>,
+        ) -> Result<(), InternalError> {
+            self.log
+                .borrow_mut()
+                .push(format!("store {:?}", checkpoint.context.state()));
+            self.inner.store(checkpoint)
+        }
+
+        fn history(&self) -> Result<Vec<Checkpoint<Context, SystemTime>>, InternalError> {
+            self.inner.history()
+        }
+    }
+
+    /// Test that the coordinator's abort is stored before the abort message is sent, that the
+    /// final update is stored at the end of the batch, and that it is returned.
+    #[test]
+    fn test_persist_before_send() -> Result<(), Box<dyn std::error::Error>> {
+        let log = RefCell::new(Vec::new());
+        let mut store = RecordingStore {
+            log: &log,
+            inner: MemoryContextStore::new(),
+        };
+        let mut transport = |to: String, message: TwoPhaseCommitMessage<String>| {
+            log.borrow_mut().push(format!("send {message:?} to {to}"));
+            Ok(())
+        };
+        let mut handler = |_| Ok(());
+
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new());
+        let context: Context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![
+                Participant::new("p1".to_string()),
+                Participant::new("p2".to_string()),
+            ])
+            .build()?;
+
+        let actions = algorithm.event(TwoPhaseCommitEvent::Start("v".into()), context)?;
+        let context = execute_actions(actions, &mut store, &mut transport, &mut handler)?
+            .unwrap()
+            .context;
+        log.borrow_mut().clear();
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver("p1".into(), TwoPhaseCommitMessage::VoteResponse(1, true)),
+            context,
+        )?;
+        let context = execute_actions(actions, &mut store, &mut transport, &mut handler)?
+            .unwrap()
+            .context;
+        log.borrow_mut().clear();
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver(
+                "p2".into(),
+                TwoPhaseCommitMessage::VoteResponse(1, false),
+            ),
+            context,
+        )?;
+        let latest = execute_actions(actions, &mut store, &mut transport, &mut handler)?.unwrap();
+
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "store Abort".to_string(),
+                "send Abort(1) to p1".to_string(),
+                format!("store {:?}", latest.context.state()),
+            ]
+        );
+        assert!(matches!(
+            latest.context.state(),
+            TwoPhaseCommitState::WaitingForDecisionAck { .. }
+        ));
+        assert_eq!(store.load()?, Some(latest));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The record format shared by the file-backed stores and logs.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+
+use crate::error::InternalError;
+
+// Each record is a sequence number, the payload length and a checksum, followed by the payload.
+const HEADER_LEN: usize = 8 + 4 + 4;
+
+pub(crate) fn io_error(err: io::Error) -> InternalError {
+    InternalError::from_source(Box::new(err))
+}
+
+// Returns the contents of the file, or nothing if it does not exist.
+pub(crate) fn read_file(path: &Path) -> Result<Vec<u8>, InternalError> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(bytes),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(io_error(err)),
+    }
+}
+
+#[cfg(unix)]
+pub(crate) fn sync_dir(dir: &Path) -> Result<(), InternalError> {
+    File::open(dir)
+        .and_then(|dir| dir.sync_all())
+        .map_err(io_error)
+}
+
+// Directories can not be opened as files on other platforms; the rename is still atomic.
+#[cfg(not(unix))]
+pub(crate) fn sync_dir(_dir: &Path) -> Result<(), InternalError> {
+    Ok(())
+}
+
+pub(crate) fn encode_record(seq: u64, payload: &[u8]) -> Vec<u8> {
+    let len = payload.len() as u32;
+    let mut record = Vec::with_capacity(HEADER_LEN + payload.len());
+    record.extend_from_slice(&seq.to_le_bytes());
+    record.extend_from_slice(&len.to_le_bytes());
+    record.extend_from_slice(&checksum(seq, len, payload).to_le_bytes());
+    record.extend_from_slice(payload);
+    record
+}
+
+// Returns the sequence number and payload of each complete, valid record, along with the length
+// of the bytes they occupy. Parsing stops at the first incomplete or corrupt record.
+pub(crate) fn parse_records(mut bytes: &[u8]) -> (Vec<(u64, Vec<u8>)>, usize) {
+    let mut records = Vec::new();
+    let mut valid_len = 0;
+
+    while bytes.len() >= HEADER_LEN {
+        let seq = u64::from_le_bytes(bytes[0..8].try_into().unwrap_or_default());
+        let len = u32::from_le_bytes(bytes[8..12].try_into().unwrap_or_default());
+        let sum = u32::from_le_bytes(bytes[12..16].try_into().unwrap_or_default());
+
+        let end = HEADER_LEN + len as usize;
+        if bytes.len() < end || checksum(seq, len, &bytes[HEADER_LEN..end]) != sum {
+            break;
+        }
+
+        records.push((seq, bytes[HEADER_LEN..end].to_vec()));
+        valid_len += end;
+        bytes = &bytes[end..];
+    }
+
+    (records, valid_len)
+}
+
+// FNV-1a over the record's header fields and payload.
+fn checksum(seq: u64, len: u32, payload: &[u8]) -> u32 {
+    seq.to_le_bytes()
+        .iter()
+        .chain(len.to_le_bytes().iter())
+        .chain(payload)
+        .fold(0x811c_9dc5, |hash, byte| {
+            (hash ^ u32::from(*byte)).wrapping_mul(0x0100_0193)
+        })
+}
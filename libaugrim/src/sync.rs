@@ -0,0 +1,28 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains lock_recover, for locks which never guard data left invalid by a panic.
+
+use std::sync::{Mutex, MutexGuard};
+
+/// Lock the mutex, recovering it if a thread panicked while holding it.
+///
+/// Every lock in the crate guards data which is only replaced whole, or updated by code which
+/// does not panic part way, so a poisoned lock still guards valid data; recovering it keeps one
+/// panicking thread from making every other thread panic in turn.
+pub(crate) fn lock_recover<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,22 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for testing algorithms and the applications which use them.
+//!
+//! With the `algorithm-two-phase-commit` feature, [`two_phase_commit`] provides
+//! [proptest](https://docs.rs/proptest) strategies generating two-phase commit events, messages,
+//! states and valid contexts.
+
+#[cfg(feature = "algorithm-two-phase-commit")]
+pub mod two_phase_commit;
@@ -0,0 +1,421 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Proptest strategies for the two-phase commit types.
+//!
+//! The strategies are generic over the process, value and time types, and take the strategies for
+//! those elements as parameters. Contexts are generated for a given cluster: a coordinator and its
+//! participants.
+//!
+//! For convenience, [`process`], [`value`] and [`time`] draw from a small cluster: [`COORDINATOR`]
+//! coordinates [`PARTICIPANTS`], and [`OUTSIDER`] is not a member of the cluster at all. Epochs,
+//! values and times are drawn from small ranges, so that generated events frequently match the
+//! generated contexts.
+//!
+//! ```
+//! use augrim::testing::two_phase_commit::{
+//!     context, event, participant_processes, process, time, value, COORDINATOR,
+//! };
+//! use proptest::strategy::{Strategy, ValueTree};
+//! use proptest::test_runner::TestRunner;
+//!
+//! let mut runner = TestRunner::deterministic();
+//! let context = context(COORDINATOR.to_string(), participant_processes(), value(), time())
+//!     .new_tree(&mut runner)
+//!     .unwrap()
+//!     .current();
+//! let event = event(process(), value()).new_tree(&mut runner).unwrap().current();
+//! ```
+
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+use std::time::{Duration, SystemTime};
+
+use proptest::option;
+use proptest::prelude::*;
+
+use crate::algorithm::Value;
+use crate::process::Process;
+use crate::time::Time;
+use crate::two_phase_commit::{
+    Epoch, Participant, TwoPhaseCommitContext, TwoPhaseCommitContextBuilder, TwoPhaseCommitEvent,
+    TwoPhaseCommitMessage, TwoPhaseCommitState,
+};
+
+/// The coordinator of the generated cluster.
+pub const COORDINATOR: &str = "c";
+
+/// The participants of the generated cluster.
+pub const PARTICIPANTS: [&str; 2] = ["p1", "p2"];
+
+/// A process which is not a member of the generated cluster.
+pub const OUTSIDER: &str = "x";
+
+/// The range of generated epochs.
+pub const EPOCHS: RangeInclusive<Epoch> = 0..=3;
+
+/// The generated values.
+pub const VALUES: [&str; 2] = ["a", "b"];
+
+/// The range of generated times, in seconds since the Unix epoch: long enough to span every
+/// timeout.
+pub const TIME_SECS: RangeInclusive<u64> = 0..=179;
+
+/// The context type of the generated cluster.
+pub type Context = TwoPhaseCommitContext<String, String, SystemTime>;
+
+/// The event type of the generated cluster.
+pub type Event = TwoPhaseCommitEvent<String, String>;
+
+/// Returns the participants of the generated cluster.
+pub fn participant_processes() -> Vec<String> {
+    PARTICIPANTS.iter().map(|p| p.to_string()).collect()
+}
+
+/// Generates a process of the generated cluster: the coordinator, a participant or the outsider.
+pub fn process() -> impl Strategy<Value = String> + Clone {
+    prop_oneof![
+        Just(COORDINATOR),
+        Just(PARTICIPANTS[0]),
+        Just(PARTICIPANTS[1]),
+        Just(OUTSIDER),
+    ]
+    .prop_map(String::from)
+}
+
+/// Generates an epoch from a small range.
+pub fn epoch() -> impl Strategy<Value = Epoch> + Clone {
+    EPOCHS
+}
+
+/// Generates one of a small set of values.
+pub fn value() -> impl Strategy<Value = String> + Clone {
+    proptest::sample::select(&VALUES[..]).prop_map(String::from)
+}
+
+/// Generates a time within a few minutes of the Unix epoch, long enough to span every timeout.
+pub fn time() -> impl Strategy<Value = SystemTime> + Clone {
+    TIME_SECS.prop_map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+/// Generates any message, with values drawn from `value`.
+pub fn message<V>(
+    value: impl Strategy<Value = V>,
+) -> impl Strategy<Value = TwoPhaseCommitMessage<V>>
+where
+    V: Value + Debug + 'static,
+{
+    prop_oneof![
+        (epoch(), value).prop_map(|(e, v)| TwoPhaseCommitMessage::VoteRequest(e, v)),
+        (epoch(), any::<bool>()).prop_map(|(e, v)| TwoPhaseCommitMessage::VoteResponse(e, v)),
+        epoch().prop_map(TwoPhaseCommitMessage::Commit),
+        epoch().prop_map(TwoPhaseCommitMessage::Abort),
+        epoch().prop_map(TwoPhaseCommitMessage::DecisionRequest),
+        epoch().prop_map(TwoPhaseCommitMessage::DecisionAck),
+    ]
+}
+
+/// Generates any event, with senders drawn from `process` and values from `value`.
+pub fn event<P, V>(
+    process: impl Strategy<Value = P>,
+    value: impl Strategy<Value = V> + Clone,
+) -> impl Strategy<Value = TwoPhaseCommitEvent<P, V>>
+where
+    P: Process + Debug + 'static,
+    V: Value + Debug + 'static,
+{
+    prop_oneof![
+        Just(TwoPhaseCommitEvent::Alarm()),
+        (process, message(value.clone())).prop_map(|(p, m)| TwoPhaseCommitEvent::Deliver(p, m)),
+        value.prop_map(TwoPhaseCommitEvent::Start),
+        any::<bool>().prop_map(TwoPhaseCommitEvent::Vote),
+    ]
+}
+
+/// Generates any state, whether or not it is valid for a particular role, with timeouts starting
+/// at times drawn from `time`.
+pub fn state<T>(
+    time: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = TwoPhaseCommitState<T>>
+where
+    T: Time + 'static,
+{
+    prop_oneof![
+        coordinator_state(time.clone()),
+        (any::<bool>(), time).prop_map(|(vote, start)| TwoPhaseCommitState::Voted {
+            vote,
+            decision_timeout_start: start,
+        }),
+        Just(TwoPhaseCommitState::WaitingForVoteRequest),
+    ]
+}
+
+/// Returns every state which is valid for a coordinator, with `start` as the start of any
+/// timeout.
+pub fn coordinator_states<T: Copy>(start: T) -> Vec<TwoPhaseCommitState<T>> {
+    vec![
+        TwoPhaseCommitState::Abort,
+        TwoPhaseCommitState::Commit,
+        TwoPhaseCommitState::Voting {
+            vote_timeout_start: start,
+        },
+        TwoPhaseCommitState::WaitingForStart,
+        TwoPhaseCommitState::WaitingForVote,
+        TwoPhaseCommitState::WaitingForDecisionAck {
+            ack_timeout_start: start,
+        },
+    ]
+}
+
+/// Returns every state which is valid for a participant, with `vote` as the vote cast and
+/// `start` as the start of any timeout.
+pub fn participant_states<T: Copy>(vote: bool, start: T) -> Vec<TwoPhaseCommitState<T>> {
+    vec![
+        TwoPhaseCommitState::Abort,
+        TwoPhaseCommitState::Commit,
+        TwoPhaseCommitState::Voted {
+            vote,
+            decision_timeout_start: start,
+        },
+        TwoPhaseCommitState::WaitingForVoteRequest,
+        TwoPhaseCommitState::WaitingForVote,
+    ]
+}
+
+/// Generates a state which is valid for a coordinator, with timeouts starting at times drawn from
+/// `time`.
+pub fn coordinator_state<T>(
+    time: impl Strategy<Value = T>,
+) -> impl Strategy<Value = TwoPhaseCommitState<T>>
+where
+    T: Time + 'static,
+{
+    time.prop_flat_map(|start| proptest::sample::select(coordinator_states(start)))
+}
+
+/// Generates a state which is valid for a participant, with timeouts starting at times drawn from
+/// `time`.
+pub fn participant_state<T>(
+    time: impl Strategy<Value = T>,
+) -> impl Strategy<Value = TwoPhaseCommitState<T>>
+where
+    T: Time + 'static,
+{
+    (any::<bool>(), time)
+        .prop_flat_map(|(vote, start)| proptest::sample::select(participant_states(vote, start)))
+}
+
+/// Generates a participant of the coordinator's context, with any vote and acknowledgement.
+pub fn participant<P>(process: P) -> impl Strategy<Value = Participant<P>>
+where
+    P: Process + Debug + 'static,
+{
+    (option::of(any::<bool>()), any::<bool>()).prop_map(move |(vote, decision_ack)| Participant {
+        process: process.clone(),
+        vote,
+        decision_ack,
+    })
+}
+
+/// Generates a context for `coordinator`, built with [`TwoPhaseCommitContextBuilder`]. The
+/// coordinator's participants are a non-empty prefix of `participants`.
+///
+/// # Panics
+///
+/// Panics if `participants` is empty.
+pub fn coordinator_context<P, V, T>(
+    coordinator: P,
+    participants: Vec<P>,
+    value: impl Strategy<Value = V>,
+    time: impl Strategy<Value = T>,
+) -> impl Strategy<Value = TwoPhaseCommitContext<P, V, T>>
+where
+    P: Process + Debug + 'static,
+    V: Value + Debug + 'static,
+    T: Time + 'static,
+{
+    assert!(!participants.is_empty(), "a coordinator needs participants");
+    let participants = (1..=participants.len()).prop_flat_map(move |len| {
+        participants[..len]
+            .iter()
+            .cloned()
+            .map(participant)
+            .collect::<Vec<_>>()
+    });
+    (
+        epoch(),
+        option::of(epoch()),
+        coordinator_state(time),
+        participants,
+        option::of(value),
+    )
+        .prop_map(
+            move |(epoch, last_commit_epoch, state, participants, value)| {
+                let mut builder = TwoPhaseCommitContextBuilder::new()
+                    .with_coordinator(coordinator.clone())
+                    .with_epoch(epoch)
+                    .with_state(state)
+                    .with_this_process(coordinator.clone())
+                    .with_participants(participants);
+                if let Some(last_commit_epoch) = last_commit_epoch {
+                    builder = builder.with_last_commit_epoch(last_commit_epoch);
+                }
+                if let Some(value) = value {
+                    builder = builder.with_value(value);
+                }
+                builder
+                    .build()
+                    .expect("generated coordinator context is valid")
+            },
+        )
+}
+
+/// Generates a context for one of `participants`, coordinated by `coordinator`, built with
+/// [`TwoPhaseCommitContextBuilder`].
+///
+/// # Panics
+///
+/// Panics if `participants` is empty.
+pub fn participant_context<P, V, T>(
+    coordinator: P,
+    participants: Vec<P>,
+    value: impl Strategy<Value = V>,
+    time: impl Strategy<Value = T>,
+) -> impl Strategy<Value = TwoPhaseCommitContext<P, V, T>>
+where
+    P: Process + Debug + 'static,
+    V: Value + Debug + 'static,
+    T: Time + 'static,
+{
+    (
+        proptest::sample::select(participants.clone()),
+        epoch(),
+        option::of(epoch()),
+        participant_state(time),
+        option::of(value),
+    )
+        .prop_map(
+            move |(this_process, epoch, last_commit_epoch, state, value)| {
+                let mut builder = TwoPhaseCommitContextBuilder::new()
+                    .with_coordinator(coordinator.clone())
+                    .with_epoch(epoch)
+                    .with_state(state)
+                    .with_this_process(this_process)
+                    .with_participant_processes(participants.clone());
+                if let Some(last_commit_epoch) = last_commit_epoch {
+                    builder = builder.with_last_commit_epoch(last_commit_epoch);
+                }
+                if let Some(value) = value {
+                    builder = builder.with_value(value);
+                }
+                builder
+                    .build()
+                    .expect("generated participant context is valid")
+            },
+        )
+}
+
+/// Generates a context for any process of the cluster: `coordinator` or one of `participants`.
+///
+/// # Panics
+///
+/// Panics if `participants` is empty.
+pub fn context<P, V, T>(
+    coordinator: P,
+    participants: Vec<P>,
+    value: impl Strategy<Value = V> + Clone,
+    time: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = TwoPhaseCommitContext<P, V, T>>
+where
+    P: Process + Debug + 'static,
+    V: Value + Debug + 'static,
+    T: Time + 'static,
+{
+    prop_oneof![
+        coordinator_context(
+            coordinator.clone(),
+            participants.clone(),
+            value.clone(),
+            time.clone()
+        ),
+        participant_context(coordinator, participants, value, time),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+
+    use crate::algorithm::Algorithm;
+    use crate::driver::{DrivableAction, DriverAction};
+    use crate::error::AlgorithmError;
+    use crate::time::ManualTimeSource;
+    use crate::two_phase_commit::TwoPhaseCommitAlgorithm;
+
+    use super::*;
+
+    fn cluster_context() -> impl Strategy<Value = Context> {
+        context(
+            COORDINATOR.to_string(),
+            participant_processes(),
+            value(),
+            time(),
+        )
+    }
+
+    proptest! {
+        /// Test that any single event, processed with any valid context at any time, either
+        /// succeeds or is rejected as invalid for the context; it never panics or fails
+        /// internally.
+        #[test]
+        fn test_event(context in cluster_context(), event in event(process(), value()), now in time()) {
+            let algorithm = TwoPhaseCommitAlgorithm::new(ManualTimeSource::new(now));
+            let result = algorithm.event(event, context);
+            prop_assert!(
+                matches!(result, Ok(_) | Err(AlgorithmError::InvalidState(_))),
+                "unexpected result {:?}",
+                result
+            );
+        }
+
+        /// Test that sequences of events, each processed with the context produced by the
+        /// previous ones, never panic or fail internally.
+        #[test]
+        fn test_event_sequence(
+            context in cluster_context(),
+            steps in vec((event(process(), value()), 0..40u64), 0..30),
+        ) {
+            let clock = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+            let algorithm = TwoPhaseCommitAlgorithm::new(clock.clone());
+
+            let mut context = context;
+            for (event, elapsed) in steps {
+                clock.advance(Duration::from_secs(elapsed));
+                match algorithm.event(event, context.clone()) {
+                    Ok(actions) => {
+                        for action in actions {
+                            if let DriverAction::Update { context: next, .. } =
+                                action.into_driver_action()
+                            {
+                                context = next;
+                            }
+                        }
+                    }
+                    Err(AlgorithmError::InvalidState(_)) => (),
+                    Err(err) => prop_assert!(false, "unexpected error {}", err),
+                }
+            }
+        }
+    }
+}
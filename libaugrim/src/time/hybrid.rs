@@ -0,0 +1,329 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains HybridClock, a hybrid logical clock.
+
+use std::fmt;
+use std::ops::Add;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime};
+
+use crate::error::ClockDriftError;
+use crate::sync::lock_recover;
+
+use super::{SystemTimeFactory, Time, TimeSource};
+
+/// The default maximum drift allowed between clocks.
+const DEFAULT_MAX_DRIFT: Duration = Duration::from_millis(500);
+
+/// A hybrid logical clock timestamp: a physical time, and a logical counter ordering timestamps
+/// with the same physical time.
+///
+/// Timestamps are ordered by physical time, then by the logical counter. Adding a [`Duration`]
+/// advances the physical time and keeps the counter, so timestamps can be used as [`Time`] for
+/// timeouts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct HybridTimestamp {
+    /// The physical time, since the Unix epoch.
+    pub physical: Duration,
+    pub logical: u32,
+}
+
+impl HybridTimestamp {
+    pub fn new(physical: Duration, logical: u32) -> Self {
+        Self { physical, logical }
+    }
+
+    // Returns the next timestamp with this physical time, or once the counter is exhausted, the
+    // first timestamp of the next nanosecond.
+    fn successor(self) -> Self {
+        match self.logical.checked_add(1) {
+            Some(logical) => Self::new(self.physical, logical),
+            None => Self::new(self.physical + Duration::from_nanos(1), 0),
+        }
+    }
+}
+
+impl Add<Duration> for HybridTimestamp {
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self {
+        Self::new(self.physical + duration, self.logical)
+    }
+}
+
+impl Time for HybridTimestamp {
+    fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+        self.physical.checked_sub(earlier.physical)
+    }
+}
+
+impl fmt::Display for HybridTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{:09}+{}",
+            self.physical.as_secs(),
+            self.physical.subsec_nanos(),
+            self.logical
+        )
+    }
+}
+
+/// A hybrid logical clock (HLC), after Kulkarni et al., _Logical Physical Clocks_.
+///
+/// The clock follows the physical clock `S`, but never goes backwards: if the physical clock is
+/// adjusted back, as by NTP, the clock holds its physical time and advances its logical counter
+/// until the physical clock catches up, while its timestamps stay close to wall time.
+///
+/// Timeouts measured with it are not protected from adjustments: after an adjustment back, a
+/// timeout waits for the physical clock to catch up with the held time, and an adjustment
+/// forward, or a received stamp from a clock which is ahead, brings timeouts closer; by up to the
+/// maximum drift, for a stamp.
+///
+/// Processes stamp their messages with [`TimeSource::now`] and pass the stamps of the messages
+/// they receive to [`HybridClock::update`], so that the timestamps also respect causality. A
+/// stamp further ahead of the local physical clock than the maximum drift is rejected.
+///
+/// Clones share the same clock, so one clone may be given to an algorithm while another is
+/// updated with received stamps.
+#[derive(Clone, Debug)]
+pub struct HybridClock<S = SystemTimeFactory> {
+    physical: S,
+    last: Arc<Mutex<HybridTimestamp>>,
+    max_drift: Duration,
+}
+
+impl Default for HybridClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HybridClock {
+    /// Create a clock following the system clock.
+    pub fn new() -> Self {
+        Self::with_physical(SystemTimeFactory::new())
+    }
+}
+
+impl<S: TimeSource<Time = SystemTime>> HybridClock<S> {
+    /// Create a clock following the given physical clock.
+    pub fn with_physical(physical: S) -> Self {
+        Self {
+            physical,
+            last: Arc::new(Mutex::new(HybridTimestamp::default())),
+            max_drift: DEFAULT_MAX_DRIFT,
+        }
+    }
+
+    /// Set the maximum drift allowed between clocks; the default is 500 milliseconds.
+    pub fn with_max_drift(mut self, max_drift: Duration) -> Self {
+        self.max_drift = max_drift;
+        self
+    }
+
+    /// Merge the stamp of a received message, returning the timestamp of its receipt, which is
+    /// greater than both the stamp and every timestamp previously returned.
+    ///
+    /// Returns an error, leaving the clock unchanged, if the stamp is further ahead of the
+    /// physical clock than the maximum drift.
+    pub fn update(&self, stamp: &HybridTimestamp) -> Result<HybridTimestamp, ClockDriftError> {
+        let physical = self.physical_now();
+        if let Some(ahead) = stamp.physical.checked_sub(physical) {
+            if ahead > self.max_drift {
+                return Err(ClockDriftError {
+                    ahead,
+                    max_drift: self.max_drift,
+                });
+            }
+        }
+
+        let mut last = self.lock();
+        let next = physical.max(last.physical).max(stamp.physical);
+        *last = if next == last.physical && next == stamp.physical {
+            HybridTimestamp::new(next, last.logical.max(stamp.logical)).successor()
+        } else if next == last.physical {
+            last.successor()
+        } else if next == stamp.physical {
+            stamp.successor()
+        } else {
+            HybridTimestamp::new(next, 0)
+        };
+        Ok(*last)
+    }
+
+    fn physical_now(&self) -> Duration {
+        self.physical
+            .now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HybridTimestamp> {
+        lock_recover(&self.last)
+    }
+}
+
+impl<S: TimeSource<Time = SystemTime>> TimeSource for HybridClock<S> {
+    type Time = HybridTimestamp;
+
+    /// Returns a timestamp greater than every timestamp previously returned.
+    fn now(&self) -> Self::Time {
+        let physical = self.physical_now();
+        let mut last = self.lock();
+        *last = if physical > last.physical {
+            HybridTimestamp::new(physical, 0)
+        } else {
+            last.successor()
+        };
+        *last
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::ManualTimeSource;
+
+    use super::*;
+
+    fn at(secs: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    fn stamp(secs: u64, logical: u32) -> HybridTimestamp {
+        HybridTimestamp::new(Duration::from_secs(secs), logical)
+    }
+
+    /// Test that the clock follows the physical clock, but never goes backwards when the
+    /// physical clock is adjusted back.
+    #[test]
+    fn test_now() {
+        let physical = ManualTimeSource::new(at(100));
+        let clock = HybridClock::with_physical(physical.clone());
+
+        assert_eq!(clock.now(), stamp(100, 0));
+        assert_eq!(clock.now(), stamp(100, 1));
+
+        physical.set(at(90));
+        assert_eq!(clock.now(), stamp(100, 2));
+
+        physical.set(at(101));
+        assert_eq!(clock.now(), stamp(101, 0));
+    }
+
+    /// Test that an exhausted logical counter carries into the physical time, and that adding a
+    /// duration keeps the counter.
+    #[test]
+    fn test_logical_overflow() {
+        let clock = HybridClock::with_physical(ManualTimeSource::new(at(100)));
+        let carried = HybridTimestamp::new(Duration::new(100, 1), 0);
+
+        assert_eq!(clock.update(&stamp(100, u32::MAX)), Ok(carried));
+        assert_eq!(clock.now(), HybridTimestamp::new(Duration::new(100, 1), 1));
+        assert!(clock.now() > stamp(100, u32::MAX));
+
+        assert_eq!(stamp(100, 3) + Duration::ZERO, stamp(100, 3));
+        assert_eq!(stamp(100, 3) + Duration::from_secs(1), stamp(101, 3));
+    }
+
+    /// Test that received stamps are merged, and that stamps too far ahead are rejected.
+    #[test]
+    fn test_update() {
+        let physical = ManualTimeSource::new(at(100));
+        let clock =
+            HybridClock::with_physical(physical.clone()).with_max_drift(Duration::from_secs(5));
+
+        assert_eq!(clock.now(), stamp(100, 0));
+        assert_eq!(clock.update(&stamp(100, 4)), Ok(stamp(100, 5)));
+        assert_eq!(clock.update(&stamp(103, 2)), Ok(stamp(103, 3)));
+        assert_eq!(clock.update(&stamp(50, 9)), Ok(stamp(103, 4)));
+        assert_eq!(clock.now(), stamp(103, 5));
+
+        assert_eq!(
+            clock.update(&stamp(106, 0)),
+            Err(ClockDriftError {
+                ahead: Duration::from_secs(6),
+                max_drift: Duration::from_secs(5),
+            })
+        );
+        assert_eq!(clock.now(), stamp(103, 6));
+
+        physical.set(at(104));
+        assert_eq!(clock.now(), stamp(104, 0));
+    }
+
+    /// Test that a two-phase commit coordinator using the clock holds its vote timeout while the
+    /// physical clock is adjusted back, and times out early once it merges a stamp from a clock
+    /// which is ahead.
+    #[cfg(feature = "algorithm-two-phase-commit")]
+    #[test]
+    fn test_two_phase_commit_timeout() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::algorithm::Algorithm;
+        use crate::two_phase_commit::{
+            Participant, TwoPhaseCommitAction, TwoPhaseCommitAlgorithm,
+            TwoPhaseCommitContextBuilder, TwoPhaseCommitEvent, TwoPhaseCommitState,
+        };
+
+        let physical = ManualTimeSource::new(at(1000));
+        let clock =
+            HybridClock::with_physical(physical.clone()).with_max_drift(Duration::from_secs(5));
+        let algorithm = TwoPhaseCommitAlgorithm::new(clock.clone());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![Participant::new("p1".to_string())])
+            .build()?;
+
+        let update = |actions: Vec<TwoPhaseCommitAction<String, String, HybridTimestamp>>| {
+            actions.into_iter().rev().find_map(|action| match action {
+                TwoPhaseCommitAction::Update { context, .. } => Some(context),
+                _ => None,
+            })
+        };
+
+        let context = update(algorithm.event(TwoPhaseCommitEvent::Start("v".into()), context)?)
+            .expect("no update action");
+
+        // The clock holds its time while the physical clock is behind, so the timeout, 30
+        // seconds from the start of voting, has not passed.
+        physical.set(at(900));
+        assert_eq!(
+            update(algorithm.event(TwoPhaseCommitEvent::Alarm(), context.clone())?),
+            None
+        );
+        assert_eq!(clock.now(), stamp(1000, 2));
+
+        // A stamp from a clock 5 seconds ahead moves the clock past the timeout, 4 seconds before
+        // the physical clock reaches it.
+        physical.set(at(1026));
+        assert_eq!(
+            update(algorithm.event(TwoPhaseCommitEvent::Alarm(), context.clone())?),
+            None
+        );
+        assert_eq!(clock.update(&stamp(1031, 0)), Ok(stamp(1031, 1)));
+        let context = update(algorithm.event(TwoPhaseCommitEvent::Alarm(), context)?)
+            .expect("no update action");
+        assert!(matches!(
+            context.state(),
+            TwoPhaseCommitState::WaitingForDecisionAck { .. }
+        ));
+
+        Ok(())
+    }
+}
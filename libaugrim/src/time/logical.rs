@@ -0,0 +1,304 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Logical clocks, which order events by causality rather than by wall-clock time.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug};
+
+/// A clock which orders the events of processes which communicate by messages.
+///
+/// Where [`Time`](super::Time) measures the passage of time for timeouts, a logical clock
+/// captures the happened-before relation: if one event may have caused another, the cause's
+/// timestamp is less than the effect's. Each process keeps its own clock, ticks it for local
+/// events, stamps outgoing messages with [`LogicalClock::send`], and merges the stamps of
+/// incoming messages with [`LogicalClock::receive`]. See [`Stamped`](crate::Stamped) for a
+/// message carrying a stamp.
+pub trait LogicalClock {
+    /// The timestamps of the clock, ordered consistently with the happened-before relation.
+    type Timestamp: Clone + Debug + PartialOrd;
+
+    /// Returns the timestamp of the last event, without advancing the clock.
+    fn timestamp(&self) -> Self::Timestamp;
+
+    /// Advance the clock for a local event, returning the event's timestamp.
+    fn tick(&mut self) -> Self::Timestamp;
+
+    /// Advance the clock for sending a message, returning the stamp to send with it.
+    fn send(&mut self) -> Self::Timestamp {
+        self.tick()
+    }
+
+    /// Merge the stamp of a received message and advance the clock for its receipt, returning
+    /// the timestamp of the receipt, which is greater than the stamp.
+    fn receive(&mut self, stamp: &Self::Timestamp) -> Self::Timestamp;
+}
+
+/// A Lamport timestamp.
+///
+/// Timestamps are totally ordered, but only partially capture causality: if one event happened
+/// before another, its timestamp is less, but a lesser timestamp does not imply that its event
+/// happened before. Pair the timestamp with the process to break ties for a total order of
+/// events.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LamportTimestamp(pub u64);
+
+impl fmt::Display for LamportTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A Lamport clock: a single counter, advanced past the stamp of every message received.
+#[derive(Clone, Debug, Default)]
+pub struct LamportClock {
+    time: LamportTimestamp,
+}
+
+impl LamportClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl LogicalClock for LamportClock {
+    type Timestamp = LamportTimestamp;
+
+    fn timestamp(&self) -> Self::Timestamp {
+        self.time
+    }
+
+    fn tick(&mut self) -> Self::Timestamp {
+        self.time.0 += 1;
+        self.time
+    }
+
+    fn receive(&mut self, stamp: &Self::Timestamp) -> Self::Timestamp {
+        self.time = self.time.max(*stamp);
+        self.tick()
+    }
+}
+
+/// A vector timestamp: the number of events of each process which happened before or at it.
+///
+/// Timestamps are partially ordered, exactly capturing causality: one event happened before
+/// another if and only if its timestamp is less, and two events are concurrent if their
+/// timestamps are incomparable.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VectorTimestamp<P: Ord> {
+    // Processes without events are omitted, so that equal timestamps are equal maps.
+    counts: BTreeMap<P, u64>,
+}
+
+impl<P: Ord> Default for VectorTimestamp<P> {
+    fn default() -> Self {
+        Self {
+            counts: BTreeMap::new(),
+        }
+    }
+}
+
+impl<P: Ord + Clone> VectorTimestamp<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of events of the process counted by the timestamp.
+    pub fn get(&self, process: &P) -> u64 {
+        self.counts.get(process).copied().unwrap_or(0)
+    }
+
+    /// Returns the processes with events counted by the timestamp, and their counts.
+    pub fn iter(&self) -> impl Iterator<Item = (&P, u64)> {
+        self.counts.iter().map(|(process, count)| (process, *count))
+    }
+
+    /// Returns whether the events of the two timestamps are concurrent: neither happened before
+    /// the other.
+    pub fn is_concurrent(&self, other: &Self) -> bool {
+        self.partial_cmp(other).is_none()
+    }
+
+    /// Merge the other timestamp into this one, taking the greater count of each process.
+    pub fn merge(&mut self, other: &Self) {
+        for (process, count) in &other.counts {
+            let entry = self.counts.entry(process.clone()).or_insert(0);
+            *entry = (*entry).max(*count);
+        }
+    }
+
+    pub(crate) fn increment(&mut self, process: &P) {
+        *self.counts.entry(process.clone()).or_insert(0) += 1;
+    }
+}
+
+impl<P: Ord + Clone> FromIterator<(P, u64)> for VectorTimestamp<P> {
+    fn from_iter<I: IntoIterator<Item = (P, u64)>>(iter: I) -> Self {
+        Self {
+            counts: iter.into_iter().filter(|(_, count)| *count > 0).collect(),
+        }
+    }
+}
+
+// Zero counts are dropped as the timestamp is deserialized, as they are when it is collected.
+#[cfg(feature = "serde")]
+impl<'de, P> serde::Deserialize<'de> for VectorTimestamp<P>
+where
+    P: Ord + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "VectorTimestamp")]
+        struct Counts<P: Ord> {
+            counts: BTreeMap<P, u64>,
+        }
+
+        let Counts { mut counts } = Counts::deserialize(deserializer)?;
+        counts.retain(|_, count| *count > 0);
+        Ok(Self { counts })
+    }
+}
+
+impl<P: Ord + Clone> PartialOrd for VectorTimestamp<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let mut ordering = Ordering::Equal;
+        for process in self.counts.keys().chain(other.counts.keys()) {
+            match (ordering, self.get(process).cmp(&other.get(process))) {
+                (_, Ordering::Equal) => (),
+                (Ordering::Equal, next) => ordering = next,
+                (current, next) if current != next => return None,
+                _ => (),
+            }
+        }
+        Some(ordering)
+    }
+}
+
+/// A vector clock, kept by one process.
+#[derive(Clone, Debug)]
+pub struct VectorClock<P: Ord> {
+    process: P,
+    timestamp: VectorTimestamp<P>,
+}
+
+impl<P: Ord + Clone> VectorClock<P> {
+    /// Create the clock of the given process.
+    pub fn new(process: P) -> Self {
+        Self {
+            process,
+            timestamp: VectorTimestamp::new(),
+        }
+    }
+
+    pub fn process(&self) -> &P {
+        &self.process
+    }
+}
+
+impl<P: Ord + Clone + Debug> LogicalClock for VectorClock<P> {
+    type Timestamp = VectorTimestamp<P>;
+
+    fn timestamp(&self) -> Self::Timestamp {
+        self.timestamp.clone()
+    }
+
+    fn tick(&mut self) -> Self::Timestamp {
+        self.timestamp.increment(&self.process);
+        self.timestamp.clone()
+    }
+
+    fn receive(&mut self, stamp: &Self::Timestamp) -> Self::Timestamp {
+        self.timestamp.merge(stamp);
+        self.tick()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that Lamport timestamps increase along every chain of events and messages.
+    #[test]
+    fn test_lamport_clock() {
+        let mut a = LamportClock::new();
+        let mut b = LamportClock::new();
+
+        assert_eq!(a.tick(), LamportTimestamp(1));
+        let stamp = a.send();
+        assert_eq!(stamp, LamportTimestamp(2));
+
+        // b's clock jumps past the stamp of the message it receives.
+        assert_eq!(b.tick(), LamportTimestamp(1));
+        assert_eq!(b.receive(&stamp), LamportTimestamp(3));
+        // A stale stamp does not move the clock back.
+        assert_eq!(b.receive(&LamportTimestamp(1)), LamportTimestamp(4));
+        assert_eq!(b.timestamp(), LamportTimestamp(4));
+    }
+
+    /// Test that vector timestamps order causally related events and leave concurrent events
+    /// unordered.
+    #[test]
+    fn test_vector_clock() {
+        let mut a = VectorClock::new("a");
+        let mut b = VectorClock::new("b");
+        let mut c = VectorClock::new("c");
+
+        let a1 = a.send();
+        let b1 = b.tick();
+        assert!(a1.is_concurrent(&b1));
+        assert_eq!(a1.partial_cmp(&b1), None);
+
+        let b2 = b.receive(&a1);
+        assert!(a1 < b2);
+        assert!(b1 < b2);
+        assert_eq!(b2, [("a", 1), ("b", 2)].into_iter().collect());
+
+        let c1 = c.tick();
+        assert!(c1.is_concurrent(&b2));
+
+        let c2 = c.receive(&b2);
+        assert!(a1 < c2 && b2 < c2 && c1 < c2);
+        assert_eq!(c2.get(&"a"), 1);
+        assert_eq!(c2.get(&"d"), 0);
+        assert_eq!(c2.partial_cmp(&c.timestamp()), Some(Ordering::Equal));
+
+        // Zero counts are not significant.
+        assert_eq!(
+            VectorTimestamp::from_iter([("a", 1), ("b", 0)]),
+            [("a", 1)].into_iter().collect()
+        );
+    }
+
+    /// Test that zero counts are dropped when a vector timestamp is deserialized, so that it
+    /// equals the timestamp without them.
+    #[cfg(feature = "store-json")]
+    #[test]
+    fn test_vector_timestamp_deserialize() -> Result<(), serde_json::Error> {
+        let timestamp: VectorTimestamp<String> =
+            serde_json::from_str(r#"{ "counts": { "a": 1, "b": 0 } }"#)?;
+        assert_eq!(timestamp, [("a".to_string(), 1)].into_iter().collect());
+        assert_eq!(
+            serde_json::from_str::<VectorTimestamp<String>>(&serde_json::to_string(&timestamp)?)?,
+            timestamp
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2021-2022 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Time-related traits and default implementations.
+//!
+//! Augrim distinguishes two kinds of clock. A [`Time`], read from a [`TimeSource`], measures the
+//! passage of time so that algorithms can set alarms and detect timeouts. A [`LogicalClock`]
+//! instead orders events by causality, so that algorithms can tell whether one event may have
+//! caused another; its timestamps can not be added to a [`Duration`] and are not used for
+//! timeouts. A [`HybridClock`] combines the two: its timestamps track wall time and can be used
+//! for timeouts, but never go backwards, and respect causality when updated with the timestamps
+//! of received messages.
+
+mod hybrid;
+mod logical;
+
+use std::cmp::PartialOrd;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::sync::lock_recover;
+
+pub use hybrid::{HybridClock, HybridTimestamp};
+pub use logical::{LamportClock, LamportTimestamp, LogicalClock, VectorClock, VectorTimestamp};
+
+/// A specific instant in time.
+///
+/// Algorithms which use time, common for implementing timeouts, define time using this trait. The
+/// user of the algorithm can implement this Time trait on the specific type desired for their
+/// application. To order events by causality instead, see [`LogicalClock`].
+pub trait Time: Add<Duration, Output = Self> + PartialOrd + Copy + Debug {
+    /// Returns the amount of time elapsed from `earlier` to this time.
+    ///
+    /// This is used for reporting (for example, the latency metrics of an algorithm) and should
+    /// not be relied upon for correctness. Returns `None` if `earlier` is later than this time or
+    /// if the implementation can not measure the difference; the default implementation always
+    /// returns `None`.
+    fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+        let _ = earlier;
+        None
+    }
+}
+
+/// A factory for getting the current time.
+///
+/// This factory is used by algorithms which need to get the current time (for example, to
+/// determine if a timeout has occurred). Any implementations of [`Time`] will likely need to also
+/// implement this trait as well.
+pub trait TimeSource {
+    type Time: Time;
+
+    /// Return the current time.
+    fn now(&self) -> Self::Time;
+}
+
+/// [`SystemTime`] can be used directly as Time. See also [`SystemTimeFactory`].
+impl Time for SystemTime {
+    fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+        SystemTime::duration_since(self, *earlier).ok()
+    }
+}
+
+/// An implementation of [`TimeSource`] which works with [`SystemTime`].
+#[derive(Default, Clone)]
+pub struct SystemTimeFactory {}
+
+impl SystemTimeFactory {
+    pub fn new() -> Self {
+        SystemTimeFactory {}
+    }
+}
+
+impl TimeSource for SystemTimeFactory {
+    type Time = SystemTime;
+
+    fn now(&self) -> Self::Time {
+        SystemTime::now()
+    }
+}
+
+/// A [`TimeSource`] whose time only changes when it is set or advanced.
+///
+/// Clones share the same time, so one clone may be given to an algorithm while another is used to
+/// control it. This is used to run an algorithm deterministically: for example, to replay
+/// recorded events at the times they originally occurred, or in tests.
+#[derive(Clone, Debug)]
+pub struct ManualTimeSource<T> {
+    now: Arc<Mutex<T>>,
+}
+
+impl<T: Time> ManualTimeSource<T> {
+    pub fn new(now: T) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Set the current time.
+    pub fn set(&self, now: T) {
+        *self.lock() = now;
+    }
+
+    /// Advance the current time by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.lock();
+        *now = *now + duration;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, T> {
+        lock_recover(&self.now)
+    }
+}
+
+impl<T: Time> TimeSource for ManualTimeSource<T> {
+    type Time = T;
+
+    fn now(&self) -> Self::Time {
+        *self.lock()
+    }
+}
@@ -0,0 +1,377 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains SequenceDiagram, which renders message sequence charts.
+
+use std::fmt::Write;
+
+/// An entry in a [`SequenceDiagram`], in the order in which it occurred.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagramItem {
+    /// A message sent from one lifeline to another.
+    Message {
+        from: usize,
+        to: usize,
+        label: String,
+    },
+    /// An annotation on a lifeline, such as a state transition or an alarm.
+    Note { process: usize, text: String },
+}
+
+/// A message sequence chart, with a lifeline for each process.
+///
+/// A diagram may be built directly, or, with the `algorithm-two-phase-commit` feature, from a
+/// recorded [`Trace`](super::Trace) of a two-phase commit run. It can be rendered as Mermaid,
+/// PlantUML or Graphviz DOT source.
+///
+/// ```
+/// use augrim::trace::SequenceDiagram;
+///
+/// let mut diagram = SequenceDiagram::new();
+/// diagram.message("coordinator", "participant", "VoteRequest(1)");
+/// diagram.note("participant", "Voted (yes)");
+///
+/// assert!(diagram.to_mermaid().starts_with("sequenceDiagram"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SequenceDiagram {
+    processes: Vec<String>,
+    items: Vec<DiagramItem>,
+}
+
+impl SequenceDiagram {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the lifelines, in order of first appearance.
+    pub fn processes(&self) -> &[String] {
+        &self.processes
+    }
+
+    pub fn items(&self) -> &[DiagramItem] {
+        &self.items
+    }
+
+    /// Add a lifeline for the process, if it does not already have one, returning its index.
+    pub fn process(&mut self, name: &str) -> usize {
+        match self.processes.iter().position(|process| process == name) {
+            Some(index) => index,
+            None => {
+                self.processes.push(name.to_string());
+                self.processes.len() - 1
+            }
+        }
+    }
+
+    /// Add a message from one process to another.
+    pub fn message(&mut self, from: &str, to: &str, label: &str) {
+        let from = self.process(from);
+        let to = self.process(to);
+        self.items.push(DiagramItem::Message {
+            from,
+            to,
+            label: label.to_string(),
+        });
+    }
+
+    /// Add an annotation to the process's lifeline.
+    pub fn note(&mut self, process: &str, text: &str) {
+        let process = self.process(process);
+        self.items.push(DiagramItem::Note {
+            process,
+            text: text.to_string(),
+        });
+    }
+
+    /// Render the diagram as a Mermaid `sequenceDiagram`.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("sequenceDiagram\n");
+        for (index, process) in self.processes.iter().enumerate() {
+            let _ = writeln!(out, "    participant P{index} as {}", mermaid_text(process));
+        }
+        for item in &self.items {
+            let _ = match item {
+                DiagramItem::Message { from, to, label } => {
+                    writeln!(out, "    P{from}->>P{to}: {}", mermaid_text(label))
+                }
+                DiagramItem::Note { process, text } => {
+                    writeln!(out, "    Note over P{process}: {}", mermaid_text(text))
+                }
+            };
+        }
+        out
+    }
+
+    /// Render the diagram as a PlantUML sequence diagram.
+    pub fn to_plantuml(&self) -> String {
+        let mut out = String::from("@startuml\n");
+        for (index, process) in self.processes.iter().enumerate() {
+            let _ = writeln!(out, "participant \"{}\" as P{index}", quoted_text(process));
+        }
+        for item in &self.items {
+            let _ = match item {
+                DiagramItem::Message { from, to, label } => {
+                    writeln!(out, "P{from} -> P{to} : {}", plantuml_text(label))
+                }
+                DiagramItem::Note { process, text } => {
+                    writeln!(out, "hnote over P{process} : {}", plantuml_text(text))
+                }
+            };
+        }
+        out.push_str("@enduml\n");
+        out
+    }
+
+    /// Render the diagram as a Graphviz `digraph`.
+    ///
+    /// DOT has no notion of a sequence chart, so each lifeline is drawn as a column of points,
+    /// one for each row of the chart, with the points of a row constrained to the same rank.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph sequence {\n");
+        out.push_str("    rankdir=TB;\n    splines=false;\n    node [shape=point, width=0];\n");
+
+        for (index, process) in self.processes.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "    p{index}_0 [shape=box, width=1, label=\"{}\"];",
+                quoted_text(process)
+            );
+        }
+
+        let rows = self.items.len();
+        for index in 0..self.processes.len() {
+            for row in 0..rows {
+                let _ = writeln!(
+                    out,
+                    "    p{index}_{row} -> p{index}_{} [style=dashed, arrowhead=none];",
+                    row + 1
+                );
+            }
+        }
+
+        for (row, item) in self.items.iter().enumerate() {
+            let row = row + 1;
+            let _ = write!(out, "    {{ rank=same;");
+            for index in 0..self.processes.len() {
+                let _ = write!(out, " p{index}_{row};");
+            }
+            let _ = match item {
+                DiagramItem::Message { from, to, label } => writeln!(
+                    out,
+                    " }}\n    p{from}_{row} -> p{to}_{row} [label=\"{}\", constraint=false];",
+                    quoted_text(label)
+                ),
+                DiagramItem::Note { process, text } => writeln!(
+                    out,
+                    " n{row}; }}\n    n{row} [shape=note, width=1, label=\"{}\"];\n    \
+                     p{process}_{row} -> n{row} [style=dotted, arrowhead=none];",
+                    quoted_text(text)
+                ),
+            };
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
+// Mermaid ends a statement at a newline or semicolon, and starts an entity code at `#`.
+fn mermaid_text(text: &str) -> String {
+    text.replace('#', "#35;")
+        .replace(';', "#59;")
+        .replace('\n', "<br/>")
+}
+
+// PlantUML ends a statement at a newline; `\n` within a label is rendered as a line break.
+fn plantuml_text(text: &str) -> String {
+    text.replace('\n', "\\n")
+}
+
+// Text within double quotes, as used by PlantUML participant names and DOT labels.
+fn quoted_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(feature = "algorithm-two-phase-commit")]
+mod two_phase_commit {
+    use std::fmt::Display;
+
+    use crate::algorithm::Value;
+    use crate::process::Process;
+    use crate::time::Time;
+    use crate::trace::Trace;
+    use crate::two_phase_commit::{
+        TwoPhaseCommitAction, TwoPhaseCommitContext, TwoPhaseCommitEvent, TwoPhaseCommitMessage,
+        TwoPhaseCommitState,
+    };
+
+    use super::SequenceDiagram;
+
+    type TwoPhaseCommitTrace<P, V, T> = Trace<
+        P,
+        TwoPhaseCommitEvent<P, V>,
+        TwoPhaseCommitContext<P, V, T>,
+        TwoPhaseCommitAction<P, V, T>,
+        T,
+    >;
+
+    /// Builds a diagram of a two-phase commit run: each process is a lifeline, each message sent
+    /// is an arrow, and each alarm and state transition is a note on the process's lifeline.
+    impl<P, V, T> From<&TwoPhaseCommitTrace<P, V, T>> for SequenceDiagram
+    where
+        P: Process + Display,
+        V: Value,
+        T: Time,
+    {
+        fn from(trace: &TwoPhaseCommitTrace<P, V, T>) -> Self {
+            let mut diagram = SequenceDiagram::new();
+
+            for step in trace.steps() {
+                let process = step.process.to_string();
+                diagram.process(&process);
+
+                if let TwoPhaseCommitEvent::Alarm() = step.event {
+                    diagram.note(&process, "alarm");
+                }
+
+                let mut state = step.context.state();
+                for action in &step.actions {
+                    match action {
+                        TwoPhaseCommitAction::Update { context, .. } => {
+                            let next = context.state();
+                            if next != state {
+                                diagram.note(&process, &state_label(&next));
+                                state = next;
+                            }
+                        }
+                        TwoPhaseCommitAction::SendMessage(to, message) => {
+                            diagram.message(&process, &to.to_string(), &message_label(message))
+                        }
+                        TwoPhaseCommitAction::Notify(_) => (),
+                    }
+                }
+
+                if let Some(error) = &step.error {
+                    diagram.note(&process, &format!("error: {error}"));
+                }
+            }
+
+            diagram
+        }
+    }
+
+    // Values may be large, so they are left out of message labels.
+    fn message_label<V: Value>(message: &TwoPhaseCommitMessage<V>) -> String {
+        match message {
+            TwoPhaseCommitMessage::VoteRequest(epoch, _) => format!("VoteRequest({epoch})"),
+            TwoPhaseCommitMessage::VoteResponse(epoch, vote) => {
+                format!(
+                    "VoteResponse({epoch}, {})",
+                    if *vote { "yes" } else { "no" }
+                )
+            }
+            TwoPhaseCommitMessage::Commit(epoch) => format!("Commit({epoch})"),
+            TwoPhaseCommitMessage::Abort(epoch) => format!("Abort({epoch})"),
+            TwoPhaseCommitMessage::DecisionRequest(epoch) => format!("DecisionRequest({epoch})"),
+            TwoPhaseCommitMessage::DecisionAck(epoch) => format!("DecisionAck({epoch})"),
+        }
+    }
+
+    fn state_label<T>(state: &TwoPhaseCommitState<T>) -> String {
+        match state {
+            TwoPhaseCommitState::Abort => "Abort".into(),
+            TwoPhaseCommitState::Commit => "Commit".into(),
+            TwoPhaseCommitState::Voted { vote, .. } => {
+                format!("Voted ({})", if *vote { "yes" } else { "no" })
+            }
+            TwoPhaseCommitState::Voting { .. } => "Voting".into(),
+            TwoPhaseCommitState::WaitingForStart => "WaitingForStart".into(),
+            TwoPhaseCommitState::WaitingForVoteRequest => "WaitingForVoteRequest".into(),
+            TwoPhaseCommitState::WaitingForVote => "WaitingForVote".into(),
+            TwoPhaseCommitState::WaitingForDecisionAck { .. } => "WaitingForDecisionAck".into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagram() -> SequenceDiagram {
+        let mut diagram = SequenceDiagram::new();
+        diagram.message("c", "p", "VoteRequest(1)");
+        diagram.note("p", "a \"quoted\"; note");
+        diagram
+    }
+
+    /// Test rendering a diagram in each format.
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            diagram().to_mermaid(),
+            "sequenceDiagram\n    participant P0 as c\n    participant P1 as p\n    \
+             P0->>P1: VoteRequest(1)\n    Note over P1: a \"quoted\"#59; note\n"
+        );
+
+        assert_eq!(
+            diagram().to_plantuml(),
+            "@startuml\nparticipant \"c\" as P0\nparticipant \"p\" as P1\n\
+             P0 -> P1 : VoteRequest(1)\nhnote over P1 : a \"quoted\"; note\n@enduml\n"
+        );
+
+        let dot = diagram().to_dot();
+        assert!(dot.starts_with("digraph sequence {\n"));
+        assert!(dot.contains("p0_1 -> p1_1 [label=\"VoteRequest(1)\", constraint=false];"));
+        assert!(dot.contains("n2 [shape=note, width=1, label=\"a \\\"quoted\\\"; note\"];"));
+        assert!(dot.contains("{ rank=same; p0_2; p1_2; n2; }"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    /// Test that a recorded two-phase commit run is drawn with its messages and transitions.
+    #[cfg(all(feature = "algorithm-two-phase-commit", feature = "sim"))]
+    #[test]
+    fn test_two_phase_commit_trace() -> Result<(), Box<dyn std::error::Error>> {
+        let trace = crate::trace::tests::record_commit()?;
+        let diagram = SequenceDiagram::from(&trace);
+
+        assert_eq!(diagram.processes(), ["c", "p"]);
+        let items = diagram.items();
+        assert_eq!(
+            items[0],
+            DiagramItem::Note {
+                process: 0,
+                text: "alarm".into()
+            }
+        );
+        assert!(items.contains(&DiagramItem::Message {
+            from: 0,
+            to: 1,
+            label: "VoteRequest(1)".into()
+        }));
+        assert!(items.contains(&DiagramItem::Message {
+            from: 1,
+            to: 0,
+            label: "VoteResponse(1, yes)".into()
+        }));
+        assert!(items.contains(&DiagramItem::Note {
+            process: 1,
+            text: "Voted (yes)".into()
+        }));
+
+        Ok(())
+    }
+}
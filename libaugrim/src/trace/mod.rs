@@ -0,0 +1,370 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recording and replay of traces of a distributed run.
+//!
+//! A [`Trace`] is the sequence of steps taken by every process in a run: the event each process
+//! received, the context and time at which it was processed, and the actions (or error) which
+//! resulted. A [`TraceRecorder`] collects a trace from algorithms running in one or more
+//! processes. A [`TraceReplayer`] re-runs a trace, from its start or from any later step,
+//! through an algorithm, reporting the first step at which the algorithm's behavior
+//! [diverges](Divergence) from the recording; this allows a failure seen in a multi-process run
+//! to be turned into a deterministic regression test. A [`SequenceDiagram`] draws a trace as a
+//! message sequence chart.
+
+mod diagram;
+mod replay;
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::algorithm::Algorithm;
+use crate::error::AlgorithmError;
+use crate::sync::lock_recover;
+use crate::time::{ManualTimeSource, TimeSource};
+
+pub use diagram::{DiagramItem, SequenceDiagram};
+pub use replay::{Divergence, TraceReplayer, TraceReport};
+
+/// A single event processed by a single process.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TraceStep<P, E, C, A, T> {
+    /// The process which processed the event.
+    pub process: P,
+    /// The time at which the event was processed.
+    pub time: T,
+    /// The event.
+    pub event: E,
+    /// The context the event was processed with.
+    pub context: C,
+    /// The actions returned by the algorithm; empty if it returned an error.
+    pub actions: Vec<A>,
+    /// The error returned by the algorithm, if any.
+    pub error: Option<String>,
+}
+
+/// The steps of a run, in the order in which they occurred.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Trace<P, E, C, A, T> {
+    steps: Vec<TraceStep<P, E, C, A, T>>,
+}
+
+impl<P, E, C, A, T> Default for Trace<P, E, C, A, T> {
+    fn default() -> Self {
+        Self { steps: Vec::new() }
+    }
+}
+
+impl<P, E, C, A, T> Trace<P, E, C, A, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a step to the trace.
+    pub fn push(&mut self, step: TraceStep<P, E, C, A, T>) {
+        self.steps.push(step);
+    }
+
+    pub fn steps(&self) -> &[TraceStep<P, E, C, A, T>] {
+        &self.steps
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl<P, E, C, A, T> From<Vec<TraceStep<P, E, C, A, T>>> for Trace<P, E, C, A, T> {
+    fn from(steps: Vec<TraceStep<P, E, C, A, T>>) -> Self {
+        Self { steps }
+    }
+}
+
+// A trace shared between the recorder and the algorithms it has wrapped.
+type SharedTrace<P, E, C, A, T> = Arc<Mutex<Trace<P, E, C, A, T>>>;
+
+/// Collects a [`Trace`] from the algorithms of one or more processes.
+///
+/// Clones share the same trace, so a clone may be handed to each process (or thread) of a run.
+/// Each process's algorithm is wrapped with [`TraceRecorder::record`].
+pub struct TraceRecorder<P, E, C, A, T> {
+    trace: SharedTrace<P, E, C, A, T>,
+}
+
+impl<P, E, C, A, T> Clone for TraceRecorder<P, E, C, A, T> {
+    fn clone(&self) -> Self {
+        Self {
+            trace: Arc::clone(&self.trace),
+        }
+    }
+}
+
+impl<P, E, C, A, T> Default for TraceRecorder<P, E, C, A, T> {
+    fn default() -> Self {
+        Self {
+            trace: Arc::new(Mutex::new(Trace::new())),
+        }
+    }
+}
+
+impl<P, E, C, A, T> TraceRecorder<P, E, C, A, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap the algorithm of the given process so that every event it processes is recorded.
+    ///
+    /// As with [`Logged`](crate::wal::Logged), the algorithm must read the time from `clock`,
+    /// which is set to the current time of `time_source` before each event; this is the time
+    /// recorded with the step, and the time at which it will be replayed.
+    pub fn record<Alg, TS>(
+        &self,
+        process: P,
+        inner: Alg,
+        time_source: TS,
+        clock: ManualTimeSource<T>,
+    ) -> Recorded<Alg, P, TS>
+    where
+        Alg: Algorithm<Event = E, Context = C, Action = A>,
+        TS: TimeSource<Time = T>,
+    {
+        Recorded {
+            inner,
+            process,
+            time_source,
+            clock,
+            trace: Arc::clone(&self.trace),
+        }
+    }
+
+    /// Returns a copy of the trace recorded so far.
+    pub fn trace(&self) -> Trace<P, E, C, A, T>
+    where
+        Trace<P, E, C, A, T>: Clone,
+    {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Trace<P, E, C, A, T>> {
+        lock_recover(&self.trace)
+    }
+}
+
+/// An algorithm which records each event it processes to a [`TraceRecorder`].
+///
+/// This `struct` is returned by the [`TraceRecorder::record`] method.
+pub struct Recorded<A, P, TS>
+where
+    A: Algorithm,
+    TS: TimeSource,
+{
+    inner: A,
+    process: P,
+    time_source: TS,
+    clock: ManualTimeSource<TS::Time>,
+    trace: SharedTrace<P, A::Event, A::Context, A::Action, TS::Time>,
+}
+
+impl<A, P, TS> Recorded<A, P, TS>
+where
+    A: Algorithm,
+    TS: TimeSource,
+{
+    /// Returns the wrapped algorithm.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+impl<A, P, TS> Algorithm for Recorded<A, P, TS>
+where
+    A: Algorithm,
+    A::Event: Clone,
+    A::Context: Clone,
+    A::Action: Clone,
+    P: Clone,
+    TS: TimeSource,
+{
+    type Event = A::Event;
+    type Action = A::Action;
+    type Context = A::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let time = self.time_source.now();
+        self.clock.set(time);
+
+        let result = self.inner.event(event.clone(), context.clone());
+
+        let (actions, error) = match &result {
+            Ok(actions) => (actions.clone(), None),
+            Err(err) => (Vec::new(), Some(err.to_string())),
+        };
+        lock_recover(&self.trace).push(TraceStep {
+            process: self.process.clone(),
+            time,
+            event,
+            context,
+            actions,
+            error,
+        });
+
+        result
+    }
+}
+
+#[cfg(all(test, feature = "algorithm-two-phase-commit", feature = "sim"))]
+mod tests {
+    use std::collections::{BTreeMap, VecDeque};
+    use std::time::{Duration, SystemTime};
+
+    use crate::driver::{DrivableAction, DriverAction};
+    use crate::sim::two_phase_commit::application;
+    use crate::two_phase_commit::{
+        Participant, TwoPhaseCommitAction, TwoPhaseCommitActionNotification,
+        TwoPhaseCommitAlgorithm, TwoPhaseCommitContext, TwoPhaseCommitContextBuilder,
+        TwoPhaseCommitEvent, TwoPhaseCommitState,
+    };
+
+    use super::*;
+
+    type Context = TwoPhaseCommitContext<String, String, SystemTime>;
+    type Event = TwoPhaseCommitEvent<String, String>;
+    type Action = TwoPhaseCommitAction<String, String, SystemTime>;
+    type TwoPhaseCommitTrace = Trace<String, Event, Context, Action, SystemTime>;
+
+    fn contexts() -> BTreeMap<String, Context> {
+        let coordinator = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![Participant::new("p".to_string())])
+            .build()
+            .unwrap();
+        let participant = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(0)
+            .with_state(TwoPhaseCommitState::WaitingForVoteRequest)
+            .with_this_process("p".to_string())
+            .with_participant_processes(vec!["p".to_string()])
+            .build()
+            .unwrap();
+        [
+            ("c".to_string(), coordinator),
+            ("p".to_string(), participant),
+        ]
+        .into()
+    }
+
+    // Run a coordinator and a participant through a single commit, delivering messages in order,
+    // and return the recorded trace.
+    pub(super) fn record_commit() -> Result<TwoPhaseCommitTrace, AlgorithmError> {
+        let time_source = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let recorder = TraceRecorder::new();
+        let algorithms: BTreeMap<_, _> = ["c", "p"]
+            .into_iter()
+            .map(|process| {
+                let clock = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+                let algorithm = recorder.record(
+                    process.to_string(),
+                    TwoPhaseCommitAlgorithm::new(clock.clone()),
+                    time_source.clone(),
+                    clock,
+                );
+                (process.to_string(), algorithm)
+            })
+            .collect();
+
+        let mut contexts = contexts();
+        let mut pending = VecDeque::from([("c".to_string(), TwoPhaseCommitEvent::Alarm())]);
+        let mut application = application(["v".to_string()], || true);
+        while let Some((process, event)) = pending.pop_front() {
+            time_source.advance(Duration::from_millis(10));
+            let actions = algorithms[&process].event(event, contexts[&process].clone())?;
+            for action in actions {
+                match action.into_driver_action() {
+                    DriverAction::Update { context, .. } => {
+                        contexts.insert(process.clone(), context);
+                    }
+                    DriverAction::SendMessage(to, message) => pending
+                        .push_back((to, TwoPhaseCommitEvent::Deliver(process.clone(), message))),
+                    DriverAction::Notify(notification) => pending.extend(
+                        application(&process, &notification)
+                            .into_iter()
+                            .map(|event| (process.clone(), event)),
+                    ),
+                }
+            }
+        }
+
+        Ok(recorder.trace())
+    }
+
+    fn replayer() -> TraceReplayer<
+        TwoPhaseCommitAlgorithm<String, String, ManualTimeSource<SystemTime>>,
+        SystemTime,
+    > {
+        let clock = ManualTimeSource::new(SystemTime::now());
+        TraceReplayer::new(TwoPhaseCommitAlgorithm::new(clock.clone()), clock)
+    }
+
+    /// Test that a recorded run replays without divergence, both from the start and from a step
+    /// part way through.
+    #[test]
+    fn test_replay() -> Result<(), Box<dyn std::error::Error>> {
+        let trace = record_commit()?;
+        assert!(trace.steps().iter().any(|step| step.process == "p"
+            && step.actions.contains(&TwoPhaseCommitAction::Notify(
+                TwoPhaseCommitActionNotification::Commit(1, "v".into())
+            ))));
+
+        let report = replayer().replay(&trace, 0);
+        assert_eq!(report.divergence, None);
+        assert_eq!(report.replayed, trace.len());
+
+        let report = replayer().replay(&trace, 3);
+        assert_eq!(report.divergence, None);
+        assert_eq!(report.replayed, trace.len() - 3);
+
+        Ok(())
+    }
+
+    /// Test that the first step at which the algorithm behaves differently from the recording is
+    /// reported.
+    #[test]
+    fn test_divergence() -> Result<(), Box<dyn std::error::Error>> {
+        let mut steps = record_commit()?.steps().to_vec();
+        let index = steps
+            .iter()
+            .position(|step| step.event == TwoPhaseCommitEvent::Vote(true))
+            .expect("participant voted");
+        // As if an earlier build had voted no.
+        steps[index].event = TwoPhaseCommitEvent::Vote(false);
+
+        let report = replayer().replay(&Trace::from(steps), 0);
+        assert_eq!(report.replayed, index);
+        assert_eq!(report.divergence.map(|d| d.step()), Some(index));
+
+        Ok(())
+    }
+}
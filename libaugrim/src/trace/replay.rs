@@ -0,0 +1,166 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains TraceReplayer.
+
+use std::collections::BTreeMap;
+
+use crate::algorithm::Algorithm;
+use crate::driver::{DrivableAction, DriverAction};
+use crate::time::{ManualTimeSource, Time};
+
+use super::Trace;
+
+/// The first difference found between a trace and a replay of it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Divergence<C, A> {
+    /// The context produced by the replayed steps differs from the context recorded for the
+    /// step.
+    Context { step: usize, expected: C, actual: C },
+    /// The algorithm returned different actions than were recorded for the step.
+    Actions {
+        step: usize,
+        expected: Vec<A>,
+        actual: Vec<A>,
+    },
+    /// The algorithm returned an error where none was recorded, or did not return an error where
+    /// one was recorded. Only the presence of an error is compared, not its message.
+    Error {
+        step: usize,
+        expected: Option<String>,
+        actual: Option<String>,
+    },
+}
+
+impl<C, A> Divergence<C, A> {
+    /// Returns the index of the step at which the divergence occurred.
+    pub fn step(&self) -> usize {
+        match self {
+            Divergence::Context { step, .. }
+            | Divergence::Actions { step, .. }
+            | Divergence::Error { step, .. } => *step,
+        }
+    }
+}
+
+/// The result of replaying a trace.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraceReport<C, A> {
+    /// The number of steps which were replayed without diverging.
+    pub replayed: usize,
+    /// The first divergence, if any. Replay stops at the first divergence.
+    pub divergence: Option<Divergence<C, A>>,
+}
+
+/// Replays a [`Trace`] through an algorithm, comparing its behavior with the recording.
+///
+/// The algorithm must read the time from `clock`, which is set to the recorded time of each step
+/// before it is replayed. A single algorithm replays the steps of every process, since the
+/// algorithm's state is held entirely in the context.
+///
+/// Each process starts from the context recorded with its first replayed step; from then on, it
+/// is given the context produced by the `Update` actions of its replayed steps.
+pub struct TraceReplayer<A, T> {
+    algorithm: A,
+    clock: ManualTimeSource<T>,
+}
+
+impl<A, T> TraceReplayer<A, T>
+where
+    A: Algorithm,
+    A::Event: Clone,
+    A::Context: Clone + PartialEq,
+    A::Action: Clone + PartialEq + DrivableAction<Context = A::Context, Time = T>,
+    T: Time,
+{
+    pub fn new(algorithm: A, clock: ManualTimeSource<T>) -> Self {
+        Self { algorithm, clock }
+    }
+
+    /// Replay the steps of the trace from the step with index `from` onwards.
+    pub fn replay<P>(
+        &self,
+        trace: &Trace<P, A::Event, A::Context, A::Action, T>,
+        from: usize,
+    ) -> TraceReport<A::Context, A::Action>
+    where
+        P: Ord + Clone,
+    {
+        let mut contexts: BTreeMap<P, A::Context> = BTreeMap::new();
+        let mut replayed = 0;
+
+        for (index, step) in trace.steps().iter().enumerate().skip(from) {
+            let context = match contexts.get(&step.process) {
+                Some(context) if *context != step.context => {
+                    return TraceReport {
+                        replayed,
+                        divergence: Some(Divergence::Context {
+                            step: index,
+                            expected: step.context.clone(),
+                            actual: context.clone(),
+                        }),
+                    };
+                }
+                Some(context) => context.clone(),
+                None => step.context.clone(),
+            };
+
+            self.clock.set(step.time);
+            let divergence = match self.algorithm.event(step.event.clone(), context) {
+                Ok(_) if step.error.is_some() => Some(Divergence::Error {
+                    step: index,
+                    expected: step.error.clone(),
+                    actual: None,
+                }),
+                Ok(actions) if actions != step.actions => Some(Divergence::Actions {
+                    step: index,
+                    expected: step.actions.clone(),
+                    actual: actions,
+                }),
+                Ok(actions) => {
+                    for action in actions {
+                        if let DriverAction::Update { context, .. } = action.into_driver_action() {
+                            contexts.insert(step.process.clone(), context);
+                        }
+                    }
+                    None
+                }
+                Err(err) if step.error.is_none() => Some(Divergence::Error {
+                    step: index,
+                    expected: None,
+                    actual: Some(err.to_string()),
+                }),
+                Err(_) => None,
+            };
+
+            if divergence.is_some() {
+                return TraceReport {
+                    replayed,
+                    divergence,
+                };
+            }
+            replayed += 1;
+        }
+
+        TraceReport {
+            replayed,
+            divergence: None,
+        }
+    }
+
+    /// Returns the wrapped algorithm.
+    pub fn into_inner(self) -> A {
+        self.algorithm
+    }
+}
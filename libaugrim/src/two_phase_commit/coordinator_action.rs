@@ -17,6 +17,7 @@ use crate::process::Process;
 use crate::time::Time;
 
 use super::CoordinatorContext;
+use super::Epoch;
 use super::TwoPhaseCommitContext;
 use super::TwoPhaseCommitMessage;
 use super::{TwoPhaseCommitAction, TwoPhaseCommitActionNotification};
@@ -28,18 +29,21 @@ where
     T: Time,
 {
     Update {
-        context: TwoPhaseCommitContext<P, T, CoordinatorContext<P, T>>,
+        context: TwoPhaseCommitContext<P, V, T, CoordinatorContext<P, V, T>>,
         alarm: Option<T>,
     },
     SendMessage(P, TwoPhaseCommitMessage<V>),
-    Notify(CoordinatorActionNotification),
+    Notify(CoordinatorActionNotification<V>),
 }
 
-pub enum CoordinatorActionNotification {
+pub enum CoordinatorActionNotification<V>
+where
+    V: Value,
+{
     RequestForStart(),
     RequestForVote(),
-    Commit(),
-    Abort(),
+    Commit(Epoch, V),
+    Abort(Epoch, V),
     MessageDropped(String),
 }
 
@@ -61,14 +65,18 @@ where
     }
 }
 
-impl<V> From<CoordinatorActionNotification> for TwoPhaseCommitActionNotification<V>
+impl<V> From<CoordinatorActionNotification<V>> for TwoPhaseCommitActionNotification<V>
 where
     V: Value,
 {
-    fn from(notification: CoordinatorActionNotification) -> Self {
+    fn from(notification: CoordinatorActionNotification<V>) -> Self {
         match notification {
-            CoordinatorActionNotification::Abort() => TwoPhaseCommitActionNotification::Abort(),
-            CoordinatorActionNotification::Commit() => TwoPhaseCommitActionNotification::Commit(),
+            CoordinatorActionNotification::Abort(epoch, value) => {
+                TwoPhaseCommitActionNotification::Abort(epoch, value)
+            }
+            CoordinatorActionNotification::Commit(epoch, value) => {
+                TwoPhaseCommitActionNotification::Commit(epoch, value)
+            }
             CoordinatorActionNotification::MessageDropped(s) => {
                 TwoPhaseCommitActionNotification::MessageDropped(s)
             }
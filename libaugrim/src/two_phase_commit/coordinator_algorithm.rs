@@ -20,6 +20,8 @@ use crate::error::{AlgorithmError, InvalidStateError};
 use crate::process::Process;
 use crate::time::TimeSource;
 
+use super::meter::Meter;
+use super::require_value;
 use super::CoordinatorAction;
 use super::CoordinatorActionNotification;
 use super::CoordinatorContext;
@@ -38,6 +40,9 @@ const ACK_TIMEOUT_SECONDS: u64 = 5;
 
 const VOTE_TIMEOUT_SECONDS: u64 = 30;
 
+// The unified context, specialized to the coordinator role.
+type Context<P, V, T> = TwoPhaseCommitContext<P, V, T, CoordinatorContext<P, V, T>>;
+
 pub struct CoordinatorAlgorithm<P, V, TS>
 where
     P: Process,
@@ -47,6 +52,7 @@ where
     _process_phantom: PhantomData<P>,
     _value_phantom: PhantomData<V>,
     time_source: TS,
+    meter: Meter<P, TS::Time>,
 }
 
 impl<P, V, TS> CoordinatorAlgorithm<P, V, TS>
@@ -56,13 +62,26 @@ where
     TS: TimeSource,
 {
     pub fn new(time_source: TS) -> Self {
+        Self::with_meter(time_source, Meter::disabled())
+    }
+
+    pub fn with_meter(time_source: TS, meter: Meter<P, TS::Time>) -> Self {
         CoordinatorAlgorithm {
             _process_phantom: PhantomData,
             _value_phantom: PhantomData,
             time_source,
+            meter,
         }
     }
 
+    // Create the actions for a dropped message, which is reported via a notification.
+    fn message_dropped(&self, reason: String) -> Vec<CoordinatorAction<P, V, TS::Time>> {
+        self.meter.message_dropped();
+        vec![CoordinatorAction::Notify(
+            CoordinatorActionNotification::MessageDropped(reason),
+        )]
+    }
+
     // Create actions for an abort decision. This set of actions is generated whenever an abort
     // occurs; an abort occurs when:
     //
@@ -70,9 +89,11 @@ where
     // - When all participants have voted but at least one voted NO
     fn push_abort_actions(
         &self,
-        mut context: TwoPhaseCommitContext<P, TS::Time, CoordinatorContext<P, TS::Time>>,
+        mut context: Context<P, V, TS::Time>,
         actions: &mut Vec<CoordinatorAction<P, V, TS::Time>>,
-    ) {
+    ) -> Result<(), AlgorithmError> {
+        let value = require_value(context.value())?;
+
         // The order of actions here is important! We must update our state to `Abort` before we
         // send any messages for correctness of the algorithm.
 
@@ -97,18 +118,23 @@ where
 
         // Notify that we've aborted.
         actions.push(CoordinatorAction::Notify(
-            CoordinatorActionNotification::Abort(),
+            CoordinatorActionNotification::Abort(*context.epoch(), value),
         ));
+        self.meter.aborted();
+        self.meter
+            .epoch_decided(context.this_process(), *context.epoch(), &self.time_source);
 
         // Wait for a decision ack.
         self.push_wait_for_decision_ack(&mut context, actions);
+
+        Ok(())
     }
 
     // Create actions for switching into WaitingForDecisionAck state. This is the state after
     // a decision has been communicated to participants, before we start a new epoch.
     fn push_wait_for_decision_ack(
         &self,
-        context: &mut TwoPhaseCommitContext<P, TS::Time, CoordinatorContext<P, TS::Time>>,
+        context: &mut Context<P, V, TS::Time>,
         actions: &mut Vec<CoordinatorAction<P, V, TS::Time>>,
     ) {
         let ack_timeout_start = self.time_source.now();
@@ -124,16 +150,13 @@ where
     // a decision has been reached, either abort or commit.
     fn push_advance_epoch_actions(
         &self,
-        context: &mut TwoPhaseCommitContext<P, TS::Time, CoordinatorContext<P, TS::Time>>,
+        context: &mut Context<P, V, TS::Time>,
         actions: &mut Vec<CoordinatorAction<P, V, TS::Time>>,
     ) {
-        // Update the epoch and set the state to WaitingForStart. Also update the last commit epoch
-        // used to answer DecisionRequest messages.
-        if *context.state() == CoordinatorState::Commit {
-            context.set_last_commit_epoch(Some(*context.epoch()));
-        }
+        // Update the epoch and set the state to WaitingForStart.
         context.set_epoch(context.epoch() + 1);
         context.set_state(CoordinatorState::WaitingForStart);
+        context.set_value(None);
         context
             .participants_mut()
             .iter_mut()
@@ -161,7 +184,7 @@ where
 {
     type Event = CoordinatorEvent<P, V>;
     type Action = CoordinatorAction<P, V, TS::Time>;
-    type Context = TwoPhaseCommitContext<P, TS::Time, CoordinatorContext<P, TS::Time>>;
+    type Context = Context<P, V, TS::Time>;
 
     fn event(
         &self,
@@ -177,6 +200,17 @@ where
             //   - Update the state to Voting
             //   - Set a timeout alarm for the maximum time to wait for votes
             CoordinatorEvent::Start(value) => {
+                // If we receive a Start event when not in WaitingForStart, it indicates a
+                // programming error by the caller of the algorithm; voting again would reopen an
+                // epoch which may already have been decided.
+                if !matches!(context.state(), CoordinatorState::WaitingForStart) {
+                    return Err(AlgorithmError::InvalidState(
+                        InvalidStateError::with_message(
+                            "Start event when not in WaitingForStart state".into(),
+                        ),
+                    ));
+                }
+
                 let mut actions = Vec::new();
 
                 // Send a VoteRequest message to all participants
@@ -194,8 +228,15 @@ where
                 let vote_timeout_end =
                     vote_timeout_start + Duration::from_secs(VOTE_TIMEOUT_SECONDS);
 
-                // Add an action to update the state to Voting and set the timeout alarm.
+                // Add an action to update the state to Voting and set the timeout alarm. The value
+                // is retained so that the decision notification can report it.
                 context.set_state(CoordinatorState::Voting { vote_timeout_start });
+                context.set_value(Some(value));
+                self.meter.epoch_started(
+                    context.this_process(),
+                    *context.epoch(),
+                    vote_timeout_start,
+                );
                 actions.push(CoordinatorAction::Update {
                     context,
                     alarm: Some(vote_timeout_end),
@@ -221,8 +262,13 @@ where
 
                 // If vote is true, then we decide to commit; if vote is false, we decide to abort.
                 if vote {
-                    // Add an action to update the state to commit and unset the alarm.
+                    let value = require_value(context.value())?;
+
+                    // Add an action to update the state to commit and unset the alarm. The epoch
+                    // is recorded as the last commit epoch now, as the state moves on to waiting
+                    // for acks before the epoch advances.
                     context.set_state(CoordinatorState::Commit);
+                    context.set_last_commit_epoch(Some(*context.epoch()));
                     actions.push(CoordinatorAction::Update {
                         context: context.clone(),
                         alarm: None,
@@ -238,13 +284,19 @@ where
 
                     // Notify that we've committed.
                     actions.push(CoordinatorAction::Notify(
-                        CoordinatorActionNotification::Commit(),
+                        CoordinatorActionNotification::Commit(*context.epoch(), value),
                     ));
+                    self.meter.committed();
+                    self.meter.epoch_decided(
+                        context.this_process(),
+                        *context.epoch(),
+                        &self.time_source,
+                    );
 
                     // Wait for a decision ack.
                     self.push_wait_for_decision_ack(&mut context, &mut actions);
                 } else {
-                    self.push_abort_actions(context, &mut actions);
+                    self.push_abort_actions(context, &mut actions)?;
                 }
 
                 Ok(actions)
@@ -267,7 +319,8 @@ where
                     {
                         // Decide to abort. Use a function to fill in the abort actions since abort
                         // can occur in other situations as well.
-                        self.push_abort_actions(context, &mut actions);
+                        self.meter.vote_timeout();
+                        self.push_abort_actions(context, &mut actions)?;
                     }
 
                     Ok(actions)
@@ -324,6 +377,7 @@ where
                         > *ack_timeout_start + Duration::from_secs(ACK_TIMEOUT_SECONDS)
                     {
                         // Move to the next epoch. This will unset the alarm.
+                        self.meter.decision_ack_timeout();
                         self.push_advance_epoch_actions(&mut context, &mut actions);
                     }
 
@@ -362,11 +416,9 @@ where
                 {
                     Some(inner) => inner,
                     None => {
-                        return Ok(vec![CoordinatorAction::Notify(
-                            CoordinatorActionNotification::MessageDropped(
-                                "sender process is not a participant".into(),
-                            ),
-                        )]);
+                        return Ok(
+                            self.message_dropped("sender process is not a participant".into())
+                        );
                     }
                 };
 
@@ -374,11 +426,7 @@ where
                 // could happen under normal operation if a vote was processed after a timeout, and
                 // is therefore not an error.
                 if context_epoch != epoch {
-                    return Ok(vec![CoordinatorAction::Notify(
-                        CoordinatorActionNotification::MessageDropped(
-                            "epoch is not the current epoch".into(),
-                        ),
-                    )]);
+                    return Ok(self.message_dropped("epoch is not the current epoch".into()));
                 }
 
                 // Ignore the message if we are not in the voting window. This could occur if we've
@@ -391,21 +439,22 @@ where
                         vote_timeout_start: _,
                     }
                 ) {
-                    return Ok(vec![CoordinatorAction::Notify(
-                        CoordinatorActionNotification::MessageDropped(
-                            "context state is not Voting".into(),
-                        ),
-                    )]);
+                    return Ok(self.message_dropped("context state is not Voting".into()));
                 }
 
                 // Ignore if this participant already voted. This should not occur in normal
                 // operation.
                 if participant.vote.is_some() {
-                    return Ok(vec![CoordinatorAction::Notify(
-                        CoordinatorActionNotification::MessageDropped(
-                            "participant has already voted".into(),
-                        ),
-                    )]);
+                    return Ok(self.message_dropped("participant has already voted".into()));
+                }
+
+                // The vote timeout alarm is kept while other votes are outstanding.
+                let mut vote_timeout_end = None;
+                if let CoordinatorState::Voting { vote_timeout_start } = &context_state {
+                    vote_timeout_end =
+                        Some(*vote_timeout_start + Duration::from_secs(VOTE_TIMEOUT_SECONDS));
+                    self.meter
+                        .vote_received(vote_timeout_start, &self.time_source);
                 }
 
                 let mut actions = Vec::new();
@@ -414,7 +463,7 @@ where
                 participant.vote = Some(vote);
                 actions.push(CoordinatorAction::Update {
                     context: context.clone(),
-                    alarm: None,
+                    alarm: vote_timeout_end,
                 });
 
                 // If all the participants have voted, then either decide to abort or change state.
@@ -422,7 +471,7 @@ where
                     if context.participants().iter().any(|p| p.vote == Some(false)) {
                         // We got at least one NO vote, so decide to abort. Use a function to fill
                         // in the abort since abort can occur in other situations as well.
-                        self.push_abort_actions(context, &mut actions)
+                        self.push_abort_actions(context, &mut actions)?;
                     } else {
                         // All participants voted yes, so we provide one last opportunity for the
                         // coordinator to vote no by waiting for the coordinators vote.
@@ -445,17 +494,15 @@ where
             // as part of its termination protocol. If we have the information, answer this request
             // with a commit or abort message.
             CoordinatorEvent::Deliver(process, CoordinatorMessage::DecisionRequest(epoch)) => {
+                self.meter.decision_request_received();
+
                 // The sender must be a participant.
-                if context
+                if !context
                     .participants()
                     .iter()
-                    .any(|participant| participant.process != process)
+                    .any(|participant| participant.process == process)
                 {
-                    return Ok(vec![CoordinatorAction::Notify(
-                        CoordinatorActionNotification::MessageDropped(
-                            "sender process is not a participant".into(),
-                        ),
-                    )]);
+                    return Ok(self.message_dropped("sender process is not a participant".into()));
                 }
 
                 // We record the last commit epoch in the context; if the epoch requested was the
@@ -486,17 +533,15 @@ where
                 // uncertainty period for an older epoch.
                 //
                 // If the epoch is after our current epoch, we ignore the message as we do not yet
-                // know what the future holds. Similarly, we do not yet have a decision for the
-                // current epoch or we would have advanced to the next epoch already.
-                Ok(vec![CoordinatorAction::Notify(
-                    CoordinatorActionNotification::MessageDropped(format!(
-                        "decision for requested epoch {} is unknown (current epoch: {}, \
+                // know what the future holds. Similarly, unless it committed, we do not yet answer for
+                // the current epoch; it is answered once we have advanced to the next epoch.
+                Ok(self.message_dropped(format!(
+                    "decision for requested epoch {} is unknown (current epoch: {}, \
                         last commit epoch: {:?})",
-                        epoch,
-                        context.epoch(),
-                        context.last_commit_epoch()
-                    )),
-                )])
+                    epoch,
+                    context.epoch(),
+                    context.last_commit_epoch()
+                )))
             }
 
             CoordinatorEvent::Deliver(process, CoordinatorMessage::DecisionAck(epoch)) => {
@@ -512,11 +557,9 @@ where
                 {
                     Some(inner) => inner,
                     None => {
-                        return Ok(vec![CoordinatorAction::Notify(
-                            CoordinatorActionNotification::MessageDropped(
-                                "sender process is not a participant".into(),
-                            ),
-                        )]);
+                        return Ok(
+                            self.message_dropped("sender process is not a participant".into())
+                        );
                     }
                 };
 
@@ -524,11 +567,9 @@ where
                 // could happen under normal operation if an ack was processed after a timeout, and
                 // is therefore not an error.
                 if context_epoch != epoch {
-                    return Ok(vec![CoordinatorAction::Notify(
-                        CoordinatorActionNotification::MessageDropped(format!(
-                            "epoch {epoch} is not the current epoch {context_epoch}",
-                        )),
-                    )]);
+                    return Ok(self.message_dropped(format!(
+                        "epoch {epoch} is not the current epoch {context_epoch}",
+                    )));
                 }
 
                 // Ignore the message if we are not in the decision ack window. This is unlikely to
@@ -541,30 +582,33 @@ where
                         ack_timeout_start: _,
                     }
                 ) {
-                    return Ok(vec![CoordinatorAction::Notify(
-                        CoordinatorActionNotification::MessageDropped(
-                            "context state is not WaitingForDecisionAck".into(),
-                        ),
-                    )]);
+                    return Ok(
+                        self.message_dropped("context state is not WaitingForDecisionAck".into())
+                    );
                 }
 
                 // Ignore if this participant already acked. This should not occur in normal
                 // operation.
                 if participant.decision_ack {
-                    return Ok(vec![CoordinatorAction::Notify(
-                        CoordinatorActionNotification::MessageDropped(
-                            "participant has already sent a decision ack".into(),
-                        ),
-                    )]);
+                    return Ok(
+                        self.message_dropped("participant has already sent a decision ack".into())
+                    );
                 }
 
                 let mut actions = Vec::new();
 
-                // Update the context to record the participant's ack
+                // Update the context to record the participant's ack, keeping the ack timeout
+                // alarm while other acks are outstanding.
+                let ack_timeout_end = match context_state {
+                    CoordinatorState::WaitingForDecisionAck { ack_timeout_start } => {
+                        Some(ack_timeout_start + Duration::from_secs(ACK_TIMEOUT_SECONDS))
+                    }
+                    _ => None,
+                };
                 participant.decision_ack = true;
                 actions.push(CoordinatorAction::Update {
                     context: context.clone(),
-                    alarm: None,
+                    alarm: ack_timeout_end,
                 });
 
                 // If all the participants have acked, then move to the next epoch.
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::algorithm::Value;
 use crate::process::Process;
 use crate::time::Time;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Participant<P> {
     pub process: P,
     pub vote: Option<bool>,
@@ -33,6 +35,7 @@ impl<P> Participant<P> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum CoordinatorState<T>
 where
     T: Time,
@@ -46,11 +49,14 @@ where
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct CoordinatorContext<P, T>
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CoordinatorContext<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     pub(super) participants: Vec<Participant<P>>,
     pub(super) state: CoordinatorState<T>,
+    pub(super) value: Option<V>,
 }
@@ -0,0 +1,71 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementations of the driver traits, allowing 2PC to be run by any driver.
+
+use crate::algorithm::Value;
+use crate::driver::{DrivableAction, DrivableEvent, DriverAction};
+use crate::process::Process;
+use crate::time::Time;
+
+use super::TwoPhaseCommitAction;
+use super::TwoPhaseCommitActionNotification;
+use super::TwoPhaseCommitContext;
+use super::TwoPhaseCommitEvent;
+use super::TwoPhaseCommitMessage;
+
+impl<P, V, T> DrivableAction for TwoPhaseCommitAction<P, V, T>
+where
+    P: Process,
+    V: Value,
+    T: Time,
+{
+    type Context = TwoPhaseCommitContext<P, V, T>;
+    type Time = T;
+    type Process = P;
+    type Message = TwoPhaseCommitMessage<V>;
+    type Notification = TwoPhaseCommitActionNotification<V>;
+
+    fn into_driver_action(
+        self,
+    ) -> DriverAction<Self::Context, Self::Time, Self::Process, Self::Message, Self::Notification>
+    {
+        match self {
+            TwoPhaseCommitAction::Update { context, alarm } => {
+                DriverAction::Update { context, alarm }
+            }
+            TwoPhaseCommitAction::SendMessage(to, message) => {
+                DriverAction::SendMessage(to, message)
+            }
+            TwoPhaseCommitAction::Notify(notification) => DriverAction::Notify(notification),
+        }
+    }
+}
+
+impl<P, V> DrivableEvent for TwoPhaseCommitEvent<P, V>
+where
+    P: Process,
+    V: Value,
+{
+    type Process = P;
+    type Message = TwoPhaseCommitMessage<V>;
+
+    fn alarm() -> Option<Self> {
+        Some(TwoPhaseCommitEvent::Alarm())
+    }
+
+    fn deliver(from: Self::Process, message: Self::Message) -> Self {
+        TwoPhaseCommitEvent::Deliver(from, message)
+    }
+}
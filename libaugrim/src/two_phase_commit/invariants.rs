@@ -0,0 +1,347 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains TwoPhaseCommitInvariants, for use with `Checked`.
+
+use crate::algorithm::{Invariant, Value};
+use crate::error::InvalidStateError;
+use crate::process::Process;
+use crate::time::Time;
+
+use super::TwoPhaseCommitAction;
+use super::TwoPhaseCommitContext;
+use super::TwoPhaseCommitState;
+
+/// The invariants of [`TwoPhaseCommitAlgorithm`](super::TwoPhaseCommitAlgorithm), for use with
+/// [`Checked`](crate::Checked).
+///
+/// The incoming context must have a `last_commit_epoch` no greater than its `epoch`. Each
+/// `Update` action, compared with the context before it (either the incoming context or the
+/// previous `Update` in the same list of actions), must:
+///
+/// - keep the same coordinator and `this_process`
+/// - not decrease the epoch or the last commit epoch
+/// - have a `last_commit_epoch` no greater than its `epoch`
+/// - follow an allowed state transition
+/// - only set an alarm in a state which has a timeout (`Voting`, `WaitingForDecisionAck` and
+///   `Voted`)
+///
+/// ```
+/// use augrim::{Algorithm, SystemTimeFactory};
+/// use augrim::two_phase_commit::{TwoPhaseCommitAlgorithm, TwoPhaseCommitInvariants};
+///
+/// let algorithm = TwoPhaseCommitAlgorithm::<String, String, _>::new(SystemTimeFactory::new())
+///     .checked(TwoPhaseCommitInvariants);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TwoPhaseCommitInvariants;
+
+impl<P, V, T> Invariant<TwoPhaseCommitContext<P, V, T>, TwoPhaseCommitAction<P, V, T>>
+    for TwoPhaseCommitInvariants
+where
+    P: Process,
+    V: Value,
+    T: Time,
+{
+    fn check_context(
+        &self,
+        context: &TwoPhaseCommitContext<P, V, T>,
+    ) -> Result<(), InvalidStateError> {
+        check_last_commit_epoch(context)
+    }
+
+    fn check_actions(
+        &self,
+        context: &TwoPhaseCommitContext<P, V, T>,
+        actions: &[TwoPhaseCommitAction<P, V, T>],
+    ) -> Result<(), InvalidStateError> {
+        let mut previous = context;
+
+        for action in actions {
+            let (next, alarm) = match action {
+                TwoPhaseCommitAction::Update { context, alarm } => (context, alarm),
+                _ => continue,
+            };
+
+            if next.coordinator() != previous.coordinator()
+                || next.this_process() != previous.this_process()
+            {
+                return Err(violation("coordinator or this_process changed".into()));
+            }
+
+            if next.epoch() < previous.epoch() {
+                return Err(violation(format!(
+                    "epoch decreased from {} to {}",
+                    previous.epoch(),
+                    next.epoch()
+                )));
+            }
+
+            if next.last_commit_epoch() < previous.last_commit_epoch() {
+                return Err(violation(format!(
+                    "last commit epoch decreased from {:?} to {:?}",
+                    previous.last_commit_epoch(),
+                    next.last_commit_epoch()
+                )));
+            }
+
+            check_last_commit_epoch(next)?;
+
+            let (from, to) = (previous.state(), next.state());
+            let is_coordinator = previous.participants().is_some();
+            if !is_allowed_transition(is_coordinator, &from, &to) {
+                return Err(violation(format!(
+                    "state transition from {from:?} to {to:?} is not allowed"
+                )));
+            }
+
+            if alarm.is_some() && !has_timeout(&to) {
+                return Err(violation(format!("alarm set in state {to:?}")));
+            }
+
+            previous = next;
+        }
+
+        Ok(())
+    }
+}
+
+fn violation(message: String) -> InvalidStateError {
+    InvalidStateError::with_message(format!("2PC invariant violated: {message}"))
+}
+
+fn check_last_commit_epoch<P, V, T>(
+    context: &TwoPhaseCommitContext<P, V, T>,
+) -> Result<(), InvalidStateError>
+where
+    P: Process,
+    V: Value,
+    T: Time,
+{
+    match context.last_commit_epoch() {
+        Some(last_commit_epoch) if last_commit_epoch > context.epoch() => Err(violation(format!(
+            "last commit epoch {} is greater than epoch {}",
+            last_commit_epoch,
+            context.epoch()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+// Returns true if the state is one in which the algorithm waits for a timeout, and therefore may
+// set an alarm.
+fn has_timeout<T>(state: &TwoPhaseCommitState<T>) -> bool {
+    matches!(
+        state,
+        TwoPhaseCommitState::Voting { .. }
+            | TwoPhaseCommitState::WaitingForDecisionAck { .. }
+            | TwoPhaseCommitState::Voted { .. }
+    )
+}
+
+// The allowed state graph of the coordinator or the participant. Remaining in the same state
+// (for example, to record a vote or reset a timeout) is always allowed.
+fn is_allowed_transition<T>(
+    is_coordinator: bool,
+    from: &TwoPhaseCommitState<T>,
+    to: &TwoPhaseCommitState<T>,
+) -> bool {
+    use TwoPhaseCommitState::*;
+
+    if std::mem::discriminant(from) == std::mem::discriminant(to) {
+        return true;
+    }
+
+    if is_coordinator {
+        matches!(
+            (from, to),
+            (WaitingForStart, Voting { .. })
+                | (Voting { .. }, WaitingForVote)
+                | (Voting { .. }, Abort)
+                | (WaitingForVote, Commit)
+                | (WaitingForVote, Abort)
+                | (Commit, WaitingForDecisionAck { .. })
+                | (Abort, WaitingForDecisionAck { .. })
+                | (Commit, WaitingForStart)
+                | (Abort, WaitingForStart)
+                | (WaitingForDecisionAck { .. }, WaitingForStart)
+        )
+    } else {
+        matches!(
+            (from, to),
+            (WaitingForVoteRequest, WaitingForVote)
+                | (WaitingForVote, Voted { .. })
+                | (WaitingForVote, Abort)
+                | (Voted { .. }, Commit)
+                | (Voted { .. }, Abort)
+                | (Commit, WaitingForVoteRequest)
+                | (Abort, WaitingForVoteRequest)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use crate::algorithm::Algorithm;
+    use crate::error::AlgorithmError;
+    use crate::time::SystemTimeFactory;
+    use crate::two_phase_commit::{
+        Participant, TwoPhaseCommitAlgorithm, TwoPhaseCommitContextBuilder, TwoPhaseCommitEvent,
+        TwoPhaseCommitMessage,
+    };
+
+    use super::*;
+
+    fn coordinator_context(
+        epoch: u64,
+        last_commit_epoch: u64,
+        state: TwoPhaseCommitState<SystemTime>,
+    ) -> TwoPhaseCommitContext<String, String, SystemTime> {
+        TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(epoch)
+            .with_last_commit_epoch(last_commit_epoch)
+            .with_state(state)
+            .with_this_process("c".to_string())
+            .with_participants(vec![Participant::new("p1".to_string())])
+            .build()
+            .unwrap()
+    }
+
+    fn participant_context(
+        state: TwoPhaseCommitState<SystemTime>,
+    ) -> TwoPhaseCommitContext<String, String, SystemTime> {
+        TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(2)
+            .with_state(state)
+            .with_this_process("p1".to_string())
+            .with_participant_processes(vec!["p1".to_string()])
+            .build()
+            .unwrap()
+    }
+
+    /// Test that a correct run through the algorithm passes the invariant checks.
+    #[test]
+    fn test_valid_run() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new())
+            .checked(TwoPhaseCommitInvariants);
+
+        let context = coordinator_context(2, 1, TwoPhaseCommitState::WaitingForStart);
+        let actions = algorithm.event(TwoPhaseCommitEvent::Start("v".to_string()), context)?;
+
+        let context = actions
+            .into_iter()
+            .find_map(|action| match action {
+                TwoPhaseCommitAction::Update { context, .. } => Some(context),
+                _ => None,
+            })
+            .unwrap();
+
+        algorithm.event(
+            TwoPhaseCommitEvent::Deliver(
+                "p1".into(),
+                TwoPhaseCommitMessage::VoteResponse(2, false),
+            ),
+            context,
+        )?;
+
+        Ok(())
+    }
+
+    /// Test that an incoming context with a last commit epoch after its epoch is rejected.
+    #[test]
+    fn test_invalid_context() {
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new())
+            .checked(TwoPhaseCommitInvariants);
+
+        let context = coordinator_context(2, 3, TwoPhaseCommitState::WaitingForStart);
+        assert!(matches!(
+            algorithm.event(TwoPhaseCommitEvent::Start("v".to_string()), context),
+            Err(AlgorithmError::InvalidState(_))
+        ));
+    }
+
+    /// Test that decreasing epochs, disallowed transitions and misplaced alarms are detected.
+    #[test]
+    fn test_invalid_actions() {
+        let context = coordinator_context(2, 1, TwoPhaseCommitState::WaitingForStart);
+
+        let decreased_epoch = vec![TwoPhaseCommitAction::Update {
+            context: coordinator_context(1, 1, TwoPhaseCommitState::WaitingForStart),
+            alarm: None,
+        }];
+        assert!(TwoPhaseCommitInvariants
+            .check_actions(&context, &decreased_epoch)
+            .is_err());
+
+        let skipped_vote = vec![TwoPhaseCommitAction::Update {
+            context: coordinator_context(2, 1, TwoPhaseCommitState::Commit),
+            alarm: None,
+        }];
+        assert!(TwoPhaseCommitInvariants
+            .check_actions(&context, &skipped_vote)
+            .is_err());
+
+        let misplaced_alarm = vec![TwoPhaseCommitAction::Update {
+            context: coordinator_context(2, 1, TwoPhaseCommitState::WaitingForStart),
+            alarm: Some(SystemTime::now()),
+        }];
+        assert!(TwoPhaseCommitInvariants
+            .check_actions(&context, &misplaced_alarm)
+            .is_err());
+    }
+
+    /// Test that transitions are checked against the graph of the context's own role, so that a
+    /// participant cannot take a transition allowed only for the coordinator.
+    #[test]
+    fn test_cross_role_transitions() {
+        let participant_commit = vec![TwoPhaseCommitAction::Update {
+            context: participant_context(TwoPhaseCommitState::Commit),
+            alarm: None,
+        }];
+        assert!(TwoPhaseCommitInvariants
+            .check_actions(
+                &participant_context(TwoPhaseCommitState::WaitingForVote),
+                &participant_commit
+            )
+            .is_err());
+
+        // A coordinator cannot move from Commit to the participant's WaitingForVoteRequest, which
+        // its context cannot even hold.
+        assert!(
+            TwoPhaseCommitContextBuilder::<String, String, SystemTime>::new()
+                .with_coordinator("c".to_string())
+                .with_epoch(2)
+                .with_state(TwoPhaseCommitState::WaitingForVoteRequest)
+                .with_this_process("c".to_string())
+                .with_participants(vec![Participant::new("p1".to_string())])
+                .build()
+                .is_err()
+        );
+
+        let participant_abort = vec![TwoPhaseCommitAction::Update {
+            context: participant_context(TwoPhaseCommitState::Abort),
+            alarm: None,
+        }];
+        assert!(TwoPhaseCommitInvariants
+            .check_actions(
+                &participant_context(TwoPhaseCommitState::WaitingForVote),
+                &participant_abort
+            )
+            .is_ok());
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains `Meter`, through which the 2PC algorithms report their metrics.
+
+#![cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+
+use std::marker::PhantomData;
+#[cfg(feature = "metrics")]
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "metrics")]
+use crate::metrics::MetricsSink;
+use crate::process::Process;
+#[cfg(feature = "metrics")]
+use crate::sync::lock_recover;
+use crate::time::{Time, TimeSource};
+
+#[cfg(feature = "metrics")]
+use super::metrics;
+use super::Epoch;
+
+// Reports the metrics of a coordinator or participant to the sink it was given, if any. Without
+// the `metrics` feature, every method does nothing.
+//
+// The metrics are not persisted with the context; in particular, the start of each coordinator
+// epoch is kept in memory, so the latency of an epoch started before a restart is not reported.
+pub(super) struct Meter<P, T> {
+    #[cfg(feature = "metrics")]
+    sink: Option<Arc<dyn MetricsSink + Send + Sync>>,
+    // The time at which each coordinator started its current epoch.
+    #[cfg(feature = "metrics")]
+    epoch_starts: Mutex<Vec<(P, Epoch, T)>>,
+    _phantom: PhantomData<fn() -> (P, T)>,
+}
+
+impl<P, T> Meter<P, T>
+where
+    P: Process,
+    T: Time,
+{
+    pub fn disabled() -> Self {
+        Meter {
+            #[cfg(feature = "metrics")]
+            sink: None,
+            #[cfg(feature = "metrics")]
+            epoch_starts: Mutex::new(Vec::new()),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    pub fn new(sink: Arc<dyn MetricsSink + Send + Sync>) -> Self {
+        Meter {
+            sink: Some(sink),
+            epoch_starts: Mutex::new(Vec::new()),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn committed(&self) {
+        #[cfg(feature = "metrics")]
+        self.count(metrics::COMMITS, 1);
+    }
+
+    pub fn aborted(&self) {
+        #[cfg(feature = "metrics")]
+        self.count(metrics::ABORTS, 1);
+    }
+
+    pub fn vote_timeout(&self) {
+        #[cfg(feature = "metrics")]
+        self.count(metrics::VOTE_TIMEOUTS, 1);
+    }
+
+    pub fn decision_ack_timeout(&self) {
+        #[cfg(feature = "metrics")]
+        self.count(metrics::DECISION_ACK_TIMEOUTS, 1);
+    }
+
+    pub fn decision_timeout(&self) {
+        #[cfg(feature = "metrics")]
+        self.count(metrics::DECISION_TIMEOUTS, 1);
+    }
+
+    pub fn decision_requests_sent(&self, count: u64) {
+        #[cfg(feature = "metrics")]
+        self.count(metrics::DECISION_REQUESTS_SENT, count);
+    }
+
+    pub fn decision_request_received(&self) {
+        #[cfg(feature = "metrics")]
+        self.count(metrics::DECISION_REQUESTS_RECEIVED, 1);
+    }
+
+    pub fn message_dropped(&self) {
+        #[cfg(feature = "metrics")]
+        self.count(metrics::MESSAGES_DROPPED, 1);
+    }
+
+    // Record the latency of a participant's vote, from the start of the epoch at `start`.
+    pub fn vote_received<TS>(&self, start: &T, time_source: &TS)
+    where
+        TS: TimeSource<Time = T>,
+    {
+        #[cfg(feature = "metrics")]
+        self.record_latency(metrics::VOTE_LATENCY, start, time_source);
+    }
+
+    // Record the latency of a decision, from the participant's vote or last decision request at
+    // `start`.
+    pub fn decision_received<TS>(&self, start: &T, time_source: &TS)
+    where
+        TS: TimeSource<Time = T>,
+    {
+        #[cfg(feature = "metrics")]
+        self.record_latency(metrics::DECISION_LATENCY, start, time_source);
+    }
+
+    pub fn epoch_started(&self, process: &P, epoch: Epoch, start: T) {
+        #[cfg(feature = "metrics")]
+        if self.sink.is_some() {
+            let mut epoch_starts = lock_recover(&self.epoch_starts);
+            epoch_starts.retain(|(p, _, _)| p != process);
+            epoch_starts.push((process.clone(), epoch, start));
+        }
+    }
+
+    // Record the latency of the coordinator's epoch, if it was started by this algorithm.
+    pub fn epoch_decided<TS>(&self, process: &P, epoch: Epoch, time_source: &TS)
+    where
+        TS: TimeSource<Time = T>,
+    {
+        #[cfg(feature = "metrics")]
+        {
+            let start = {
+                let mut epoch_starts = lock_recover(&self.epoch_starts);
+                let index = epoch_starts
+                    .iter()
+                    .position(|(p, e, _)| p == process && *e == epoch);
+                index.map(|index| epoch_starts.swap_remove(index).2)
+            };
+            if let Some(start) = start {
+                self.record_latency(metrics::EPOCH_LATENCY, &start, time_source);
+            }
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    fn count(&self, name: &'static str, value: u64) {
+        if let Some(sink) = &self.sink {
+            sink.increment_counter(name, value);
+        }
+    }
+
+    // Record the time elapsed since `start` in the named histogram, if the time source is able to
+    // measure it.
+    #[cfg(feature = "metrics")]
+    fn record_latency<TS>(&self, name: &'static str, start: &T, time_source: &TS)
+    where
+        TS: TimeSource<Time = T>,
+    {
+        if let Some(sink) = &self.sink {
+            if let Some(elapsed) = time_source.now().duration_since(start) {
+                sink.record_duration(name, elapsed);
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Names of the metrics reported by [`TwoPhaseCommitAlgorithm`].
+//!
+//! Metrics are reported to the [`MetricsSink`] given to
+//! [`TwoPhaseCommitAlgorithm::with_metrics`]. Latencies are measured using the algorithm's
+//! [`TimeSource`] and are only reported if its [`Time`] implements
+//! [`Time::duration_since`](crate::Time::duration_since). The metrics are reported as the
+//! coordinator and participants process events, and are not persisted with the context; in
+//! particular, the start of each coordinator epoch is kept in memory, and the latency of an epoch
+//! which was started before a restart is not reported.
+//!
+//! The abort rate may be calculated as `ABORTS / (COMMITS + ABORTS)`.
+//!
+//! [`MetricsSink`]: crate::metrics::MetricsSink
+//! [`Time`]: crate::Time
+//! [`TimeSource`]: crate::TimeSource
+//! [`TwoPhaseCommitAlgorithm`]: super::TwoPhaseCommitAlgorithm
+//! [`TwoPhaseCommitAlgorithm::with_metrics`]: super::TwoPhaseCommitAlgorithm::with_metrics
+
+/// Histogram of the time from the coordinator starting an epoch until it decides. Reported by the
+/// coordinator only.
+pub const EPOCH_LATENCY: &str = "two_phase_commit.epoch_latency";
+
+/// Histogram of the time from the coordinator starting an epoch until it receives each
+/// participant's vote. Reported by the coordinator only.
+pub const VOTE_LATENCY: &str = "two_phase_commit.vote_latency";
+
+/// Histogram of the time from a participant voting yes (or last requesting the decision) until it
+/// learns the decision. Reported by participants only.
+pub const DECISION_LATENCY: &str = "two_phase_commit.decision_latency";
+
+/// Counter of commit decisions.
+pub const COMMITS: &str = "two_phase_commit.commits";
+
+/// Counter of abort decisions.
+pub const ABORTS: &str = "two_phase_commit.aborts";
+
+/// Counter of timeouts while the coordinator waits for votes.
+pub const VOTE_TIMEOUTS: &str = "two_phase_commit.vote_timeouts";
+
+/// Counter of timeouts while the coordinator waits for decision acks.
+pub const DECISION_ACK_TIMEOUTS: &str = "two_phase_commit.decision_ack_timeouts";
+
+/// Counter of timeouts while a participant waits for a decision.
+pub const DECISION_TIMEOUTS: &str = "two_phase_commit.decision_timeouts";
+
+/// Counter of `DecisionRequest` messages sent.
+pub const DECISION_REQUESTS_SENT: &str = "two_phase_commit.decision_requests_sent";
+
+/// Counter of `DecisionRequest` messages received.
+pub const DECISION_REQUESTS_RECEIVED: &str = "two_phase_commit.decision_requests_received";
+
+/// Counter of messages dropped (reported via a `MessageDropped` notification).
+pub const MESSAGES_DROPPED: &str = "two_phase_commit.messages_dropped";
@@ -25,6 +25,13 @@ mod coordinator_algorithm;
 mod coordinator_context;
 mod coordinator_event;
 mod coordinator_message;
+#[cfg(feature = "driver")]
+mod drivable;
+#[cfg(feature = "algorithm-checked")]
+mod invariants;
+mod meter;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 mod participant_action;
 mod participant_algorithm;
 mod participant_context;
@@ -45,6 +52,8 @@ pub use coordinator_context::Participant;
 use coordinator_context::{CoordinatorContext, CoordinatorState};
 use coordinator_event::CoordinatorEvent;
 use coordinator_message::CoordinatorMessage;
+#[cfg(feature = "algorithm-checked")]
+pub use invariants::TwoPhaseCommitInvariants;
 use participant_action::{ParticipantAction, ParticipantActionNotification};
 use participant_algorithm::ParticipantAlgorithm;
 use participant_context::{ParticipantContext, ParticipantState};
@@ -52,6 +61,7 @@ use participant_event::ParticipantEvent;
 use participant_message::ParticipantMessage;
 pub use unified_action::{TwoPhaseCommitAction, TwoPhaseCommitActionNotification};
 pub use unified_algorithm::TwoPhaseCommitAlgorithm;
+use unified_context::require_value;
 pub use unified_context::TwoPhaseCommitContext;
 pub use unified_context_builder::TwoPhaseCommitContextBuilder;
 pub use unified_event::TwoPhaseCommitEvent;
@@ -16,6 +16,7 @@ use crate::algorithm::Value;
 use crate::process::Process;
 use crate::time::Time;
 
+use super::Epoch;
 use super::ParticipantContext;
 use super::TwoPhaseCommitContext;
 use super::TwoPhaseCommitMessage;
@@ -30,14 +31,17 @@ where
     Notify(ParticipantActionNotification<V>),
     SendMessage(P, TwoPhaseCommitMessage<V>),
     Update {
-        context: TwoPhaseCommitContext<P, T, ParticipantContext<P, T>>,
+        context: TwoPhaseCommitContext<P, V, T, ParticipantContext<P, V, T>>,
         alarm: Option<T>,
     },
 }
 
-pub enum ParticipantActionNotification<V> {
-    Abort(),
-    Commit(),
+pub enum ParticipantActionNotification<V>
+where
+    V: Value,
+{
+    Abort(Epoch, V),
+    Commit(Epoch, V),
     MessageDropped(String),
     RequestForVote(V),
 }
@@ -66,8 +70,12 @@ where
 {
     fn from(notification: ParticipantActionNotification<V>) -> Self {
         match notification {
-            ParticipantActionNotification::Abort() => TwoPhaseCommitActionNotification::Abort(),
-            ParticipantActionNotification::Commit() => TwoPhaseCommitActionNotification::Commit(),
+            ParticipantActionNotification::Abort(epoch, value) => {
+                TwoPhaseCommitActionNotification::Abort(epoch, value)
+            }
+            ParticipantActionNotification::Commit(epoch, value) => {
+                TwoPhaseCommitActionNotification::Commit(epoch, value)
+            }
             ParticipantActionNotification::MessageDropped(s) => {
                 TwoPhaseCommitActionNotification::MessageDropped(s)
             }
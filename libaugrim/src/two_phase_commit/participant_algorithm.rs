@@ -21,6 +21,8 @@ use crate::process::Process;
 use crate::time::TimeSource;
 use crate::two_phase_commit::Epoch;
 
+use super::meter::Meter;
+use super::require_value;
 use super::ParticipantAction;
 use super::ParticipantActionNotification;
 use super::ParticipantContext;
@@ -32,6 +34,9 @@ use super::TwoPhaseCommitMessage;
 
 const DECISION_TIMEOUT_SECONDS: u64 = 30;
 
+// The unified context, specialized to the participant role.
+type Context<P, V, T> = TwoPhaseCommitContext<P, V, T, ParticipantContext<P, V, T>>;
+
 pub struct ParticipantAlgorithm<P, V, TS>
 where
     P: Process,
@@ -41,6 +46,7 @@ where
     _process_phantom: PhantomData<P>,
     _value_phantom: PhantomData<V>,
     time_source: TS,
+    meter: Meter<P, TS::Time>,
 }
 
 impl<P, V, TS> ParticipantAlgorithm<P, V, TS>
@@ -50,24 +56,36 @@ where
     TS: TimeSource,
 {
     pub fn new(time_source: TS) -> Self {
+        Self::with_meter(time_source, Meter::disabled())
+    }
+
+    pub fn with_meter(time_source: TS, meter: Meter<P, TS::Time>) -> Self {
         ParticipantAlgorithm {
             _process_phantom: PhantomData,
             _value_phantom: PhantomData,
             time_source,
+            meter,
         }
     }
 
+    // Create the actions for a dropped message, which is reported via a notification.
+    fn message_dropped(&self, reason: String) -> Vec<ParticipantAction<P, V, TS::Time>> {
+        self.meter.message_dropped();
+        vec![ParticipantAction::Notify(
+            ParticipantActionNotification::MessageDropped(reason),
+        )]
+    }
+
     // Create actions for advancing to the next epoch. This set of actions is generated whenever
     // a decision has been reached, either abort or commit.
     fn push_advance_epoch_actions(
         &self,
-        context: &mut TwoPhaseCommitContext<P, TS::Time, ParticipantContext<P, TS::Time>>,
+        context: &mut Context<P, V, TS::Time>,
         actions: &mut Vec<ParticipantAction<P, V, TS::Time>>,
         epoch: Epoch,
     ) {
-        // Update the epoch and set the state to WaitingForStart. Also update the last commit epoch
-        // used to answer DecisionRequest messages.
-        context.set_last_commit_epoch(Some(*context.epoch()));
+        // Update the epoch. The last commit epoch used to answer DecisionRequest messages is
+        // updated when a commit is received, as the previous epoch may have been aborted.
         context.set_epoch(epoch);
         actions.push(ParticipantAction::Update {
             context: context.clone(),
@@ -84,7 +102,7 @@ where
 {
     type Event = ParticipantEvent<P, V>;
     type Action = ParticipantAction<P, V, TS::Time>;
-    type Context = TwoPhaseCommitContext<P, TS::Time, ParticipantContext<P, TS::Time>>;
+    type Context = Context<P, V, TS::Time>;
 
     fn event(
         &self,
@@ -119,6 +137,8 @@ where
                     if self.time_source.now()
                         > *decision_timeout_start + Duration::from_secs(DECISION_TIMEOUT_SECONDS)
                     {
+                        self.meter.decision_timeout();
+
                         // Send a Decision Request to all other participant processes
                         for process in context
                             .participant_processes()
@@ -136,6 +156,7 @@ where
                             context.coordinator().clone(),
                             TwoPhaseCommitMessage::DecisionRequest(*context.epoch()),
                         ));
+                        self.meter.decision_requests_sent(actions.len() as u64);
 
                         // Calculate new decision timeout start/end.
                         let new_decision_timeout_start = self.time_source.now();
@@ -177,32 +198,24 @@ where
             ParticipantEvent::Deliver(process, ParticipantMessage::VoteRequest(epoch, value)) => {
                 // A VoteRequest must come from the coordinator, drop it otherwise.
                 if *context.coordinator() != process {
-                    return Ok(vec![ParticipantAction::Notify(
-                        ParticipantActionNotification::MessageDropped(
-                            "sender process is not the coordinator".into(),
-                        ),
-                    )]);
+                    return Ok(self.message_dropped("sender process is not the coordinator".into()));
                 }
 
                 // A VoteRequest can only be processed when we are waiting for one, drop it
                 // otherwise.
                 if !matches!(context.state(), ParticipantState::WaitingForVoteRequest) {
-                    return Ok(vec![ParticipantAction::Notify(
-                        ParticipantActionNotification::MessageDropped(
-                            "context state is not WaitingForVoteRequest".into(),
-                        ),
-                    )]);
+                    return Ok(
+                        self.message_dropped("context state is not WaitingForVoteRequest".into())
+                    );
                 }
 
                 // New epoch must be greater than the previous epoch.
                 if *context.epoch() >= epoch {
-                    return Ok(vec![ParticipantAction::Notify(
-                        ParticipantActionNotification::MessageDropped(format!(
-                            "epoch {} was not greater than previous epoch {}",
-                            epoch,
-                            *context.epoch()
-                        )),
-                    )]);
+                    return Ok(self.message_dropped(format!(
+                        "epoch {} was not greater than previous epoch {}",
+                        epoch,
+                        *context.epoch()
+                    )));
                 }
 
                 let mut actions = Vec::new();
@@ -210,8 +223,10 @@ where
                 // Advance the epoch to the epoch sent from the coordinator
                 self.push_advance_epoch_actions(&mut context, &mut actions, epoch);
 
-                // Update the context with the new state of WaitingForVote
+                // Update the context with the new state of WaitingForVote, retaining the value so
+                // that the decision notification can report it.
                 context.set_state(ParticipantState::WaitingForVote);
+                context.set_value(Some(value.clone()));
                 actions.push(ParticipantAction::Update {
                     context,
                     alarm: None,
@@ -227,11 +242,7 @@ where
             ParticipantEvent::Deliver(process, ParticipantMessage::Commit(epoch)) => {
                 // A Commit must be for the current epoch to be processed, drop it otherwise.
                 if *context.epoch() != epoch {
-                    return Ok(vec![ParticipantAction::Notify(
-                        ParticipantActionNotification::MessageDropped(
-                            "epoch is not the current epoch".into(),
-                        ),
-                    )]);
+                    return Ok(self.message_dropped("epoch is not the current epoch".into()));
                 }
 
                 if !matches!(
@@ -241,17 +252,27 @@ where
                         decision_timeout_start: _
                     }
                 ) {
-                    return Ok(vec![ParticipantAction::Notify(
-                        ParticipantActionNotification::MessageDropped(
-                            "commit received outside decision window".into(),
-                        ),
-                    )]);
+                    return Ok(
+                        self.message_dropped("commit received outside decision window".into())
+                    );
                 }
 
+                let value = require_value(context.value())?;
+
                 let mut actions = Vec::new();
 
-                // The vote was no, so record our decision to Abort.
+                if let ParticipantState::Voted {
+                    decision_timeout_start,
+                    ..
+                } = context.state()
+                {
+                    self.meter
+                        .decision_received(decision_timeout_start, &self.time_source);
+                }
+
+                // Record our decision to Commit, and the epoch in which it was made.
                 context.set_state(ParticipantState::Commit);
+                context.set_last_commit_epoch(Some(epoch));
                 actions.push(ParticipantAction::Update {
                     context: context.clone(),
                     alarm: None,
@@ -259,8 +280,9 @@ where
 
                 // Notify that we've committed.
                 actions.push(ParticipantAction::Notify(
-                    ParticipantActionNotification::Commit(),
+                    ParticipantActionNotification::Commit(epoch, value),
                 ));
+                self.meter.committed();
 
                 // Send an acknowledgement back to the coordinator.
                 actions.push(ParticipantAction::SendMessage(
@@ -270,6 +292,7 @@ where
 
                 // Switch to WaitingForVoteRequest to prepare for the next epoch
                 context.set_state(ParticipantState::WaitingForVoteRequest);
+                context.set_value(None);
                 actions.push(ParticipantAction::Update {
                     context: context.clone(),
                     alarm: None,
@@ -280,11 +303,7 @@ where
             ParticipantEvent::Deliver(_process, ParticipantMessage::Abort(epoch)) => {
                 // An Abort must be for the current epoch to be processed, drop it otherwise.
                 if *context.epoch() != epoch {
-                    return Ok(vec![ParticipantAction::Notify(
-                        ParticipantActionNotification::MessageDropped(
-                            "epoch is not the current epoch".into(),
-                        ),
-                    )]);
+                    return Ok(self.message_dropped("epoch is not the current epoch".into()));
                 }
 
                 if !matches!(
@@ -294,15 +313,24 @@ where
                         decision_timeout_start: _
                     }
                 ) {
-                    return Ok(vec![ParticipantAction::Notify(
-                        ParticipantActionNotification::MessageDropped(
-                            "abort received outside decision window".into(),
-                        ),
-                    )]);
+                    return Ok(
+                        self.message_dropped("abort received outside decision window".into())
+                    );
                 }
 
+                let value = require_value(context.value())?;
+
                 let mut actions = Vec::new();
 
+                if let ParticipantState::Voted {
+                    decision_timeout_start,
+                    ..
+                } = context.state()
+                {
+                    self.meter
+                        .decision_received(decision_timeout_start, &self.time_source);
+                }
+
                 // The vote was no, so record our decision to Abort.
                 context.set_state(ParticipantState::Abort);
                 actions.push(ParticipantAction::Update {
@@ -312,11 +340,13 @@ where
 
                 // Notify that we've aborted.
                 actions.push(ParticipantAction::Notify(
-                    ParticipantActionNotification::Abort(),
+                    ParticipantActionNotification::Abort(epoch, value),
                 ));
+                self.meter.aborted();
 
                 // Switch to WaitingForVoteRequest to prepare for the next epoch
                 context.set_state(ParticipantState::WaitingForVoteRequest);
+                context.set_value(None);
                 actions.push(ParticipantAction::Update {
                     context: context.clone(),
                     alarm: None,
@@ -326,15 +356,15 @@ where
             }
 
             ParticipantEvent::Deliver(process, ParticipantMessage::DecisionRequest(epoch)) => {
+                self.meter.decision_request_received();
+
                 // The sender must be a coordinator or participant.
                 if !(context.participant_processes().contains(&process)
                     && *context.coordinator() != process)
                 {
-                    return Ok(vec![ParticipantAction::Notify(
-                        ParticipantActionNotification::MessageDropped(
-                            "sender process is not a coordinator or participant".into(),
-                        ),
-                    )]);
+                    return Ok(self.message_dropped(
+                        "sender process is not a coordinator or participant".into(),
+                    ));
                 }
 
                 // We record the last commit epoch in the context; if the epoch requested was the
@@ -367,11 +397,7 @@ where
                 // If the epoch is after our current epoch, we ignore the message as we do not yet
                 // know what the future holds. Similarly, we do not yet have a decision for the
                 // current epoch or we would have advanced to the next epoch already.
-                Ok(vec![ParticipantAction::Notify(
-                    ParticipantActionNotification::MessageDropped(
-                        "decision for requested epoch is unknown".into(),
-                    ),
-                )])
+                Ok(self.message_dropped("decision for requested epoch is unknown".into()))
             }
 
             // In response to a RequestForVote, a Vote message contains either true (vote yes) or
@@ -407,6 +433,8 @@ where
                         alarm: Some(decision_timeout_end),
                     });
                 } else {
+                    let value = require_value(context.value())?;
+
                     // The vote was no, so record our decision to Abort.
                     context.set_state(ParticipantState::Abort);
                     actions.push(ParticipantAction::Update {
@@ -416,11 +444,13 @@ where
 
                     // Notify that we've aborted.
                     actions.push(ParticipantAction::Notify(
-                        ParticipantActionNotification::Abort(),
+                        ParticipantActionNotification::Abort(*context.epoch(), value),
                     ));
+                    self.meter.aborted();
 
                     // Switch to WaitingForVoteRequest to prepare for the next epoch
                     context.set_state(ParticipantState::WaitingForVoteRequest);
+                    context.set_value(None);
                     actions.push(ParticipantAction::Update {
                         context: context.clone(),
                         alarm: None,
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::algorithm::Value;
 use crate::process::Process;
 use crate::time::Time;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ParticipantState<T>
 where
     T: Time,
@@ -31,11 +33,14 @@ where
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ParticipantContext<P, T>
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ParticipantContext<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     pub(super) participant_processes: Vec<P>,
     pub(super) state: ParticipantState<T>,
+    pub(super) value: Option<V>,
 }
@@ -16,10 +16,12 @@ use crate::algorithm::Value;
 use crate::process::Process;
 use crate::time::Time;
 
+use super::Epoch;
 use super::TwoPhaseCommitContext;
 use super::TwoPhaseCommitMessage;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum TwoPhaseCommitAction<P, V, T>
 where
     P: Process,
@@ -27,20 +29,21 @@ where
     T: Time,
 {
     Update {
-        context: TwoPhaseCommitContext<P, T>,
+        context: TwoPhaseCommitContext<P, V, T>,
         alarm: Option<T>,
     },
     SendMessage(P, TwoPhaseCommitMessage<V>),
     Notify(TwoPhaseCommitActionNotification<V>),
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum TwoPhaseCommitActionNotification<V>
 where
     V: Value,
 {
-    Abort(),
-    Commit(),
+    Abort(Epoch, V),
+    Commit(Epoch, V),
     MessageDropped(String),
     RequestForStart(),
     CoordinatorRequestForVote(),
@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "metrics")]
+use std::sync::Arc;
+
 use crate::algorithm::{Algorithm, Value};
 use crate::error::AlgorithmError;
+#[cfg(feature = "metrics")]
+use crate::metrics::MetricsSink;
 use crate::process::Process;
 use crate::time::TimeSource;
 
+#[cfg(feature = "metrics")]
+use super::meter::Meter;
 use super::CoordinatorAlgorithm;
 use super::ParticipantAlgorithm;
 use super::TwoPhaseCommitAction;
@@ -45,6 +52,23 @@ where
             participant: ParticipantAlgorithm::new(time_source),
         }
     }
+
+    /// Create an algorithm which reports metrics to the given sink. See
+    /// [`two_phase_commit::metrics`](super::metrics) for the metrics reported.
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics<M>(time_source: TS, metrics: M) -> Self
+    where
+        M: MetricsSink + Send + Sync + 'static,
+    {
+        let metrics: Arc<dyn MetricsSink + Send + Sync> = Arc::new(metrics);
+        Self {
+            coordinator: CoordinatorAlgorithm::with_meter(
+                time_source.clone(),
+                Meter::new(metrics.clone()),
+            ),
+            participant: ParticipantAlgorithm::with_meter(time_source, Meter::new(metrics)),
+        }
+    }
 }
 
 impl<P, V, TS> Algorithm for TwoPhaseCommitAlgorithm<P, V, TS>
@@ -55,7 +79,7 @@ where
 {
     type Event = TwoPhaseCommitEvent<P, V>;
     type Action = TwoPhaseCommitAction<P, V, TS::Time>;
-    type Context = TwoPhaseCommitContext<P, TS::Time>;
+    type Context = TwoPhaseCommitContext<P, V, TS::Time>;
 
     fn event(
         &self,
@@ -73,3 +97,467 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    #[cfg(feature = "metrics")]
+    use crate::metrics::InMemoryMetrics;
+    use crate::time::{ManualTimeSource, SystemTimeFactory};
+    #[cfg(feature = "metrics")]
+    use crate::two_phase_commit::metrics;
+    use crate::two_phase_commit::{
+        Participant, TwoPhaseCommitActionNotification, TwoPhaseCommitContextBuilder,
+        TwoPhaseCommitMessage, TwoPhaseCommitState,
+    };
+
+    use super::*;
+
+    type Context = TwoPhaseCommitContext<String, String, SystemTime>;
+    type Action = TwoPhaseCommitAction<String, String, SystemTime>;
+
+    fn last_context(actions: &[Action]) -> Context {
+        actions
+            .iter()
+            .rev()
+            .find_map(|action| match action {
+                TwoPhaseCommitAction::Update { context, .. } => Some(context.clone()),
+                _ => None,
+            })
+            .expect("no update action")
+    }
+
+    fn notifications(actions: Vec<Action>) -> Vec<TwoPhaseCommitActionNotification<String>> {
+        actions
+            .into_iter()
+            .filter_map(|action| match action {
+                TwoPhaseCommitAction::Notify(n) => Some(n),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Test that the coordinator's commit notification contains the epoch and the value provided
+    /// by the Start event.
+    #[test]
+    fn test_coordinator_commit_notification() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(3)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![Participant::new("p1".to_string())])
+            .build()?;
+
+        let actions = algorithm.event(TwoPhaseCommitEvent::Start("v".to_string()), context)?;
+        let context = last_context(&actions);
+        assert_eq!(context.value(), Some(&"v".to_string()));
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver("p1".into(), TwoPhaseCommitMessage::VoteResponse(3, true)),
+            context,
+        )?;
+        let context = last_context(&actions);
+
+        let actions = algorithm.event(TwoPhaseCommitEvent::Vote(true), context)?;
+        assert_eq!(
+            notifications(actions),
+            vec![TwoPhaseCommitActionNotification::Commit(3, "v".into())]
+        );
+
+        Ok(())
+    }
+
+    /// Test that the coordinator records the epoch of a commit as soon as it commits, and answers
+    /// a DecisionRequest for that epoch with Commit, both while waiting for decision acks and
+    /// after the ack timeout advances the epoch.
+    #[test]
+    fn test_coordinator_decision_request_after_commit() -> Result<(), Box<dyn std::error::Error>> {
+        let time = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let algorithm = TwoPhaseCommitAlgorithm::new(time.clone());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![
+                Participant::new("p1".to_string()),
+                Participant::new("p2".to_string()),
+            ])
+            .build()?;
+
+        let mut actions = algorithm.event(TwoPhaseCommitEvent::Start("v".to_string()), context)?;
+        for process in ["p1", "p2"] {
+            actions = algorithm.event(
+                TwoPhaseCommitEvent::Deliver(
+                    process.into(),
+                    TwoPhaseCommitMessage::VoteResponse(1, true),
+                ),
+                last_context(&actions),
+            )?;
+        }
+        let actions = algorithm.event(TwoPhaseCommitEvent::Vote(true), last_context(&actions))?;
+        let context = last_context(&actions);
+        assert_eq!(*context.last_commit_epoch(), Some(1));
+
+        let decision_request =
+            TwoPhaseCommitEvent::Deliver("p1".into(), TwoPhaseCommitMessage::DecisionRequest(1));
+        let commit = vec![TwoPhaseCommitAction::SendMessage(
+            "p1".into(),
+            TwoPhaseCommitMessage::Commit(1),
+        )];
+        assert_eq!(
+            algorithm.event(decision_request.clone(), context.clone())?,
+            commit
+        );
+
+        time.advance(Duration::from_secs(60));
+        let actions = algorithm.event(TwoPhaseCommitEvent::Alarm(), context)?;
+        let context = last_context(&actions);
+        assert_eq!(*context.epoch(), 2);
+        assert_eq!(*context.last_commit_epoch(), Some(1));
+        assert_eq!(algorithm.event(decision_request, context)?, commit);
+
+        Ok(())
+    }
+
+    /// Test that the coordinator rejects a Start event unless it is waiting for one, rather
+    /// than reopening voting on an epoch which has already been decided.
+    #[test]
+    fn test_coordinator_start_when_decided() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::Abort)
+            .with_this_process("c".to_string())
+            .with_participants(vec![Participant::new("p1".to_string())])
+            .build()?;
+
+        assert!(matches!(
+            algorithm.event(TwoPhaseCommitEvent::Start("v".to_string()), context),
+            Err(AlgorithmError::InvalidState(_))
+        ));
+
+        Ok(())
+    }
+
+    /// Test that a second Start event while the coordinator is voting is rejected, leaving the
+    /// vote in progress to decide the epoch.
+    #[test]
+    fn test_coordinator_start_when_voting() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![Participant::new("p1".to_string())])
+            .build()?;
+
+        let actions = algorithm.event(TwoPhaseCommitEvent::Start("v".to_string()), context)?;
+        let context = last_context(&actions);
+        assert!(matches!(
+            context.state(),
+            TwoPhaseCommitState::Voting { .. }
+        ));
+
+        assert!(matches!(
+            algorithm.event(TwoPhaseCommitEvent::Start("w".to_string()), context),
+            Err(AlgorithmError::InvalidState(_))
+        ));
+
+        Ok(())
+    }
+
+    /// Test that the participant's abort notification contains the epoch and the value provided
+    /// by the coordinator's VoteRequest.
+    #[test]
+    fn test_participant_abort_notification() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForVoteRequest)
+            .with_this_process("p1".to_string())
+            .with_participant_processes(vec!["p1".to_string()])
+            .build()?;
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver(
+                "c".into(),
+                TwoPhaseCommitMessage::VoteRequest(2, "v".into()),
+            ),
+            context,
+        )?;
+        let context = last_context(&actions);
+
+        let actions = algorithm.event(TwoPhaseCommitEvent::Vote(false), context)?;
+        assert_eq!(last_context(&actions).value(), None);
+        assert_eq!(
+            notifications(actions),
+            vec![TwoPhaseCommitActionNotification::Abort(2, "v".into())]
+        );
+
+        Ok(())
+    }
+
+    /// Test that a participant which voted no in an epoch answers a later DecisionRequest for
+    /// that epoch with Abort, rather than reporting it as committed once the next epoch starts.
+    #[test]
+    fn test_participant_decision_request_after_abort() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(0)
+            .with_state(TwoPhaseCommitState::WaitingForVoteRequest)
+            .with_this_process("p1".to_string())
+            .with_participant_processes(vec!["p1".to_string(), "p2".to_string()])
+            .build()?;
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver(
+                "c".into(),
+                TwoPhaseCommitMessage::VoteRequest(1, "v".into()),
+            ),
+            context,
+        )?;
+        let actions = algorithm.event(TwoPhaseCommitEvent::Vote(false), last_context(&actions))?;
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver(
+                "c".into(),
+                TwoPhaseCommitMessage::VoteRequest(2, "w".into()),
+            ),
+            last_context(&actions),
+        )?;
+        let context = last_context(&actions);
+        assert_eq!(*context.last_commit_epoch(), None);
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver("p2".into(), TwoPhaseCommitMessage::DecisionRequest(1)),
+            context,
+        )?;
+        assert_eq!(
+            actions,
+            vec![TwoPhaseCommitAction::SendMessage(
+                "p2".into(),
+                TwoPhaseCommitMessage::Abort(1)
+            )]
+        );
+
+        Ok(())
+    }
+
+    /// Test that a participant records the epoch of a commit as soon as it commits, and answers
+    /// a DecisionRequest for that epoch with Commit, both before and after the next epoch starts.
+    #[test]
+    fn test_participant_decision_request_after_commit() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(0)
+            .with_state(TwoPhaseCommitState::WaitingForVoteRequest)
+            .with_this_process("p1".to_string())
+            .with_participant_processes(vec!["p1".to_string(), "p2".to_string()])
+            .build()?;
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver(
+                "c".into(),
+                TwoPhaseCommitMessage::VoteRequest(1, "v".into()),
+            ),
+            context,
+        )?;
+        let actions = algorithm.event(TwoPhaseCommitEvent::Vote(true), last_context(&actions))?;
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver("c".into(), TwoPhaseCommitMessage::Commit(1)),
+            last_context(&actions),
+        )?;
+        let context = last_context(&actions);
+        assert_eq!(*context.last_commit_epoch(), Some(1));
+
+        let decision_request =
+            TwoPhaseCommitEvent::Deliver("p2".into(), TwoPhaseCommitMessage::DecisionRequest(1));
+        let commit = vec![TwoPhaseCommitAction::SendMessage(
+            "p2".into(),
+            TwoPhaseCommitMessage::Commit(1),
+        )];
+        assert_eq!(
+            algorithm.event(decision_request.clone(), context.clone())?,
+            commit
+        );
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver(
+                "c".into(),
+                TwoPhaseCommitMessage::VoteRequest(2, "w".into()),
+            ),
+            context,
+        )?;
+        let context = last_context(&actions);
+        assert_eq!(*context.last_commit_epoch(), Some(1));
+        assert_eq!(algorithm.event(decision_request, context)?, commit);
+
+        Ok(())
+    }
+
+    /// Test that the coordinator of several participants answers a DecisionRequest from any of
+    /// them, and drops one from a process which is not a participant.
+    #[test]
+    fn test_coordinator_decision_request() -> Result<(), Box<dyn std::error::Error>> {
+        let algorithm = TwoPhaseCommitAlgorithm::new(SystemTimeFactory::new());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(3)
+            .with_last_commit_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![
+                Participant::new("p1".to_string()),
+                Participant::new("p2".to_string()),
+            ])
+            .build()?;
+
+        for (process, epoch, message) in [
+            ("p1", 1, TwoPhaseCommitMessage::Commit(1)),
+            ("p2", 2, TwoPhaseCommitMessage::Abort(2)),
+        ] {
+            assert_eq!(
+                algorithm.event(
+                    TwoPhaseCommitEvent::Deliver(
+                        process.into(),
+                        TwoPhaseCommitMessage::DecisionRequest(epoch)
+                    ),
+                    context.clone(),
+                )?,
+                vec![TwoPhaseCommitAction::SendMessage(process.into(), message)]
+            );
+        }
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver("x".into(), TwoPhaseCommitMessage::DecisionRequest(2)),
+            context,
+        )?;
+        assert_eq!(
+            notifications(actions),
+            vec![TwoPhaseCommitActionNotification::MessageDropped(
+                "sender process is not a participant".into()
+            )]
+        );
+
+        Ok(())
+    }
+
+    /// Test that the coordinator reports decision, latency and dropped message metrics.
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_coordinator_metrics() -> Result<(), Box<dyn std::error::Error>> {
+        let time = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let metrics = InMemoryMetrics::new();
+        let algorithm = TwoPhaseCommitAlgorithm::with_metrics(time.clone(), metrics.clone());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![Participant::new("p1".to_string())])
+            .build()?;
+
+        let actions = algorithm.event(TwoPhaseCommitEvent::Start("v".to_string()), context)?;
+        let context = last_context(&actions);
+        time.advance(Duration::from_secs(1));
+
+        // A vote from an unknown process is dropped.
+        algorithm.event(
+            TwoPhaseCommitEvent::Deliver("p2".into(), TwoPhaseCommitMessage::VoteResponse(1, true)),
+            context.clone(),
+        )?;
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver("p1".into(), TwoPhaseCommitMessage::VoteResponse(1, true)),
+            context,
+        )?;
+        let context = last_context(&actions);
+        time.advance(Duration::from_secs(1));
+        algorithm.event(TwoPhaseCommitEvent::Vote(false), context)?;
+
+        assert_eq!(metrics.counter(metrics::ABORTS), 1);
+        assert_eq!(metrics.counter(metrics::COMMITS), 0);
+        assert_eq!(metrics.counter(metrics::MESSAGES_DROPPED), 1);
+        assert_eq!(
+            metrics
+                .histogram(metrics::VOTE_LATENCY)
+                .and_then(|h| h.max()),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            metrics
+                .histogram(metrics::EPOCH_LATENCY)
+                .and_then(|h| h.max()),
+            Some(Duration::from_secs(2))
+        );
+
+        Ok(())
+    }
+
+    /// Test that a participant reports its decision timeouts, the decision requests it sends and
+    /// the latency of the decision.
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_participant_metrics() -> Result<(), Box<dyn std::error::Error>> {
+        let time = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let metrics = InMemoryMetrics::new();
+        let algorithm = TwoPhaseCommitAlgorithm::with_metrics(time.clone(), metrics.clone());
+
+        let context = TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(0)
+            .with_state(TwoPhaseCommitState::WaitingForVoteRequest)
+            .with_this_process("p1".to_string())
+            .with_participant_processes(vec!["p1".to_string(), "p2".to_string()])
+            .build()?;
+
+        let actions = algorithm.event(
+            TwoPhaseCommitEvent::Deliver(
+                "c".into(),
+                TwoPhaseCommitMessage::VoteRequest(1, "v".to_string()),
+            ),
+            context,
+        )?;
+        let context = last_context(&actions);
+        let actions = algorithm.event(TwoPhaseCommitEvent::Vote(true), context)?;
+        let context = last_context(&actions);
+
+        time.advance(Duration::from_secs(60));
+        let actions = algorithm.event(TwoPhaseCommitEvent::Alarm(), context)?;
+        let context = last_context(&actions);
+        time.advance(Duration::from_secs(1));
+        algorithm.event(
+            TwoPhaseCommitEvent::Deliver("c".into(), TwoPhaseCommitMessage::Commit(1)),
+            context,
+        )?;
+
+        assert_eq!(metrics.counter(metrics::DECISION_TIMEOUTS), 1);
+        assert_eq!(metrics.counter(metrics::DECISION_REQUESTS_SENT), 2);
+        assert_eq!(metrics.counter(metrics::COMMITS), 1);
+        assert_eq!(
+            metrics
+                .histogram(metrics::DECISION_LATENCY)
+                .and_then(|h| h.max()),
+            Some(Duration::from_secs(1))
+        );
+
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::marker::PhantomData;
 
+use crate::algorithm::Value;
 use crate::error::InvalidStateError;
 use crate::process::Process;
 use crate::time::Time;
@@ -25,9 +26,11 @@ use super::{CoordinatorContext, CoordinatorState, Participant};
 use super::{ParticipantContext, ParticipantState};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct TwoPhaseCommitContext<P, T, R = TwoPhaseCommitRoleContext<P, T>>
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TwoPhaseCommitContext<P, V, T, R = TwoPhaseCommitRoleContext<P, V, T>>
 where
     P: Process,
+    V: Value,
     T: Time,
     R: Clone,
 {
@@ -37,11 +40,13 @@ where
     pub(super) role_context: R,
     pub(super) this_process: P,
     pub(super) time_phantom: PhantomData<T>,
+    pub(super) value_phantom: PhantomData<V>,
 }
 
-impl<P, T, R> TwoPhaseCommitContext<P, T, R>
+impl<P, V, T, R> TwoPhaseCommitContext<P, V, T, R>
 where
     P: Process,
+    V: Value,
     T: Time,
     R: Clone,
 {
@@ -57,6 +62,9 @@ where
         self.epoch = epoch
     }
 
+    /// Returns the last epoch in which this process committed, used to answer DecisionRequest
+    /// messages. It is recorded when the commit is decided, so an epoch which aborted is never
+    /// reported as committed.
     pub fn last_commit_epoch(&self) -> &Option<Epoch> {
         &self.last_commit_epoch
     }
@@ -70,9 +78,10 @@ where
     }
 }
 
-impl<P, T> TwoPhaseCommitContext<P, T, TwoPhaseCommitRoleContext<P, T>>
+impl<P, V, T> TwoPhaseCommitContext<P, V, T, TwoPhaseCommitRoleContext<P, V, T>>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     pub fn participants(&self) -> Option<&Vec<Participant<P>>> {
@@ -86,11 +95,16 @@ where
     pub fn state(&self) -> TwoPhaseCommitState<T> {
         self.role_context.state()
     }
+
+    pub fn value(&self) -> Option<&V> {
+        self.role_context.value()
+    }
 }
 
-impl<P, T> TwoPhaseCommitContext<P, T, CoordinatorContext<P, T>>
+impl<P, V, T> TwoPhaseCommitContext<P, V, T, CoordinatorContext<P, V, T>>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     pub(super) fn participants(&self) -> &Vec<Participant<P>> {
@@ -108,11 +122,20 @@ where
     pub(super) fn set_state(&mut self, state: CoordinatorState<T>) {
         self.role_context.state = state;
     }
+
+    pub(super) fn value(&self) -> Option<&V> {
+        self.role_context.value.as_ref()
+    }
+
+    pub(super) fn set_value(&mut self, value: Option<V>) {
+        self.role_context.value = value;
+    }
 }
 
-impl<P, T> TwoPhaseCommitContext<P, T, ParticipantContext<P, T>>
+impl<P, V, T> TwoPhaseCommitContext<P, V, T, ParticipantContext<P, V, T>>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     pub(super) fn participant_processes(&self) -> &Vec<P> {
@@ -126,19 +149,36 @@ where
     pub(super) fn set_state(&mut self, state: ParticipantState<T>) {
         self.role_context.state = state;
     }
+
+    pub(super) fn value(&self) -> Option<&V> {
+        self.role_context.value.as_ref()
+    }
+
+    pub(super) fn set_value(&mut self, value: Option<V>) {
+        self.role_context.value = value;
+    }
+}
+
+// Returns a copy of the value for the current epoch. The value is recorded when the epoch begins,
+// so a missing value means the context was not produced by the algorithm.
+pub(super) fn require_value<V: Value>(value: Option<&V>) -> Result<V, InvalidStateError> {
+    value.cloned().ok_or_else(|| {
+        InvalidStateError::with_message("context has no value for the current epoch".into())
+    })
 }
 
 #[doc(hidden)]
-impl<P, T> TryFrom<TwoPhaseCommitContext<P, T, TwoPhaseCommitRoleContext<P, T>>>
-    for TwoPhaseCommitContext<P, T, CoordinatorContext<P, T>>
+impl<P, V, T> TryFrom<TwoPhaseCommitContext<P, V, T, TwoPhaseCommitRoleContext<P, V, T>>>
+    for TwoPhaseCommitContext<P, V, T, CoordinatorContext<P, V, T>>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     type Error = InvalidStateError;
 
     fn try_from(
-        context: TwoPhaseCommitContext<P, T, TwoPhaseCommitRoleContext<P, T>>,
+        context: TwoPhaseCommitContext<P, V, T, TwoPhaseCommitRoleContext<P, V, T>>,
     ) -> Result<Self, Self::Error> {
         Ok(Self {
             coordinator: context.coordinator,
@@ -147,21 +187,23 @@ where
             role_context: context.role_context.try_into()?,
             this_process: context.this_process,
             time_phantom: PhantomData,
+            value_phantom: PhantomData,
         })
     }
 }
 
 #[doc(hidden)]
-impl<P, T> TryFrom<TwoPhaseCommitContext<P, T, TwoPhaseCommitRoleContext<P, T>>>
-    for TwoPhaseCommitContext<P, T, ParticipantContext<P, T>>
+impl<P, V, T> TryFrom<TwoPhaseCommitContext<P, V, T, TwoPhaseCommitRoleContext<P, V, T>>>
+    for TwoPhaseCommitContext<P, V, T, ParticipantContext<P, V, T>>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     type Error = InvalidStateError;
 
     fn try_from(
-        context: TwoPhaseCommitContext<P, T, TwoPhaseCommitRoleContext<P, T>>,
+        context: TwoPhaseCommitContext<P, V, T, TwoPhaseCommitRoleContext<P, V, T>>,
     ) -> Result<Self, Self::Error> {
         Ok(Self {
             coordinator: context.coordinator,
@@ -170,18 +212,20 @@ where
             role_context: context.role_context.try_into()?,
             this_process: context.this_process,
             time_phantom: PhantomData,
+            value_phantom: PhantomData,
         })
     }
 }
 
 #[doc(hidden)]
-impl<P, T> From<TwoPhaseCommitContext<P, T, CoordinatorContext<P, T>>>
-    for TwoPhaseCommitContext<P, T, TwoPhaseCommitRoleContext<P, T>>
+impl<P, V, T> From<TwoPhaseCommitContext<P, V, T, CoordinatorContext<P, V, T>>>
+    for TwoPhaseCommitContext<P, V, T, TwoPhaseCommitRoleContext<P, V, T>>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
-    fn from(context: TwoPhaseCommitContext<P, T, CoordinatorContext<P, T>>) -> Self {
+    fn from(context: TwoPhaseCommitContext<P, V, T, CoordinatorContext<P, V, T>>) -> Self {
         Self {
             coordinator: context.coordinator,
             epoch: context.epoch,
@@ -189,18 +233,20 @@ where
             role_context: context.role_context.into(),
             this_process: context.this_process,
             time_phantom: PhantomData,
+            value_phantom: PhantomData,
         }
     }
 }
 
 #[doc(hidden)]
-impl<P, T> From<TwoPhaseCommitContext<P, T, ParticipantContext<P, T>>>
-    for TwoPhaseCommitContext<P, T, TwoPhaseCommitRoleContext<P, T>>
+impl<P, V, T> From<TwoPhaseCommitContext<P, V, T, ParticipantContext<P, V, T>>>
+    for TwoPhaseCommitContext<P, V, T, TwoPhaseCommitRoleContext<P, V, T>>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
-    fn from(context: TwoPhaseCommitContext<P, T, ParticipantContext<P, T>>) -> Self {
+    fn from(context: TwoPhaseCommitContext<P, V, T, ParticipantContext<P, V, T>>) -> Self {
         Self {
             coordinator: context.coordinator,
             epoch: context.epoch,
@@ -208,6 +254,7 @@ where
             role_context: context.role_context.into(),
             this_process: context.this_process,
             time_phantom: PhantomData,
+            value_phantom: PhantomData,
         }
     }
 }
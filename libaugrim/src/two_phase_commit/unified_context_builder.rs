@@ -14,6 +14,7 @@
 
 use std::marker::PhantomData;
 
+use crate::algorithm::Value;
 use crate::error::InvalidStateError;
 use crate::process::Process;
 use crate::time::Time;
@@ -25,9 +26,10 @@ use super::TwoPhaseCommitRoleContext;
 use super::TwoPhaseCommitState;
 
 #[derive(Default)]
-pub struct TwoPhaseCommitContextBuilder<P, T>
+pub struct TwoPhaseCommitContextBuilder<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     coordinator: Option<P>,
@@ -38,11 +40,13 @@ where
     state: Option<TwoPhaseCommitState<T>>,
     this_process: Option<P>,
     time_phantom: PhantomData<T>,
+    value: Option<V>,
 }
 
-impl<P, T> TwoPhaseCommitContextBuilder<P, T>
+impl<P, V, T> TwoPhaseCommitContextBuilder<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     pub fn new() -> Self {
@@ -55,6 +59,7 @@ where
             state: None,
             this_process: None,
             time_phantom: PhantomData,
+            value: None,
         }
     }
 
@@ -93,10 +98,12 @@ where
         self
     }
 
-    pub fn build(
-        self,
-    ) -> Result<TwoPhaseCommitContext<P, T, TwoPhaseCommitRoleContext<P, T>>, InvalidStateError>
-    {
+    pub fn with_value(mut self, value: V) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn build(self) -> Result<TwoPhaseCommitContext<P, V, T>, InvalidStateError> {
         let last_commit_epoch = self.last_commit_epoch;
 
         let coordinator = self
@@ -119,10 +126,12 @@ where
             (Some(participants), None) => Ok(TwoPhaseCommitRoleContext::new_coordinator(
                 participants,
                 state,
+                self.value,
             )?),
             (None, Some(participant_processes)) => Ok(TwoPhaseCommitRoleContext::new_participant(
                 participant_processes,
                 state,
+                self.value,
             )?),
             (Some(_), Some(_)) => Err(InvalidStateError::with_message(
                 "participant and participant_processes fields are mutually exclusive".into(),
@@ -139,6 +148,7 @@ where
             role_context,
             this_process,
             time_phantom: self.time_phantom,
+            value_phantom: PhantomData,
         })
     }
 }
@@ -153,12 +163,13 @@ mod tests {
 
     #[test]
     fn build_coordinator_context() {
-        let unified_context = TwoPhaseCommitContextBuilder::<String, SystemTime>::new()
+        let unified_context = TwoPhaseCommitContextBuilder::<String, String, SystemTime>::new()
             .with_coordinator("me".into())
             .with_epoch(2)
             .with_last_commit_epoch(1)
             .with_state(TwoPhaseCommitState::WaitingForStart)
             .with_this_process("me".into())
+            .with_value("value".into())
             .with_participants(vec![
                 Participant::new("me".into()),
                 Participant::new("p1".into()),
@@ -167,7 +178,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let coordinator_context: TwoPhaseCommitContext<_, _, CoordinatorContext<_, _>> =
+        let coordinator_context: TwoPhaseCommitContext<_, _, _, CoordinatorContext<_, _, _>> =
             unified_context.try_into().unwrap();
 
         assert_eq!(*coordinator_context.coordinator(), "me".to_string());
@@ -178,8 +189,9 @@ mod tests {
             CoordinatorState::WaitingForStart
         );
         assert_eq!(coordinator_context.participants().len(), 3);
+        assert_eq!(coordinator_context.value(), Some(&"value".to_string()));
 
-        let reunified_context: TwoPhaseCommitContext<_, _> = coordinator_context.into();
+        let reunified_context: TwoPhaseCommitContext<_, _, _> = coordinator_context.into();
 
         assert_eq!(*reunified_context.coordinator(), "me".to_string());
         assert_eq!(*reunified_context.epoch(), 2);
@@ -189,5 +201,6 @@ mod tests {
             TwoPhaseCommitState::WaitingForStart
         );
         assert_eq!(reunified_context.participants().unwrap().len(), 3);
+        assert_eq!(reunified_context.value(), Some(&"value".to_string()));
     }
 }
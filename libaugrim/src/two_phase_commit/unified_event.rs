@@ -17,6 +17,8 @@ use crate::process::Process;
 
 use super::TwoPhaseCommitMessage;
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum TwoPhaseCommitEvent<P, V>
 where
     P: Process,
@@ -24,6 +26,9 @@ where
 {
     Alarm(),
     Deliver(P, TwoPhaseCommitMessage<V>),
+    /// The value of the coordinator's next epoch, in response to a `RequestForStart`
+    /// notification. A coordinator which is not waiting for a start returns an error, rather
+    /// than voting again on an epoch which may already have been decided.
     Start(V),
     Vote(bool),
 }
@@ -18,6 +18,7 @@ use crate::message::Message;
 use super::Epoch;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum TwoPhaseCommitMessage<V>
 where
     V: Value,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::algorithm::Value;
 use crate::error::InvalidStateError;
 use crate::process::Process;
 use crate::time::Time;
@@ -21,37 +22,44 @@ use super::TwoPhaseCommitState;
 use super::{CoordinatorContext, Participant};
 
 #[derive(Clone, Debug, PartialEq)]
-enum InnerContext<P, T>
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+enum InnerContext<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
-    Coordinator(CoordinatorContext<P, T>),
-    Participant(ParticipantContext<P, T>),
+    Coordinator(CoordinatorContext<P, V, T>),
+    Participant(ParticipantContext<P, V, T>),
 }
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct TwoPhaseCommitRoleContext<P, T>
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TwoPhaseCommitRoleContext<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
-    inner: InnerContext<P, T>,
+    inner: InnerContext<P, V, T>,
 }
 
-impl<P, T> TwoPhaseCommitRoleContext<P, T>
+impl<P, V, T> TwoPhaseCommitRoleContext<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     pub(super) fn new_coordinator(
         participants: Vec<Participant<P>>,
         state: TwoPhaseCommitState<T>,
+        value: Option<V>,
     ) -> Result<Self, InvalidStateError> {
         Ok(Self {
             inner: InnerContext::Coordinator(CoordinatorContext {
                 participants,
                 state: state.try_into()?,
+                value,
             }),
         })
     }
@@ -59,11 +67,13 @@ where
     pub(super) fn new_participant(
         participant_processes: Vec<P>,
         state: TwoPhaseCommitState<T>,
+        value: Option<V>,
     ) -> Result<Self, InvalidStateError> {
         Ok(Self {
             inner: InnerContext::Participant(ParticipantContext {
                 participant_processes,
                 state: state.try_into()?,
+                value,
             }),
         })
     }
@@ -88,16 +98,24 @@ where
             InnerContext::Participant(c) => c.state.clone().into(),
         }
     }
+
+    pub fn value(&self) -> Option<&V> {
+        match &self.inner {
+            InnerContext::Coordinator(c) => c.value.as_ref(),
+            InnerContext::Participant(c) => c.value.as_ref(),
+        }
+    }
 }
 
-impl<P, T> TryFrom<TwoPhaseCommitRoleContext<P, T>> for CoordinatorContext<P, T>
+impl<P, V, T> TryFrom<TwoPhaseCommitRoleContext<P, V, T>> for CoordinatorContext<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     type Error = InvalidStateError;
 
-    fn try_from(context: TwoPhaseCommitRoleContext<P, T>) -> Result<Self, Self::Error> {
+    fn try_from(context: TwoPhaseCommitRoleContext<P, V, T>) -> Result<Self, Self::Error> {
         match context.inner {
             InnerContext::Coordinator(c) => Ok(c),
             InnerContext::Participant(_) => Err(InvalidStateError::with_message(
@@ -109,14 +127,15 @@ where
     }
 }
 
-impl<P, T> TryFrom<TwoPhaseCommitRoleContext<P, T>> for ParticipantContext<P, T>
+impl<P, V, T> TryFrom<TwoPhaseCommitRoleContext<P, V, T>> for ParticipantContext<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
     type Error = InvalidStateError;
 
-    fn try_from(context: TwoPhaseCommitRoleContext<P, T>) -> Result<Self, Self::Error> {
+    fn try_from(context: TwoPhaseCommitRoleContext<P, V, T>) -> Result<Self, Self::Error> {
         match context.inner {
             InnerContext::Participant(c) => Ok(c),
             InnerContext::Coordinator(_) => Err(InvalidStateError::with_message(
@@ -128,24 +147,26 @@ where
     }
 }
 
-impl<P, T> From<CoordinatorContext<P, T>> for TwoPhaseCommitRoleContext<P, T>
+impl<P, V, T> From<CoordinatorContext<P, V, T>> for TwoPhaseCommitRoleContext<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
-    fn from(context: CoordinatorContext<P, T>) -> Self {
+    fn from(context: CoordinatorContext<P, V, T>) -> Self {
         Self {
             inner: InnerContext::Coordinator(context),
         }
     }
 }
 
-impl<P, T> From<ParticipantContext<P, T>> for TwoPhaseCommitRoleContext<P, T>
+impl<P, V, T> From<ParticipantContext<P, V, T>> for TwoPhaseCommitRoleContext<P, V, T>
 where
     P: Process,
+    V: Value,
     T: Time,
 {
-    fn from(context: ParticipantContext<P, T>) -> Self {
+    fn from(context: ParticipantContext<P, V, T>) -> Self {
         Self {
             inner: InnerContext::Participant(context),
         }
@@ -19,6 +19,7 @@ use super::CoordinatorState;
 use super::ParticipantState;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum TwoPhaseCommitState<T> {
     Abort,
     Commit,
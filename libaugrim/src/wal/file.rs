@@ -0,0 +1,216 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains FileEventLog.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+use crate::error::InternalError;
+use crate::store::record::{encode_record, io_error, parse_records, read_file};
+use crate::store::Codec;
+
+use super::{EventLog, LogEntry};
+
+/// A crash-safe [`EventLog`] which appends entries to a single file.
+///
+/// Each entry is encoded with the codec and written as a record carrying a sequence number and a
+/// checksum, then flushed to disk before `append` returns. A record which was only partially
+/// written before a crash is detected and discarded when the log is opened; one left by a failed
+/// `append` is removed before the next entry is written, so that no entry is appended after it.
+pub struct FileEventLog<E, C, T, K> {
+    path: PathBuf,
+    codec: K,
+    file: File,
+    // The length of the complete records in the file.
+    len: u64,
+    next_seq: u64,
+    _entry: PhantomData<fn(E, C, T)>,
+}
+
+impl<E, C, T, K> FileEventLog<E, C, T, K>
+where
+    K: Codec<LogEntry<E, C, T>>,
+{
+    /// Open the log at the given path, creating it if necessary and discarding any partially
+    /// written entry.
+    pub fn open(path: impl Into<PathBuf>, codec: K) -> Result<Self, InternalError> {
+        let path = path.into();
+        let bytes = read_file(&path)?;
+        let (records, valid_len) = parse_records(&bytes);
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(io_error)?;
+        if valid_len < bytes.len() {
+            file.set_len(valid_len as u64).map_err(io_error)?;
+            file.sync_all().map_err(io_error)?;
+        }
+
+        let next_seq = records.last().map(|(seq, _)| seq + 1).unwrap_or(1);
+
+        Ok(Self {
+            path,
+            codec,
+            file,
+            len: valid_len as u64,
+            next_seq,
+            _entry: PhantomData,
+        })
+    }
+}
+
+impl<E, C, T, K> EventLog<E, C, T> for FileEventLog<E, C, T, K>
+where
+    K: Codec<LogEntry<E, C, T>>,
+{
+    fn append(&mut self, entry: &LogEntry<E, C, T>) -> Result<(), InternalError> {
+        let payload = self.codec.encode(entry)?;
+        let record = encode_record(self.next_seq, &payload);
+
+        // Remove any part of a record left by a failed append; until it is removed, nothing
+        // more can be appended.
+        if self.file.metadata().map_err(io_error)?.len() != self.len {
+            self.file.set_len(self.len).map_err(io_error)?;
+        }
+
+        self.file.write_all(&record).map_err(io_error)?;
+        self.file.sync_data().map_err(io_error)?;
+        self.len += record.len() as u64;
+        self.next_seq += 1;
+
+        Ok(())
+    }
+
+    fn entries(&self) -> Result<Vec<LogEntry<E, C, T>>, InternalError> {
+        parse_records(&read_file(&self.path)?)
+            .0
+            .iter()
+            .map(|(_, payload)| self.codec.decode(payload))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    type Entry = LogEntry<u8, u8, u8>;
+
+    struct EntryCodec;
+
+    impl Codec<Entry> for EntryCodec {
+        fn encode(&self, entry: &Entry) -> Result<Vec<u8>, InternalError> {
+            Ok(vec![entry.event, entry.context, entry.time])
+        }
+
+        fn decode(&self, bytes: &[u8]) -> Result<Entry, InternalError> {
+            match bytes {
+                [event, context, time] => Ok(LogEntry {
+                    event: *event,
+                    context: *context,
+                    time: *time,
+                }),
+                _ => Err(InternalError::with_message(format!(
+                    "expected 3 bytes, found {}",
+                    bytes.len()
+                ))),
+            }
+        }
+    }
+
+    fn entry(n: u8) -> Entry {
+        LogEntry {
+            event: n,
+            context: n,
+            time: n,
+        }
+    }
+
+    // A file which is removed when dropped.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default();
+            Self(std::env::temp_dir().join(format!("augrim-{name}-{}-{nanos}", std::process::id())))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    /// Test that entries survive reopening the log, and that a partially written entry is
+    /// discarded when the log is opened.
+    #[test]
+    fn test_append_and_recover() -> Result<(), Box<dyn std::error::Error>> {
+        let file = TempFile::new("wal");
+
+        let mut log = FileEventLog::open(&file.0, EntryCodec)?;
+        assert!(log.entries()?.is_empty());
+        log.append(&entry(1))?;
+        log.append(&entry(2))?;
+        drop(log);
+
+        // A crash part way through writing the next entry.
+        let mut raw = OpenOptions::new().append(true).open(&file.0)?;
+        raw.write_all(&encode_record(3, &[3, 3, 3])[..10])?;
+        drop(raw);
+
+        let mut log = FileEventLog::open(&file.0, EntryCodec)?;
+        assert_eq!(log.entries()?, vec![entry(1), entry(2)]);
+
+        log.append(&entry(4))?;
+        assert_eq!(log.entries()?, vec![entry(1), entry(2), entry(4)]);
+
+        Ok(())
+    }
+
+    /// Test that part of a record left by a failed append is removed by the next append, rather
+    /// than the next entry being written after it and lost when the log is opened.
+    #[test]
+    fn test_append_after_torn_write() -> Result<(), Box<dyn std::error::Error>> {
+        let file = TempFile::new("wal-torn");
+
+        let mut log = FileEventLog::open(&file.0, EntryCodec)?;
+        log.append(&entry(1))?;
+
+        // A failed append, which wrote only part of its record.
+        let mut raw = OpenOptions::new().append(true).open(&file.0)?;
+        raw.write_all(&encode_record(2, &[2, 2, 2])[..10])?;
+        drop(raw);
+
+        log.append(&entry(3))?;
+        assert_eq!(log.entries()?, vec![entry(1), entry(3)]);
+        drop(log);
+
+        let log = FileEventLog::open(&file.0, EntryCodec)?;
+        assert_eq!(log.entries()?, vec![entry(1), entry(3)]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains MemoryEventLog.
+
+use crate::error::InternalError;
+
+use super::{EventLog, LogEntry};
+
+/// An [`EventLog`] which keeps entries in memory.
+///
+/// This is not durable; it is intended for tests and for capturing a run to replay later.
+#[derive(Clone, Debug)]
+pub struct MemoryEventLog<E, C, T> {
+    entries: Vec<LogEntry<E, C, T>>,
+}
+
+impl<E, C, T> Default for MemoryEventLog<E, C, T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<E, C, T> MemoryEventLog<E, C, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E, C, T> EventLog<E, C, T> for MemoryEventLog<E, C, T>
+where
+    E: Clone,
+    C: Clone,
+    T: Clone,
+{
+    fn append(&mut self, entry: &LogEntry<E, C, T>) -> Result<(), InternalError> {
+        self.entries.push(entry.clone());
+        Ok(())
+    }
+
+    fn entries(&self) -> Result<Vec<LogEntry<E, C, T>>, InternalError> {
+        Ok(self.entries.clone())
+    }
+}
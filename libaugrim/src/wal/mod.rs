@@ -0,0 +1,329 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A write-ahead log of the events processed by an algorithm, and replay of that log.
+//!
+//! [`Logged`] records each event in an [`EventLog`], together with the context it was processed
+//! with and the time at which it was processed, before passing it to the algorithm. A
+//! [`Replayer`] re-runs the logged events through the algorithm to reconstruct the latest
+//! context, either to recover after a crash or to reproduce a problem deterministically.
+
+mod file;
+mod memory;
+mod replay;
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::algorithm::Algorithm;
+use crate::error::{AlgorithmError, InternalError};
+use crate::sync::lock_recover;
+use crate::time::{ManualTimeSource, TimeSource};
+
+pub use file::FileEventLog;
+pub use memory::MemoryEventLog;
+pub use replay::{Replay, Replayer};
+
+/// An event processed by an algorithm, along with the context it was processed with and the
+/// time at which it was processed.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LogEntry<E, C, T> {
+    pub event: E,
+    pub context: C,
+    pub time: T,
+}
+
+/// An append-only log of [`LogEntry`] records.
+pub trait EventLog<E, C, T> {
+    /// Durably append an entry to the log.
+    fn append(&mut self, entry: &LogEntry<E, C, T>) -> Result<(), InternalError>;
+
+    /// Returns every entry in the log, oldest first.
+    fn entries(&self) -> Result<Vec<LogEntry<E, C, T>>, InternalError>;
+}
+
+/// An algorithm which appends every event to an [`EventLog`] before processing it.
+///
+/// For the log to be replayed exactly, the inner algorithm must read the time from `clock`: for
+/// each event, `clock` is set to the current time of `time_source`, and that time is recorded
+/// with the event. The algorithm therefore sees a single time per event, which the
+/// [`Replayer`] can reproduce.
+///
+/// If the entry can not be appended, the event is not processed and
+/// [`AlgorithmError::Internal`] is returned.
+///
+/// ```
+/// use augrim::{Algorithm, ManualTimeSource, SystemTimeFactory, TimeSource};
+/// use augrim::two_phase_commit::TwoPhaseCommitAlgorithm;
+/// use augrim::wal::{Logged, MemoryEventLog};
+///
+/// let time_source = SystemTimeFactory::new();
+/// let clock = ManualTimeSource::new(time_source.now());
+///
+/// let algorithm = Logged::new(
+///     TwoPhaseCommitAlgorithm::<String, String, _>::new(clock.clone()),
+///     MemoryEventLog::new(),
+///     time_source,
+///     clock,
+/// );
+/// ```
+pub struct Logged<A, L, TS>
+where
+    TS: TimeSource,
+{
+    inner: A,
+    log: Mutex<L>,
+    time_source: TS,
+    clock: ManualTimeSource<TS::Time>,
+}
+
+impl<A, L, TS> Logged<A, L, TS>
+where
+    A: Algorithm,
+    L: EventLog<A::Event, A::Context, TS::Time>,
+    TS: TimeSource,
+{
+    pub fn new(inner: A, log: L, time_source: TS, clock: ManualTimeSource<TS::Time>) -> Self {
+        Self {
+            inner,
+            log: Mutex::new(log),
+            time_source,
+            clock,
+        }
+    }
+
+    /// Returns every entry in the log, oldest first.
+    #[allow(clippy::type_complexity)]
+    pub fn entries(&self) -> Result<Vec<LogEntry<A::Event, A::Context, TS::Time>>, InternalError> {
+        self.lock().entries()
+    }
+
+    /// Returns the wrapped algorithm and the log.
+    pub fn into_inner(self) -> (A, L) {
+        let log = self
+            .log
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        (self.inner, log)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, L> {
+        lock_recover(&self.log)
+    }
+}
+
+impl<A, L, TS> Algorithm for Logged<A, L, TS>
+where
+    A: Algorithm,
+    A::Event: Clone,
+    A::Context: Clone,
+    L: EventLog<A::Event, A::Context, TS::Time>,
+    TS: TimeSource,
+{
+    type Event = A::Event;
+    type Action = A::Action;
+    type Context = A::Context;
+
+    fn event(
+        &self,
+        event: Self::Event,
+        context: Self::Context,
+    ) -> Result<Vec<Self::Action>, AlgorithmError> {
+        let time = self.time_source.now();
+        self.clock.set(time);
+
+        self.lock().append(&LogEntry {
+            event: event.clone(),
+            context: context.clone(),
+            time,
+        })?;
+
+        self.inner.event(event, context)
+    }
+}
+
+#[cfg(all(test, feature = "algorithm-two-phase-commit"))]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::driver::{DrivableAction, DriverAction};
+    use crate::two_phase_commit::{
+        Participant, TwoPhaseCommitAlgorithm, TwoPhaseCommitContext, TwoPhaseCommitContextBuilder,
+        TwoPhaseCommitEvent, TwoPhaseCommitMessage, TwoPhaseCommitState,
+    };
+
+    use super::*;
+
+    type Context = TwoPhaseCommitContext<String, String, SystemTime>;
+    type Event = TwoPhaseCommitEvent<String, String>;
+    type Entry = LogEntry<Event, Context, SystemTime>;
+
+    fn coordinator_context() -> Context {
+        TwoPhaseCommitContextBuilder::new()
+            .with_coordinator("c".to_string())
+            .with_epoch(1)
+            .with_state(TwoPhaseCommitState::WaitingForStart)
+            .with_this_process("c".to_string())
+            .with_participants(vec![Participant::new("p".to_string())])
+            .build()
+            .unwrap()
+    }
+
+    // Run the coordinator through the events, logging each one, advancing the time source by the
+    // given duration before each event. An event which the algorithm rejects has no effect, as
+    // when run by a driver. Returns the final context.
+    fn run_logged(events: Vec<(Duration, Event)>) -> Result<(Context, Vec<Entry>), AlgorithmError> {
+        let time_source = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let clock = ManualTimeSource::new(SystemTime::UNIX_EPOCH);
+        let algorithm = Logged::new(
+            TwoPhaseCommitAlgorithm::new(clock.clone()),
+            MemoryEventLog::new(),
+            time_source.clone(),
+            clock,
+        );
+
+        let mut context = coordinator_context();
+        for (elapsed, event) in events {
+            time_source.advance(elapsed);
+            let actions = match algorithm.event(event, context.clone()) {
+                Ok(actions) => actions,
+                Err(AlgorithmError::InvalidState(_)) => continue,
+                Err(err) => return Err(err),
+            };
+            for action in actions {
+                if let DriverAction::Update { context: next, .. } = action.into_driver_action() {
+                    context = next;
+                }
+            }
+        }
+
+        Ok((context, algorithm.entries()?))
+    }
+
+    /// Test that replaying the log of a commit reconstructs the coordinator's final context.
+    #[test]
+    fn test_replay_commit() -> Result<(), Box<dyn std::error::Error>> {
+        let (context, entries) = run_logged(vec![
+            (Duration::ZERO, TwoPhaseCommitEvent::Alarm()),
+            (
+                Duration::from_secs(1),
+                TwoPhaseCommitEvent::Start("v".into()),
+            ),
+            (
+                Duration::from_secs(1),
+                TwoPhaseCommitEvent::Deliver(
+                    "p".into(),
+                    TwoPhaseCommitMessage::VoteResponse(1, true),
+                ),
+            ),
+        ])?;
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].context, coordinator_context());
+
+        let clock = ManualTimeSource::new(SystemTime::now());
+        let replay =
+            Replayer::new(TwoPhaseCommitAlgorithm::new(clock.clone()), clock).replay(entries);
+
+        assert_eq!(replay.events, 3);
+        assert!(replay.rejected.is_empty());
+        assert!(replay.mismatches.is_empty());
+        assert_eq!(replay.checkpoint.map(|c| c.context), Some(context));
+
+        Ok(())
+    }
+
+    /// Test that replay uses the recorded times rather than the current time: a vote timeout
+    /// which fired when the log was written also fires on replay, and one which did not fire
+    /// does not, no matter when the log is replayed.
+    #[test]
+    fn test_replay_timeout() -> Result<(), Box<dyn std::error::Error>> {
+        let (context, entries) = run_logged(vec![
+            (Duration::ZERO, TwoPhaseCommitEvent::Alarm()),
+            (Duration::ZERO, TwoPhaseCommitEvent::Start("v".into())),
+            (Duration::from_secs(10), TwoPhaseCommitEvent::Alarm()),
+            (Duration::from_secs(30), TwoPhaseCommitEvent::Alarm()),
+        ])?;
+        assert!(matches!(
+            context.state(),
+            TwoPhaseCommitState::WaitingForDecisionAck { .. }
+        ));
+
+        let clock = ManualTimeSource::new(SystemTime::now());
+        let replayer = Replayer::new(TwoPhaseCommitAlgorithm::new(clock.clone()), clock);
+
+        let replay = replayer.replay(entries.clone());
+        assert!(replay.mismatches.is_empty());
+        assert_eq!(replay.checkpoint.map(|c| c.context), Some(context));
+
+        let replay = replayer.replay(entries.into_iter().take(3));
+        assert!(matches!(
+            replay.checkpoint.map(|c| c.context.state()),
+            Some(TwoPhaseCommitState::Voting { .. })
+        ));
+
+        Ok(())
+    }
+
+    /// Test that an entry whose recorded context differs from the replayed context is reported.
+    #[test]
+    fn test_replay_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, mut entries) = run_logged(vec![
+            (Duration::ZERO, TwoPhaseCommitEvent::Alarm()),
+            (Duration::ZERO, TwoPhaseCommitEvent::Start("v".into())),
+            (Duration::from_secs(1), TwoPhaseCommitEvent::Alarm()),
+        ])?;
+        entries[2].context = coordinator_context();
+
+        let clock = ManualTimeSource::new(SystemTime::now());
+        let replay =
+            Replayer::new(TwoPhaseCommitAlgorithm::new(clock.clone()), clock).replay(entries);
+        assert_eq!(replay.mismatches, vec![2]);
+
+        Ok(())
+    }
+
+    /// Test that an event which the algorithm rejected when it was logged is skipped on replay,
+    /// leaving the context unchanged, rather than failing the replay.
+    #[test]
+    fn test_replay_rejected() -> Result<(), Box<dyn std::error::Error>> {
+        let (context, entries) = run_logged(vec![
+            (Duration::ZERO, TwoPhaseCommitEvent::Alarm()),
+            (Duration::ZERO, TwoPhaseCommitEvent::Start("v".into())),
+            // The coordinator is already voting, so a second start is rejected.
+            (
+                Duration::from_secs(1),
+                TwoPhaseCommitEvent::Start("w".into()),
+            ),
+            (
+                Duration::from_secs(1),
+                TwoPhaseCommitEvent::Deliver(
+                    "p".into(),
+                    TwoPhaseCommitMessage::VoteResponse(1, true),
+                ),
+            ),
+        ])?;
+        assert_eq!(entries.len(), 4);
+
+        let clock = ManualTimeSource::new(SystemTime::now());
+        let replay =
+            Replayer::new(TwoPhaseCommitAlgorithm::new(clock.clone()), clock).replay(entries);
+
+        assert_eq!(replay.events, 4);
+        assert_eq!(replay.rejected, vec![2]);
+        assert!(replay.mismatches.is_empty());
+        assert_eq!(replay.checkpoint.map(|c| c.context), Some(context));
+
+        Ok(())
+    }
+}
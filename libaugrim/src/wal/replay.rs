@@ -0,0 +1,125 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains Replayer.
+
+use crate::algorithm::Algorithm;
+use crate::driver::{DrivableAction, DriverAction};
+use crate::store::Checkpoint;
+use crate::time::{ManualTimeSource, Time};
+
+use super::LogEntry;
+
+/// The result of replaying an event log.
+#[derive(Debug, PartialEq)]
+pub struct Replay<C, T> {
+    /// The reconstructed context and alarm, or `None` if the log was empty.
+    pub checkpoint: Option<Checkpoint<C, T>>,
+    /// The number of events which were replayed, including those rejected.
+    pub events: usize,
+    /// The indexes of the entries whose event the algorithm rejected with an error.
+    ///
+    /// An event may be rejected in a normal race, as when it was first processed by a driver;
+    /// a rejected event has no effect on the context.
+    pub rejected: Vec<usize>,
+    /// The indexes of the entries whose recorded context differs from the reconstructed context.
+    ///
+    /// This is empty when the algorithm behaved the same way during replay as when the log was
+    /// written.
+    pub mismatches: Vec<usize>,
+}
+
+/// Reconstructs an algorithm's context by re-running the events of an event log.
+///
+/// The algorithm must read the time from `clock`, as with [`Logged`](super::Logged); before each
+/// event is replayed, `clock` is set to the time recorded with it.
+///
+/// Replay starts from the context recorded with the first entry. Each subsequent event is run
+/// with the context produced by the previous events, rather than the recorded one, so that the
+/// result reflects the algorithm alone; where the two differ, the entry is reported in
+/// [`Replay::mismatches`]. Since [`Logged`](super::Logged) logs each event before it is
+/// processed, the log may contain events which the algorithm rejected; these are skipped,
+/// leaving the context unchanged, and reported in [`Replay::rejected`].
+pub struct Replayer<A, T> {
+    algorithm: A,
+    clock: ManualTimeSource<T>,
+}
+
+impl<A, T> Replayer<A, T>
+where
+    A: Algorithm,
+    A::Context: Clone + PartialEq,
+    A::Action: DrivableAction<Context = A::Context, Time = T>,
+    T: Time,
+{
+    pub fn new(algorithm: A, clock: ManualTimeSource<T>) -> Self {
+        Self { algorithm, clock }
+    }
+
+    /// Replay the entries, oldest first.
+    pub fn replay<I>(&self, entries: I) -> Replay<A::Context, T>
+    where
+        I: IntoIterator<Item = LogEntry<A::Event, A::Context, T>>,
+    {
+        let mut checkpoint: Option<Checkpoint<A::Context, T>> = None;
+        let mut events = 0;
+        let mut rejected = Vec::new();
+        let mut mismatches = Vec::new();
+
+        for (index, entry) in entries.into_iter().enumerate() {
+            let current = match checkpoint.take() {
+                Some(current) => {
+                    if current.context != entry.context {
+                        mismatches.push(index);
+                    }
+                    current
+                }
+                None => Checkpoint {
+                    context: entry.context,
+                    alarm: None,
+                },
+            };
+
+            self.clock.set(entry.time);
+            events += 1;
+            let actions = match self.algorithm.event(entry.event, current.context.clone()) {
+                Ok(actions) => actions,
+                Err(_) => {
+                    rejected.push(index);
+                    checkpoint = Some(current);
+                    continue;
+                }
+            };
+
+            checkpoint = Some(actions.into_iter().fold(current, |checkpoint, action| {
+                match action.into_driver_action() {
+                    DriverAction::Update { context, alarm } => Checkpoint { context, alarm },
+                    _ => checkpoint,
+                }
+            }));
+        }
+
+        Replay {
+            checkpoint,
+            events,
+            rejected,
+            mismatches,
+        }
+    }
+
+    /// Returns the wrapped algorithm.
+    pub fn into_inner(self) -> A {
+        self.algorithm
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builds the simulated clusters run by the CLI.
+
+use std::time::{Duration, SystemTime};
+
+use augrim::error::InvalidStateError;
+use augrim::sim::two_phase_commit::{add_cluster, application};
+use augrim::sim::{SimRng, Simulation};
+
+pub type TwoPhaseCommitSimulation =
+    augrim::sim::two_phase_commit::TwoPhaseCommitSimulation<String, String, SystemTime>;
+
+/// The shape of a simulated cluster.
+#[derive(Clone, Debug)]
+pub struct ClusterConfig {
+    /// The number of processes, including the coordinator.
+    pub processes: usize,
+    /// The seed from which every random choice is drawn.
+    pub seed: u64,
+    /// The number of values the coordinator proposes.
+    pub rounds: usize,
+    /// The probability that a process votes to abort.
+    pub vote_no_probability: f64,
+    pub min_delay: Duration,
+    pub max_delay: Duration,
+}
+
+/// Returns the name of the process with the given index; process 0 is the coordinator.
+pub fn process_name(index: usize) -> String {
+    format!("p{index}")
+}
+
+/// Build a two-phase commit cluster: `p0` coordinates, and the remaining processes participate.
+///
+/// The simulated application proposes `value-1`, `value-2` and so on, one per round, and votes
+/// at random according to the configured probability.
+pub fn two_phase_commit(
+    config: &ClusterConfig,
+) -> Result<TwoPhaseCommitSimulation, InvalidStateError> {
+    if config.processes < 2 {
+        return Err(InvalidStateError::with_message(
+            "a two-phase commit cluster needs at least 2 processes".into(),
+        ));
+    }
+
+    let vote_no_probability = config.vote_no_probability;
+    // Votes are drawn separately from the network, so the number of votes does not change the
+    // message delays.
+    let mut votes = SimRng::new(config.seed ^ 0x766f_7465);
+
+    let mut simulation = Simulation::new(SystemTime::UNIX_EPOCH, config.seed)
+        .with_message_delay(config.min_delay, config.max_delay)
+        .with_application(application(
+            (1..=config.rounds).map(|round| format!("value-{round}")),
+            move || !votes.chance(vote_no_probability),
+        ));
+    add_cluster(
+        &mut simulation,
+        process_name(0),
+        (1..config.processes).map(process_name).collect(),
+    )?;
+
+    Ok(simulation)
+}
+
+#[cfg(test)]
+mod tests {
+    use augrim::sim::{Fault, FaultSchedule};
+    use augrim::two_phase_commit::TwoPhaseCommitActionNotification;
+
+    use super::*;
+
+    fn config(processes: usize, vote_no_probability: f64) -> ClusterConfig {
+        ClusterConfig {
+            processes,
+            seed: 0,
+            rounds: 2,
+            vote_no_probability,
+            min_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(20),
+        }
+    }
+
+    fn decisions(simulation: &TwoPhaseCommitSimulation) -> (usize, usize) {
+        simulation
+            .notifications()
+            .iter()
+            .fold((0, 0), |(commits, aborts), n| match n.record {
+                TwoPhaseCommitActionNotification::Commit(..) => (commits + 1, aborts),
+                TwoPhaseCommitActionNotification::Abort(..) => (commits, aborts + 1),
+                _ => (commits, aborts),
+            })
+    }
+
+    /// Test that every process decides every round, committing when all processes vote yes and
+    /// aborting when they all vote no.
+    #[test]
+    fn test_two_phase_commit() -> Result<(), Box<dyn std::error::Error>> {
+        let mut simulation = two_phase_commit(&config(4, 0.0))?;
+        simulation.run_for(Duration::from_secs(60));
+        assert_eq!(decisions(&simulation), (8, 0));
+
+        let mut simulation = two_phase_commit(&config(4, 1.0))?;
+        simulation.run_for(Duration::from_secs(60));
+        assert_eq!(simulation.stats().errors, 0);
+        assert_eq!(decisions(&simulation), (0, 8));
+
+        assert!(two_phase_commit(&config(1, 0.0)).is_err());
+
+        Ok(())
+    }
+
+    /// Test that when a participant crashes before voting, the coordinator times out waiting for
+    /// its vote, and every other process aborts every round.
+    #[test]
+    fn test_two_phase_commit_crash() -> Result<(), Box<dyn std::error::Error>> {
+        let mut simulation = two_phase_commit(&config(5, 0.0))?;
+        simulation.schedule_faults(FaultSchedule::new().with_fault(
+            Duration::ZERO,
+            Fault::Crash {
+                process: process_name(3),
+            },
+        ))?;
+        simulation.run_for(Duration::from_secs(120));
+
+        assert_eq!(simulation.stats().crashes, 1);
+        assert_eq!(simulation.stats().errors, 0);
+        assert_eq!(decisions(&simulation), (0, 8));
+        assert!(simulation
+            .notifications()
+            .iter()
+            .all(|n| n.process != process_name(3)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reads fault schedules from TOML or JSON files.
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use augrim::sim::FaultSchedule;
+
+/// Read a fault schedule, as TOML if the file's extension is `.toml` and as JSON otherwise.
+pub fn load(path: &Path) -> Result<FaultSchedule<String>, Box<dyn Error>> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("unable to read {}: {err}", path.display()))?;
+    let toml = path
+        .extension()
+        .is_some_and(|extension| extension == "toml");
+    parse(&text, toml)
+        .map_err(|err| format!("invalid fault schedule {}: {err}", path.display()).into())
+}
+
+fn parse(text: &str, toml: bool) -> Result<FaultSchedule<String>, Box<dyn Error>> {
+    if toml {
+        Ok(toml::from_str(text)?)
+    } else {
+        Ok(serde_json::from_str(text)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use augrim::sim::Fault;
+
+    use super::*;
+
+    /// Test that the same schedule may be written in TOML or JSON.
+    #[test]
+    fn test_parse() -> Result<(), Box<dyn Error>> {
+        let toml = r#"
+            [[faults]]
+            at_ms = 15
+            kind = "crash"
+            process = "p0"
+
+            [[faults]]
+            at_ms = 100
+            kind = "partition"
+            groups = [["p1"], ["p2", "p3"]]
+
+            [[faults]]
+            at_ms = 200
+            kind = "duplicate"
+            probability = 0.5
+
+            [[faults]]
+            at_ms = 60000
+            kind = "restart"
+            process = "p0"
+        "#;
+        let json = r#"{ "faults": [
+            { "at_ms": 15, "kind": "crash", "process": "p0" },
+            { "at_ms": 100, "kind": "partition", "groups": [["p1"], ["p2", "p3"]] },
+            { "at_ms": 200, "kind": "duplicate", "probability": 0.5 },
+            { "at_ms": 60000, "kind": "restart", "process": "p0" }
+        ] }"#;
+
+        let expected = FaultSchedule::new()
+            .with_fault(
+                Duration::from_millis(15),
+                Fault::Crash {
+                    process: "p0".into(),
+                },
+            )
+            .with_fault(
+                Duration::from_millis(100),
+                Fault::Partition {
+                    groups: vec![vec!["p1".into()], vec!["p2".into(), "p3".into()]],
+                },
+            )
+            .with_fault(
+                Duration::from_millis(200),
+                Fault::Duplicate { probability: 0.5 },
+            )
+            .with_fault(
+                Duration::from_secs(60),
+                Fault::Restart {
+                    process: "p0".into(),
+                },
+            );
+
+        assert_eq!(parse(toml, true)?, expected);
+        assert_eq!(parse(json, false)?, expected);
+        assert!(parse(r#"{ "faults": [{ "at_ms": 0, "kind": "meteor" }] }"#, false).is_err());
+
+        Ok(())
+    }
+}
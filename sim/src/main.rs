@@ -0,0 +1,201 @@
+// Copyright 2026 Bitwise IO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `augrim-sim` runs a simulated cluster of one of Augrim's algorithms entirely in-process,
+//! printing the decisions reached and statistics about the run, and optionally writing a trace.
+//! Faults may be injected from a schedule in a TOML or JSON file.
+
+mod cluster;
+mod faults;
+
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use augrim::trace::SequenceDiagram;
+use augrim::two_phase_commit::TwoPhaseCommitActionNotification;
+use clap::{Parser, ValueEnum};
+
+use cluster::{ClusterConfig, TwoPhaseCommitSimulation};
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum AlgorithmArg {
+    TwoPhaseCommit,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum TraceFormat {
+    Json,
+    Mermaid,
+    Plantuml,
+    Dot,
+}
+
+/// Run a simulated cluster of an Augrim algorithm.
+#[derive(Debug, Parser)]
+#[command(name = "augrim-sim", version)]
+struct Args {
+    /// The algorithm run by every process
+    #[arg(long, value_enum, default_value = "two-phase-commit")]
+    algorithm: AlgorithmArg,
+
+    /// The number of processes in the cluster
+    #[arg(long, short = 'n', default_value_t = 3)]
+    processes: usize,
+
+    /// The seed from which every random choice is drawn; a run is reproduced by its seed
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// The number of values to propose
+    #[arg(long, default_value_t = 1)]
+    rounds: usize,
+
+    /// The probability that a process votes to abort
+    #[arg(long, default_value_t = 0.0)]
+    vote_no_probability: f64,
+
+    /// The simulated time to run for, in milliseconds
+    #[arg(long, default_value_t = 10_000)]
+    duration_ms: u64,
+
+    /// The minimum delay of a message, in milliseconds
+    #[arg(long, default_value_t = 1)]
+    min_delay_ms: u64,
+
+    /// The maximum delay of a message, in milliseconds
+    #[arg(long, default_value_t = 50)]
+    max_delay_ms: u64,
+
+    /// A TOML or JSON file scheduling the faults to inject
+    #[arg(long)]
+    faults: Option<PathBuf>,
+
+    /// Write the trace of the run in the given format
+    #[arg(long, value_enum)]
+    trace: Option<TraceFormat>,
+
+    /// The file to write the trace to, instead of standard output
+    #[arg(long, requires = "trace")]
+    trace_output: Option<PathBuf>,
+}
+
+fn main() {
+    if let Err(err) = run(Args::parse()) {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let config = ClusterConfig {
+        processes: args.processes,
+        seed: args.seed,
+        rounds: args.rounds,
+        vote_no_probability: args.vote_no_probability,
+        min_delay: Duration::from_millis(args.min_delay_ms),
+        max_delay: Duration::from_millis(args.max_delay_ms),
+    };
+
+    let mut simulation = match args.algorithm {
+        AlgorithmArg::TwoPhaseCommit => cluster::two_phase_commit(&config)?,
+    };
+    if let Some(path) = &args.faults {
+        simulation.schedule_faults(faults::load(path)?)?;
+    }
+    simulation.run_for(Duration::from_millis(args.duration_ms));
+
+    if let Some(format) = args.trace {
+        let trace = render_trace(&simulation, format)?;
+        match &args.trace_output {
+            Some(path) => fs::write(path, trace)?,
+            None => print!("{trace}"),
+        }
+    }
+
+    // With the trace on standard output, the summary goes to standard error.
+    let mut summary = String::new();
+    summarize(&simulation, &mut summary);
+    if args.trace.is_some() && args.trace_output.is_none() {
+        eprint!("{summary}");
+    } else {
+        print!("{summary}");
+    }
+
+    Ok(())
+}
+
+fn render_trace(
+    simulation: &TwoPhaseCommitSimulation,
+    format: TraceFormat,
+) -> Result<String, Box<dyn Error>> {
+    let trace = simulation.trace();
+    Ok(match format {
+        TraceFormat::Json => serde_json::to_string_pretty(trace)? + "\n",
+        TraceFormat::Mermaid => SequenceDiagram::from(trace).to_mermaid(),
+        TraceFormat::Plantuml => SequenceDiagram::from(trace).to_plantuml(),
+        TraceFormat::Dot => SequenceDiagram::from(trace).to_dot(),
+    })
+}
+
+fn summarize(simulation: &TwoPhaseCommitSimulation, out: &mut String) {
+    use std::fmt::Write;
+
+    let _ = writeln!(out, "decisions:");
+    for notification in simulation.notifications() {
+        let decision = match &notification.record {
+            TwoPhaseCommitActionNotification::Commit(epoch, value) => {
+                format!("commit epoch {epoch} ({value})")
+            }
+            TwoPhaseCommitActionNotification::Abort(epoch, value) => {
+                format!("abort epoch {epoch} ({value})")
+            }
+            _ => continue,
+        };
+        let _ = writeln!(
+            out,
+            "  {:>10.3}s  {:<6} {decision}",
+            notification.at.as_secs_f64(),
+            notification.process
+        );
+    }
+
+    for error in simulation.errors() {
+        let _ = writeln!(
+            out,
+            "  {:>10.3}s  {:<6} error: {}",
+            error.at.as_secs_f64(),
+            error.process,
+            error.record
+        );
+    }
+
+    let stats = simulation.stats();
+    let _ = writeln!(out, "statistics:");
+    for (label, value) in [
+        ("simulated time", format!("{:?}", simulation.elapsed())),
+        ("events", stats.events.to_string()),
+        ("alarms", stats.alarms.to_string()),
+        ("messages sent", stats.messages_sent.to_string()),
+        ("messages delivered", stats.messages_delivered.to_string()),
+        ("messages dropped", stats.messages_dropped.to_string()),
+        ("messages duplicated", stats.messages_duplicated.to_string()),
+        ("crashes", stats.crashes.to_string()),
+        ("restarts", stats.restarts.to_string()),
+        ("errors", stats.errors.to_string()),
+    ] {
+        let _ = writeln!(out, "  {:<21}{value}", format!("{label}:"));
+    }
+}